@@ -0,0 +1,907 @@
+//! Procedural macros for [`nonzero_lit`](https://docs.rs/nonzero_lit).
+//!
+//! Don't depend on this crate directly, instead enable the `macros` feature of
+//! `nonzero_lit`, which re-exports everything here.
+#![forbid(unsafe_code)]
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// The integer types we have literal macros for, longest suffix first so that
+/// suffix matching is unambiguous.
+const INT_TYPES: &[&str] = &[
+    "usize", "isize", "u128", "i128", "u64", "i64", "u32", "i32", "u16", "i16", "u8", "i8",
+];
+
+/// Rewrites `#<literal>` inside an expression into the matching checked
+/// `nonzero_lit` constructor. See the docs in `nonzero_lit` for details.
+///
+/// Called by `nonzero_lit::nonzero_expr!` as `nonzero_expr!($crate; expr)`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn nonzero_expr(input: TokenStream) -> TokenStream {
+    let (krate, input) = split_crate(input);
+    match rewrite(&krate, input) {
+        Ok(ts) => ts,
+        Err((span, msg)) => compile_error(span, &msg),
+    }
+}
+
+/// Derives a `NonZeroU32` ID from the crate's name and a key. See the docs in
+/// `nonzero_lit` for details.
+///
+/// Called by `nonzero_lit::nonzero_counter!` as `nonzero_counter!($crate; key)`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn nonzero_counter(input: TokenStream) -> TokenStream {
+    let (krate_path, input) = split_crate(input);
+    let key = match counter_key(input) {
+        Ok(key) => key,
+        Err((span, msg)) => return compile_error(span, &msg),
+    };
+    let krate = std::env::var("CARGO_CRATE_NAME")
+        .or_else(|_| std::env::var("CARGO_PKG_NAME"))
+        .unwrap_or_default();
+    // Zero is the only hash which can't be used, so it's replaced with one.
+    let id = fnv1a(format!("{}#{}", krate, key).as_bytes()).max(1);
+    let symbol = format!("__nonzero_counter_{}", id);
+    let args: TokenStream = vec![
+        TokenTree::Literal(Literal::u32_suffixed(id)),
+        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        TokenTree::Literal(Literal::string(&symbol)),
+    ]
+    .into_iter()
+    .collect();
+    macro_call(&krate_path, "__nonzero_counter", args, Span::call_site())
+        .into_iter()
+        .collect()
+}
+
+/// Checks that an existing enum's discriminants are nonzero, and adds
+/// `as_nonzero` and `from_nonzero`. See the docs in `nonzero_lit` for details.
+#[proc_macro_derive(NonZeroRepr, attributes(nonzero_repr))]
+pub fn derive_nonzero_repr(input: TokenStream) -> TokenStream {
+    match nonzero_repr(input) {
+        Ok(ts) => ts,
+        Err((span, msg)) => {
+            // In item position, the `compile_error!` needs a semicolon.
+            let mut ts = compile_error(span, &msg);
+            ts.extend(";".parse::<TokenStream>().unwrap());
+            ts
+        }
+    }
+}
+
+/// Rewrites the initializer of a `const` or `static` item with a `NonZero`
+/// type into the matching checked `nonzero_lit` constructor. See the docs in
+/// `nonzero_lit` for details.
+#[proc_macro_attribute]
+pub fn nonzero(args: TokenStream, item: TokenStream) -> TokenStream {
+    let result = match crate_arg(args, "#[nonzero(crate = path)]") {
+        Ok(krate) => nonzero_item(&krate.unwrap_or_else(default_crate), item),
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(ts) => ts,
+        Err((span, msg)) => {
+            // In item position, the `compile_error!` needs a semicolon.
+            let mut ts = compile_error(span, &msg);
+            ts.extend(";".parse::<TokenStream>().unwrap());
+            ts
+        }
+    }
+}
+
+/// Checks a literal macro's argument for `as` casts to integer types which
+/// changed the value they cast. Used by the literal macros when the `macros`
+/// feature is enabled, and not part of the public API.
+///
+/// Called as `__diagnose_casts!(expr)`, or as `__diagnose_casts!(const; expr)`
+/// to check in inline `const` blocks (which can use generic parameters).
+#[doc(hidden)]
+#[proc_macro]
+pub fn __diagnose_casts(input: TokenStream) -> TokenStream {
+    let mut expr: Vec<TokenTree> = input.into_iter().collect();
+    let inline = match &expr[..] {
+        [TokenTree::Ident(kw), TokenTree::Punct(semi), ..]
+            if kw.to_string() == "const" && semi.as_char() == ';' =>
+        {
+            expr.drain(..2);
+            true
+        }
+        _ => false,
+    };
+    let mut casts = Vec::new();
+    find_casts(&expr, &mut casts);
+    casts
+        .into_iter()
+        .flat_map(|cast| cast_check(cast, inline))
+        .collect()
+}
+
+/// Rejects `as` casts to integer types which may lose the value, and
+/// `wrapping_*` operations, in the argument to a literal macro called with
+/// `strict:`. Not part of the public API.
+///
+/// Called as `__strict_check!($crate; expr)`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __strict_check(input: TokenStream) -> TokenStream {
+    let (krate, input) = split_crate(input);
+    let tts: Vec<TokenTree> = input.into_iter().collect();
+    if let Err((span, msg)) = strict_check(&tts) {
+        return compile_error(span, &msg);
+    }
+    let mut casts = Vec::new();
+    find_casts(&tts, &mut casts);
+    casts
+        .into_iter()
+        .flat_map(|cast| strict_cast_check(&krate, cast))
+        .collect()
+}
+
+/// Rejects an argument to a literal macro which is a single integer literal
+/// whose suffix is a different type than the macro's. Not part of the public
+/// API.
+///
+/// Called as `__check_suffix!(u32, expr)`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __check_suffix(input: TokenStream) -> TokenStream {
+    let mut iter = input.into_iter();
+    let ty = match iter.next() {
+        Some(TokenTree::Ident(ty)) => ty.to_string(),
+        _ => return TokenStream::new(),
+    };
+    let _comma = iter.next();
+    let expr: Vec<TokenTree> = iter.collect();
+    match suffix_check(&ty, &expr) {
+        Ok(()) => TokenStream::new(),
+        Err((span, msg)) => compile_error(span, &msg),
+    }
+}
+
+/// Returns the contents of the string literal passed to `nonzero_counter!`.
+fn counter_key(input: TokenStream) -> Result<String, Error> {
+    let expected = "`nonzero_counter!` takes a string literal key, such as `\"started\"`";
+    let tts: Vec<TokenTree> = input.into_iter().collect();
+    let lit = match &tts[..] {
+        [TokenTree::Literal(lit)] => lit.clone(),
+        // A `literal` or `expr` fragment from `macro_rules!` arrives wrapped
+        // in an invisible group.
+        [TokenTree::Group(g)] if g.delimiter() == Delimiter::None => {
+            return counter_key(g.stream())
+        }
+        [] => return Err((Span::call_site(), expected.into())),
+        [first, ..] => return Err((first.span(), expected.into())),
+    };
+    let text = lit.to_string();
+    match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        Some(key) => Ok(key.to_string()),
+        None => Err((lit.span(), expected.into())),
+    }
+}
+
+/// 32-bit FNV-1a.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |h, &b| {
+        (h ^ u32::from(b)).wrapping_mul(0x0100_0193)
+    })
+}
+
+type Error = (Span, String);
+
+/// An `as` cast to an integer type: the operand, the target type, and the span
+/// of the `as`.
+type Cast = (Vec<TokenTree>, Ident, Span);
+
+fn find_casts(tts: &[TokenTree], out: &mut Vec<Cast>) {
+    for (i, tt) in tts.iter().enumerate() {
+        match tt {
+            TokenTree::Group(g) => {
+                let inner: Vec<TokenTree> = g.stream().into_iter().collect();
+                find_casts(&inner, out);
+            }
+            TokenTree::Ident(id) if id.to_string() == "as" => {
+                let target = match tts.get(i + 1) {
+                    Some(TokenTree::Ident(t)) if INT_TYPES.contains(&&*t.to_string()) => t,
+                    _ => continue,
+                };
+                // Skip casts whose operand can't be found, rather than
+                // checking the wrong expression.
+                if let Some(start) = operand_start(&tts[..i]) {
+                    out.push((tts[start..i].to_vec(), target.clone(), id.span()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn strict_check(tts: &[TokenTree]) -> Result<(), Error> {
+    for (i, tt) in tts.iter().enumerate() {
+        match tt {
+            TokenTree::Group(g) => {
+                let inner: Vec<TokenTree> = g.stream().into_iter().collect();
+                strict_check(&inner)?;
+            }
+            // Other casts are checked by `strict_cast_check`, which needs to
+            // find their operand.
+            TokenTree::Ident(id) if id.to_string() == "as" => match tts.get(i + 1) {
+                Some(TokenTree::Ident(t))
+                    if INT_TYPES.contains(&&*t.to_string())
+                        && operand_start(&tts[..i]).is_none() =>
+                {
+                    return Err((
+                        id.span(),
+                        format!(
+                            "`strict:` doesn't allow `as {}`, since it may truncate; use a checked conversion instead",
+                            t
+                        ),
+                    ));
+                }
+                _ => {}
+            },
+            TokenTree::Ident(id) if id.to_string().starts_with("wrapping_") => {
+                return Err((
+                    id.span(),
+                    format!(
+                        "`strict:` doesn't allow `{}`, since it may wrap; use a checked operation instead",
+                        id
+                    ),
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn suffix_check(ty: &str, tts: &[TokenTree]) -> Result<(), Error> {
+    // An `expr` fragment from `macro_rules!` arrives wrapped in an invisible
+    // group.
+    if let [TokenTree::Group(g)] = tts {
+        if g.delimiter() == Delimiter::None {
+            let inner: Vec<TokenTree> = g.stream().into_iter().collect();
+            return suffix_check(ty, &inner);
+        }
+    }
+    let lit = match tts {
+        [TokenTree::Literal(lit)] => lit,
+        [TokenTree::Punct(p), TokenTree::Literal(lit)] if p.as_char() == '-' => lit,
+        _ => return Ok(()),
+    };
+    let text = lit.to_string();
+    let is_int = text.starts_with(|c: char| c.is_ascii_digit()) && !text.contains('.');
+    match INT_TYPES.iter().find(|suffix| text.ends_with(**suffix)) {
+        Some(suffix) if is_int && *suffix != ty => Err((
+            lit.span(),
+            format!(
+                "`nonzero_lit::{ty}!` makes a `NonZero` `{ty}`, but `{lit}` is a `{suffix}`; \
+                 remove the suffix, or use `nonzero_lit::{suffix}!` for a `NonZero` `{suffix}`",
+                ty = ty,
+                lit = text,
+                suffix = suffix,
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Finds where the operand of an `as` starts, given the tokens before it.
+///
+/// This handles paths (including generic arguments and qualified paths such
+/// as `<T as Trait>::X`), calls, method calls, field accesses, and unary
+/// operators, which is all that's likely in a constant. Returns `None` if
+/// there's no operand.
+fn operand_start(before: &[TokenTree]) -> Option<usize> {
+    let is_punct = |tt: &TokenTree, c: char| matches!(tt, TokenTree::Punct(p) if p.as_char() == c);
+    let mut start = before.len();
+    while start > 0 {
+        match &before[start - 1] {
+            TokenTree::Ident(_) | TokenTree::Literal(_) | TokenTree::Group(_) => {}
+            TokenTree::Punct(p) if p.as_char() == '.' || p.as_char() == ':' => {}
+            // The end of generic arguments (`size_of::<u64>`) or of a
+            // qualified path's `<T as Trait>`, rather than a comparison.
+            TokenTree::Punct(p) if p.as_char() == '>' => {
+                let open = match matching_angle(&before[..start - 1]) {
+                    Some(open) => open,
+                    None => break,
+                };
+                let turbofish = open >= 2
+                    && is_punct(&before[open - 1], ':')
+                    && is_punct(&before[open - 2], ':');
+                let qualified = before.get(start).map_or(false, |tt| is_punct(tt, ':'));
+                if turbofish {
+                    start = open - 2;
+                } else if qualified {
+                    start = open;
+                } else {
+                    break;
+                }
+                continue;
+            }
+            _ => break,
+        }
+        start -= 1;
+    }
+    if start == before.len() {
+        return None;
+    }
+    // Unary operators bind more tightly than `as`, but only count as unary if
+    // they aren't preceded by an operand.
+    if start > 0 {
+        if let TokenTree::Punct(p) = &before[start - 1] {
+            let unary = start == 1 || matches!(before[start - 2], TokenTree::Punct(_));
+            if unary && (p.as_char() == '-' || p.as_char() == '!') {
+                start -= 1;
+            }
+        }
+    }
+    Some(start)
+}
+
+/// Finds the `<` matching a `>` just after `before`, if there is one.
+fn matching_angle(before: &[TokenTree]) -> Option<usize> {
+    let mut depth = 1;
+    for (i, tt) in before.iter().enumerate().rev() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '>' => depth += 1,
+            TokenTree::Punct(p) if p.as_char() == '<' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Builds a const assertion which fails (pointing at the `as`) if the cast
+/// changed its operand's value, in an inline `const` block if `inline`.
+fn cast_check(cast: Cast, inline: bool) -> Vec<TokenTree> {
+    let (operand, target, as_span) = cast;
+    let span = Span::mixed_site().located_at(as_span);
+    let code = |s: &str| -> Vec<TokenTree> { respan(s.parse().unwrap(), span) };
+    let group = |delim, tts: Vec<TokenTree>| {
+        let mut g = Group::new(delim, tts.into_iter().collect());
+        g.set_span(span);
+        TokenTree::Group(g)
+    };
+    let msg = format!(
+        "this `as {}` cast changed the value; use `from:` or a checked conversion instead",
+        target
+    );
+    let operand = group(Delimiter::Parenthesis, operand);
+
+    let mut cast = vec![operand.clone()];
+    cast.extend(code("as"));
+    cast.push(TokenTree::Ident(target));
+
+    let mut body = code("let lossy =");
+    body.push(operand);
+    body.extend(code("as i128 !="));
+    body.push(group(Delimiter::Parenthesis, cast));
+    body.extend(code(&format!(
+        "as i128; let _ = [{:?}][lossy as usize];",
+        msg
+    )));
+
+    let mut out = code("let _ =");
+    if inline {
+        out.extend(code("const"));
+        out.push(group(Delimiter::Brace, body));
+    } else {
+        // Naming the constant makes the error mention it.
+        let mut block = code("const TRUNCATING_CAST_CHANGED_THE_VALUE: () =");
+        block.push(group(Delimiter::Brace, body));
+        block.extend(code("; TRUNCATING_CAST_CHANGED_THE_VALUE"));
+        out.push(group(Delimiter::Brace, block));
+    }
+    out.extend(code(";"));
+    out
+}
+
+/// Builds a check which fails to compile (pointing at the `as`) unless the
+/// cast is lossless, which is the case when the target type implements `From`
+/// for the operand's type (as for `u8 as u32`, but not `u32 as u8`, `i8 as u8`
+/// or `u32 as usize`).
+fn strict_cast_check(krate: &[TokenTree], cast: Cast) -> Vec<TokenTree> {
+    let (operand, target, as_span) = cast;
+    let span = Span::mixed_site().located_at(as_span);
+    let code = |s: &str| -> Vec<TokenTree> { respan(s.parse().unwrap(), span) };
+    let group = |delim, tts: Vec<TokenTree>| {
+        let mut g = Group::new(delim, tts.into_iter().collect());
+        g.set_span(span);
+        TokenTree::Group(g)
+    };
+    // The closure is never called, so this works in constants (and in inline
+    // `const` blocks, where the operand may use generic parameters).
+    let mut out = code("let _ = ||");
+    out.extend(krate.iter().cloned());
+    out.extend(code("::_private::strict_cast::<"));
+    out.push(TokenTree::Ident(target));
+    out.extend(code(", _>"));
+    out.push(group(
+        Delimiter::Parenthesis,
+        vec![group(Delimiter::Parenthesis, operand)],
+    ));
+    out.extend(code(";"));
+    out
+}
+
+fn respan(ts: TokenStream, span: Span) -> Vec<TokenTree> {
+    ts.into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(g) => {
+                let inner = respan(g.stream(), span);
+                let mut ng = Group::new(g.delimiter(), inner.into_iter().collect());
+                ng.set_span(span);
+                TokenTree::Group(ng)
+            }
+            mut other => {
+                other.set_span(span);
+                other
+            }
+        })
+        .collect()
+}
+
+fn rewrite(krate: &[TokenTree], input: TokenStream) -> Result<TokenStream, Error> {
+    let mut out = Vec::new();
+    let mut iter = input.into_iter().peekable();
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == '#' && is_marked_literal(iter.peek()) => {
+                let neg = match iter.peek() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == '-' => {
+                        let p = p.clone();
+                        iter.next();
+                        Some(p)
+                    }
+                    _ => None,
+                };
+                let lit = match iter.next() {
+                    Some(TokenTree::Literal(lit)) => lit,
+                    Some(other) => {
+                        return Err((
+                            other.span(),
+                            "expected an integer literal after `#-`".into(),
+                        ))
+                    }
+                    None => {
+                        return Err((p.span(), "expected an integer literal after `#-`".into()))
+                    }
+                };
+                out.extend(checked_literal(krate, neg, lit)?);
+            }
+            TokenTree::Group(g) => {
+                let mut ng = Group::new(g.delimiter(), rewrite(krate, g.stream())?);
+                ng.set_span(g.span());
+                out.push(TokenTree::Group(ng));
+            }
+            other => out.push(other),
+        }
+    }
+    Ok(out.into_iter().collect())
+}
+
+/// The parts of an enum which `#[derive(NonZeroRepr)]` needs: its name, its
+/// `repr` type, its variants, and the path to `nonzero_lit`.
+struct ReprEnum {
+    name: Ident,
+    repr: String,
+    variants: Vec<Ident>,
+    krate: Vec<TokenTree>,
+}
+
+fn parse_repr_enum(input: TokenStream) -> Result<ReprEnum, Error> {
+    let tts: Vec<TokenTree> = input.into_iter().collect();
+    let mut repr = None;
+    let mut krate = None;
+    let mut i = 0;
+    // Attributes and visibility, up to `enum`.
+    let enum_span = loop {
+        match tts.get(i) {
+            Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Bracket => {
+                repr = repr.or_else(|| repr_type(g));
+                let mut attr = g.stream().into_iter();
+                if let (Some(TokenTree::Ident(id)), Some(TokenTree::Group(args))) =
+                    (attr.next(), attr.next())
+                {
+                    if id.to_string() == "nonzero_repr" {
+                        krate = crate_arg(args.stream(), "#[nonzero_repr(crate = path)]")?;
+                    }
+                }
+            }
+            Some(TokenTree::Ident(id)) if id.to_string() == "enum" => break id.span(),
+            Some(TokenTree::Ident(id))
+                if id.to_string() == "struct" || id.to_string() == "union" =>
+            {
+                return Err((
+                    id.span(),
+                    "`NonZeroRepr` can only be derived for enums".into(),
+                ));
+            }
+            Some(_) => {}
+            None => return Err((Span::call_site(), "expected an enum".into())),
+        }
+        i += 1;
+    };
+    let name = match tts.get(i + 1) {
+        Some(TokenTree::Ident(name)) => name.clone(),
+        _ => return Err((enum_span, "expected the enum's name".into())),
+    };
+    let body = match tts.get(i + 2) {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace => g,
+        Some(other) => {
+            return Err((
+                other.span(),
+                "`NonZeroRepr` doesn't support generic enums".into(),
+            ))
+        }
+        None => return Err((name.span(), "expected the enum's variants".into())),
+    };
+    let repr = repr.ok_or_else(|| {
+        (
+            name.span(),
+            format!(
+                "`NonZeroRepr` requires an integer `#[repr]`, such as `#[repr(u8)]` on `{}`",
+                name
+            ),
+        )
+    })?;
+
+    let mut variants = Vec::new();
+    let mut iter = body.stream().into_iter().peekable();
+    while let Some(tt) = iter.next() {
+        match tt {
+            // Attributes on the variant.
+            TokenTree::Punct(p) if p.as_char() == '#' => {
+                iter.next();
+            }
+            TokenTree::Ident(variant) => {
+                match iter.peek() {
+                    Some(TokenTree::Group(g)) => {
+                        return Err((
+                            g.span(),
+                            "`NonZeroRepr` only supports variants without fields".into(),
+                        ))
+                    }
+                    // Skip the discriminant, if any.
+                    _ => {
+                        for tt in iter.by_ref() {
+                            if matches!(&tt, TokenTree::Punct(p) if p.as_char() == ',') {
+                                break;
+                            }
+                        }
+                    }
+                }
+                variants.push(variant);
+            }
+            other => return Err((other.span(), "expected a variant".into())),
+        }
+    }
+    if variants.is_empty() {
+        return Err((
+            body.span(),
+            "`NonZeroRepr` requires at least one variant".into(),
+        ));
+    }
+    Ok(ReprEnum {
+        name,
+        repr,
+        variants,
+        krate: krate.unwrap_or_else(default_crate),
+    })
+}
+
+fn nonzero_item(krate: &[TokenTree], input: TokenStream) -> Result<TokenStream, Error> {
+    let tts: Vec<TokenTree> = input.into_iter().collect();
+    let is_punct = |tt: &TokenTree, c: char| matches!(tt, TokenTree::Punct(p) if p.as_char() == c);
+    let not_item = || {
+        (
+            Span::call_site(),
+            "`#[nonzero]` can only be used on `const` and `static` items".to_string(),
+        )
+    };
+    // Attributes and visibility, up to `const` or `static`.
+    let kw = tts
+        .iter()
+        .position(|tt| match tt {
+            TokenTree::Ident(id) => id.to_string() == "const" || id.to_string() == "static",
+            _ => false,
+        })
+        .ok_or_else(not_item)?;
+    // The name (after `mut`, for a `static mut`), and then the type.
+    let colon = match &tts[kw + 1..] {
+        [TokenTree::Ident(m), TokenTree::Ident(_), c, ..]
+            if m.to_string() == "mut" && is_punct(c, ':') =>
+        {
+            kw + 3
+        }
+        [TokenTree::Ident(_), c, ..] if is_punct(c, ':') => kw + 2,
+        _ => return Err(not_item()),
+    };
+    let eq = tts[colon..]
+        .iter()
+        .position(|tt| is_punct(tt, '='))
+        .map(|i| colon + i)
+        .ok_or_else(|| {
+            (
+                tts[colon].span(),
+                "`#[nonzero]` requires the item to have an initializer".to_string(),
+            )
+        })?;
+    let end = match tts.last() {
+        Some(last) if is_punct(last, ';') && tts.len() > eq + 2 => tts.len() - 1,
+        _ => return Err((tts[eq].span(), "expected an initializer".into())),
+    };
+
+    let ty = &tts[colon + 1..eq];
+    let expr: TokenStream = tts[eq + 1..end].iter().cloned().collect();
+    let span = tts[eq + 1].span();
+    let init = match nonzero_int_type(ty) {
+        Some(int) => macro_call(krate, int, expr, span),
+        // Keep the item, so that its uses don't cause more errors.
+        None => macro_call(
+            &root_path("core", span),
+            "compile_error",
+            TokenTree::Literal(Literal::string(
+                "`#[nonzero]` requires a `NonZero` integer type, such as `NonZeroU32`",
+            ))
+            .into(),
+            ty.first().map_or(span, TokenTree::span),
+        ),
+    };
+    let mut out: Vec<TokenTree> = tts[..=eq].to_vec();
+    out.extend(init);
+    out.push(tts[end].clone());
+    Ok(out.into_iter().collect())
+}
+
+/// Returns the integer type of a `NonZero` type, written as `NonZeroU32` or
+/// `NonZero<u32>` (with or without a path).
+fn nonzero_int_type(ty: &[TokenTree]) -> Option<&'static str> {
+    let name = match ty {
+        [.., TokenTree::Ident(nz), TokenTree::Punct(lt), TokenTree::Ident(int), TokenTree::Punct(gt)]
+            if nz.to_string() == "NonZero" && lt.as_char() == '<' && gt.as_char() == '>' =>
+        {
+            int.to_string()
+        }
+        [.., TokenTree::Ident(nz)] => nz.to_string().strip_prefix("NonZero")?.to_lowercase(),
+        _ => return None,
+    };
+    INT_TYPES.iter().copied().find(|int| *int == name)
+}
+
+/// Returns the integer type from a `repr(...)` attribute, given its brackets.
+fn repr_type(attr: &Group) -> Option<String> {
+    let mut iter = attr.stream().into_iter();
+    match (iter.next(), iter.next()) {
+        (Some(TokenTree::Ident(id)), Some(TokenTree::Group(args))) if id.to_string() == "repr" => {
+            args.stream().into_iter().find_map(|tt| match tt {
+                TokenTree::Ident(ty) if INT_TYPES.contains(&&*ty.to_string()) => {
+                    Some(ty.to_string())
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn nonzero_repr(input: TokenStream) -> Result<TokenStream, Error> {
+    let ReprEnum {
+        name,
+        repr,
+        variants,
+        krate,
+    } = parse_repr_enum(input)?;
+    let krate: TokenStream = krate.into_iter().collect();
+    // `u32` -> `NonZeroU32`, `usize` -> `NonZeroUsize`.
+    let nz = format!(
+        "::core::num::NonZero{}{}",
+        repr[..1].to_uppercase(),
+        &repr[1..]
+    );
+
+    // Note: Hacky const assert. There's one per variant, spanned to the variant
+    // so that's where the error points.
+    let mut checks = Vec::new();
+    for v in &variants {
+        let msg = format!("`{}::{}` has a zero discriminant", name, v);
+        let check = format!(
+            "let _ = [{:?}][({}::{} as {} == 0) as usize];",
+            msg, name, v, repr
+        );
+        let span = Span::call_site().located_at(v.span());
+        checks.extend(respan(check.parse().unwrap(), span));
+    }
+    let mut body = Group::new(Delimiter::Brace, checks.into_iter().collect());
+    body.set_span(Span::call_site());
+
+    let from_arms: String = variants
+        .iter()
+        .map(|v| {
+            format!(
+                "if n == {name}::{v} as {repr} {{ return ::core::option::Option::Some({name}::{v}); }}",
+                name = name,
+                v = v,
+                repr = repr
+            )
+        })
+        .collect();
+
+    let code = format!(
+        "
+        impl {name} {{
+            /// Returns the discriminant, which is checked to be nonzero at
+            /// compile time.
+            #[inline]
+            pub const fn as_nonzero(self) -> {nz} {{
+                {krate}::_private::nz_ctor::{repr}(self as {repr})
+            }}
+
+            /// Returns the variant whose discriminant is `n`, if there is one.
+            #[inline]
+            pub const fn from_nonzero(n: {nz}) -> ::core::option::Option<Self> {{
+                let n = n.get();
+                {from_arms}
+                ::core::option::Option::None
+            }}
+        }}
+        ",
+        name = name,
+        nz = nz,
+        repr = repr,
+        krate = krate,
+        from_arms = from_arms,
+    );
+    let mut out: TokenStream = "const _: () =".parse().unwrap();
+    out.extend(vec![TokenTree::Group(body)]);
+    out.extend(";".parse::<TokenStream>().unwrap());
+    out.extend(code.parse::<TokenStream>().unwrap());
+    Ok(out)
+}
+
+/// Whether the token following a `#` starts a marked literal (as opposed to,
+/// say, an attribute on a nested item or closure).
+fn is_marked_literal(next: Option<&TokenTree>) -> bool {
+    match next {
+        Some(TokenTree::Literal(_)) => true,
+        Some(TokenTree::Punct(p)) => p.as_char() == '-',
+        _ => false,
+    }
+}
+
+fn checked_literal(
+    krate: &[TokenTree],
+    neg: Option<Punct>,
+    lit: Literal,
+) -> Result<Vec<TokenTree>, Error> {
+    let span = lit.span();
+    let text = lit.to_string();
+    let is_int = text.starts_with(|c: char| c.is_ascii_digit()) && !text.contains('.');
+    let ty = INT_TYPES.iter().find(|ty| text.ends_with(**ty));
+    let ty = match ty {
+        Some(ty) if is_int => *ty,
+        _ if !is_int => {
+            return Err((
+                span,
+                format!("`#{}` is not an integer literal, only integer literals may be marked", text),
+            ))
+        }
+        _ => {
+            return Err((
+                span,
+                format!(
+                    "marked literal `#{}` needs a type suffix so its NonZero type is known (e.g. `#{}u32`)",
+                    text, text
+                ),
+            ))
+        }
+    };
+    if neg.is_some() && ty.starts_with('u') {
+        return Err((span, format!("`{}` is unsigned, and can't be negated", ty)));
+    }
+
+    let mut arg = Vec::new();
+    if let Some(neg) = neg {
+        arg.push(TokenTree::Punct(neg));
+    }
+    arg.push(TokenTree::Literal(lit));
+    Ok(macro_call(krate, ty, arg.into_iter().collect(), span))
+}
+
+/// Builds `krate::name!(args)`, where `krate` is a path (such as `::core`),
+/// with every token spanned to `span`.
+fn macro_call(krate: &[TokenTree], name: &str, args: TokenStream, span: Span) -> Vec<TokenTree> {
+    let colons = || {
+        let mut a = Punct::new(':', Spacing::Joint);
+        a.set_span(span);
+        let mut b = Punct::new(':', Spacing::Alone);
+        b.set_span(span);
+        vec![TokenTree::Punct(a), TokenTree::Punct(b)]
+    };
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, args);
+    group.set_span(span);
+
+    let mut tts = krate.to_vec();
+    tts.extend(colons());
+    tts.push(TokenTree::Ident(Ident::new(name, span)));
+    tts.push(TokenTree::Punct(bang));
+    tts.push(TokenTree::Group(group));
+    tts
+}
+
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let mut lit = Literal::string(msg);
+    lit.set_span(span);
+    macro_call(
+        &root_path("core", span),
+        "compile_error",
+        TokenTree::Literal(lit).into(),
+        span,
+    )
+    .into_iter()
+    .collect()
+}
+
+/// Builds the path `::name`.
+fn root_path(name: &str, span: Span) -> Vec<TokenTree> {
+    let mut a = Punct::new(':', Spacing::Joint);
+    a.set_span(span);
+    let mut b = Punct::new(':', Spacing::Alone);
+    b.set_span(span);
+    vec![
+        TokenTree::Punct(a),
+        TokenTree::Punct(b),
+        TokenTree::Ident(Ident::new(name, span)),
+    ]
+}
+
+/// The path to `nonzero_lit`, when the caller doesn't give one.
+fn default_crate() -> Vec<TokenTree> {
+    root_path("nonzero_lit", Span::call_site())
+}
+
+/// Splits the input of a function-like macro called through a `macro_rules!`
+/// shim in `nonzero_lit` into the path to the crate (the shim's `$crate`) and
+/// the rest, at the first `;`.
+fn split_crate(input: TokenStream) -> (Vec<TokenTree>, TokenStream) {
+    let mut krate = Vec::new();
+    let mut iter = input.into_iter();
+    for tt in iter.by_ref() {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == ';' => return (krate, iter.collect()),
+            tt => krate.push(tt),
+        }
+    }
+    // Called directly, without a path.
+    (default_crate(), krate.into_iter().collect())
+}
+
+/// Parses the `crate = path` argument used to override the path to
+/// `nonzero_lit` (for when it's been renamed, or is used through a re-export),
+/// returning `None` if there are no arguments.
+fn crate_arg(args: TokenStream, usage: &str) -> Result<Option<Vec<TokenTree>>, Error> {
+    let tts: Vec<TokenTree> = args.into_iter().collect();
+    match &tts[..] {
+        [] => Ok(None),
+        [TokenTree::Ident(kw), TokenTree::Punct(eq), path @ ..]
+            if kw.to_string() == "crate" && eq.as_char() == '=' && !path.is_empty() =>
+        {
+            Ok(Some(path.to_vec()))
+        }
+        [first, ..] => Err((
+            first.span(),
+            format!("expected `{}`, or no arguments", usage),
+        )),
+    }
+}
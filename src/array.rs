@@ -0,0 +1,385 @@
+/// Sort a constant array of integers at compile time, producing an array of
+/// the `NonZero` type matching the given integer type.
+///
+/// The first argument is the integer type (as in, `u32` for `NonZeroU32`),
+/// and the second is an array of that type: either a constant's name, or a
+/// bracketed list of constant expressions. Any zero fails the build.
+///
+/// Prefixing the arguments with `dedup:` also removes duplicates, in which case
+/// the output may be shorter than the input. Either way, the result is sorted
+/// in ascending order, so it's suitable for
+/// [`binary_search`](slice::binary_search).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const TABLE: [NonZeroU32; 4] = nonzero_lit::sorted_nonzero_array!(u32, [40, 10, 30, 20]);
+/// let keys: Vec<u32> = TABLE.iter().map(|n| n.get()).collect();
+/// assert_eq!(keys, [10, 20, 30, 40]);
+/// assert_eq!(TABLE.binary_search(&nonzero_lit::u32!(30)), Ok(2));
+/// ```
+///
+/// Works with constant arrays, and can remove duplicates.
+/// ```
+/// use core::num::NonZeroI16;
+///
+/// const RAW: [i16; 6] = [3, -1, 3, 7, -1, 3];
+/// const UNIQUE: [NonZeroI16; 3] = nonzero_lit::sorted_nonzero_array!(dedup: i16, RAW);
+/// assert_eq!(UNIQUE[0].get(), -1);
+/// assert_eq!(UNIQUE[2].get(), 7);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let a = nonzero_lit::sorted_nonzero_array!(u8, [3, 0, 1]);
+/// ```
+///
+/// Note: the array must be a constant expression.
+/// ```compile_fail
+/// let bar = [3, 2, 1];
+/// let a = nonzero_lit::sorted_nonzero_array!(u8, bar);
+/// ```
+#[macro_export]
+macro_rules! sorted_nonzero_array {
+    (@imp $dedup:expr, $int:ident, $arr:expr) => {{
+        const __E: &[$int] = &$arr;
+        {
+            const LEN: usize = __E.len();
+            const SORTED: [$int; LEN] = {
+                let mut a: [$int; LEN] = [0; LEN];
+                let mut i = 0;
+                while i < LEN {
+                    a[i] = __E[i];
+                    i += 1;
+                }
+                let mut i = 1;
+                while i < LEN {
+                    let mut j = i;
+                    while j > 0 && a[j - 1] > a[j] {
+                        let t = a[j];
+                        a[j] = a[j - 1];
+                        a[j - 1] = t;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                a
+            };
+            // Note: `SORTED[i]` is a duplicate if it's equal to the previous
+            // element.
+            const N: usize = {
+                let mut n = LEN;
+                let mut i = 1;
+                while $dedup && i < LEN {
+                    if SORTED[i] == SORTED[i - 1] {
+                        n -= 1;
+                    }
+                    i += 1;
+                }
+                n
+            };
+            const OUT: [$crate::_private::nz::$int; N] = {
+                let mut out = [$crate::_private::nz_ctor::$int(1); N];
+                let mut i = 0;
+                let mut j = 0;
+                while i < LEN {
+                    if !($dedup && i > 0 && SORTED[i] == SORTED[i - 1]) {
+                        out[j] = $crate::_private::nz_ctor::$int(SORTED[i]);
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                out
+            };
+            OUT
+        }
+    }};
+    (dedup: $int:ident, $arr:expr $(,)?) => {
+        $crate::sorted_nonzero_array!(@imp true, $int, $arr)
+    };
+    ($int:ident, $arr:expr $(,)?) => {
+        $crate::sorted_nonzero_array!(@imp false, $int, $arr)
+    };
+}
+
+/// Assert at compile time that the elements of a constant array are pairwise
+/// distinct.
+///
+/// The first argument is the element type, which is either an integer type, or
+/// `nonzero` followed by an integer type for the matching `NonZero` type (as
+/// in, `nonzero u32` for `NonZeroU32`). The second is an array of that type:
+/// either a constant's name, or a bracketed list of constant expressions.
+///
+/// This can be used anywhere an item can, including at module level. If two
+/// elements are equal, the build fails with an "index out of bounds" error
+/// whose length and index are the indices of the first such pair.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const OPCODES: [u8; 3] = [0x10, 0x20, 0x30];
+/// nonzero_lit::assert_all_distinct!(u8, OPCODES);
+///
+/// const READ: NonZeroU32 = nonzero_lit::u32!(1);
+/// const WRITE: NonZeroU32 = nonzero_lit::u32!(2);
+/// nonzero_lit::assert_all_distinct!(nonzero u32, [READ, WRITE]);
+/// ```
+///
+/// Misuse is detected at compile time. This fails with "the length is 1 but
+/// the index is 3", as elements 1 and 3 are equal.
+/// ```compile_fail
+/// nonzero_lit::assert_all_distinct!(i32, [5, 6, 7, 6]);
+/// ```
+///
+/// Note: the array must be a constant expression.
+/// ```compile_fail
+/// fn check(a: [u8; 2]) {
+///     nonzero_lit::assert_all_distinct!(u8, a);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_all_distinct {
+    (nonzero $int:ident, $arr:expr $(,)?) => {
+        const _: () = {
+            const E: &[$crate::_private::nz::$int] = &$arr;
+            const RAW: [$int; E.len()] = {
+                let mut a: [$int; E.len()] = [0; E.len()];
+                let mut i = 0;
+                while i < E.len() {
+                    a[i] = E[i].get();
+                    i += 1;
+                }
+                a
+            };
+            $crate::assert_all_distinct!($int, RAW);
+        };
+    };
+    ($int:ident, $arr:expr $(,)?) => {
+        const _: () = {
+            const E: &[$int] = &$arr;
+            // The indices of the first pair of equal elements, or `(1, 0)` if
+            // there are none.
+            const DUP: (usize, usize) = {
+                let mut dup = (1, 0);
+                let mut j = 1;
+                while dup.0 > dup.1 && j < E.len() {
+                    let mut i = 0;
+                    while i < j {
+                        if E[i] == E[j] {
+                            dup = (i, j);
+                            break;
+                        }
+                        i += 1;
+                    }
+                    j += 1;
+                }
+                dup
+            };
+            // Note: Hacky const assert. The array's length is the index of
+            // the first equal element, and the index is the second's.
+            let _ = ["assert_all_distinct! elements must be distinct"; DUP.0][DUP.1];
+        };
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fold_nonzero {
+    ($int:ident, $arr:expr, $init:expr, $op:ident, $msg:literal) => {{
+        const __E: &[$int] = &$arr;
+        {
+            const V: $int = {
+                let mut acc: $int = $init;
+                let mut i = 0;
+                while i < __E.len() {
+                    let (v, overflowed) = match acc.$op(__E[i]) {
+                        Some(v) => (v, false),
+                        None => (0, true),
+                    };
+                    // Note: Hacky const assert.
+                    let _ = [$msg][overflowed as usize];
+                    acc = v;
+                    i += 1;
+                }
+                acc
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Add up a constant array of integers at compile time, producing the
+/// `NonZero` type matching the given integer type.
+///
+/// The first argument is the integer type (as in, `u32` for `NonZeroU32`),
+/// and the second is an array of that type: either a constant's name, or a
+/// bracketed list of constant expressions. Overflow, and a sum of zero, fail
+/// the build.
+///
+/// See also [`nonzero_lit::product_nonzero!`](crate::product_nonzero).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::mem::size_of;
+/// use core::num::NonZeroUsize;
+///
+/// const HEADER_LEN: NonZeroUsize =
+///     nonzero_lit::sum_nonzero!(usize, [size_of::<u32>(), size_of::<u16>(), 2]);
+/// assert_eq!(HEADER_LEN.get(), 8);
+/// ```
+///
+/// Works with constant arrays.
+/// ```
+/// const FIELDS: [i64; 3] = [-5, 10, 20];
+/// assert_eq!(nonzero_lit::sum_nonzero!(i64, FIELDS).get(), 25);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::sum_nonzero!(i32, [5, -5]);
+/// ```
+///
+/// Including overflow.
+/// ```compile_fail
+/// let n = nonzero_lit::sum_nonzero!(u8, [200, 100]);
+/// ```
+///
+/// Note: the array must be a constant expression.
+/// ```compile_fail
+/// let bar = [1, 2];
+/// let n = nonzero_lit::sum_nonzero!(u8, bar);
+/// ```
+#[macro_export]
+macro_rules! sum_nonzero {
+    ($int:ident, $arr:expr $(,)?) => {
+        $crate::__fold_nonzero!($int, $arr, 0, checked_add, "sum_nonzero! overflowed")
+    };
+}
+
+/// Multiply together a constant array of integers at compile time, producing
+/// the `NonZero` type matching the given integer type.
+///
+/// This takes the same arguments as
+/// [`nonzero_lit::sum_nonzero!`](crate::sum_nonzero). Overflow, and a product
+/// of zero, fail the build. The product of an empty array is 1.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroUsize;
+///
+/// const DIMS: [usize; 3] = [64, 48, 4];
+/// const BUF_LEN: NonZeroUsize = nonzero_lit::product_nonzero!(usize, DIMS);
+/// assert_eq!(BUF_LEN.get(), 12288);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::product_nonzero!(u32, [4, 0, 2]);
+/// ```
+///
+/// Including overflow.
+/// ```compile_fail
+/// let n = nonzero_lit::product_nonzero!(u16, [256, 256]);
+/// ```
+#[macro_export]
+macro_rules! product_nonzero {
+    ($int:ident, $arr:expr $(,)?) => {
+        $crate::__fold_nonzero!($int, $arr, 1, checked_mul, "product_nonzero! overflowed")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __reduce_nonzero {
+    ($int:ident, $arr:expr, $cmp:tt) => {{
+        const __E: &[$int] = &$arr;
+        {
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["array must not be empty"][__E.is_empty() as usize];
+                let mut acc = __E[0];
+                let mut i = 1;
+                while i < __E.len() {
+                    if __E[i] $cmp acc {
+                        acc = __E[i];
+                    }
+                    i += 1;
+                }
+                acc
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Find the smallest element of a constant array of integers at compile time,
+/// producing the `NonZero` type matching the given integer type.
+///
+/// This takes the same arguments as
+/// [`nonzero_lit::sum_nonzero!`](crate::sum_nonzero). An empty array, or a
+/// minimum of zero, fails the build.
+///
+/// See also [`nonzero_lit::array_max_nonzero!`](crate::array_max_nonzero).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const BAUD_RATES: [u32; 4] = [115_200, 9600, 57_600, 19_200];
+/// const SLOWEST: NonZeroU32 = nonzero_lit::array_min_nonzero!(u32, BAUD_RATES);
+/// assert_eq!(SLOWEST.get(), 9600);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::array_min_nonzero!(u32, [3, 0, 1]);
+/// ```
+///
+/// Including empty arrays.
+/// ```compile_fail
+/// const EMPTY: [u32; 0] = [];
+/// let n = nonzero_lit::array_min_nonzero!(u32, EMPTY);
+/// ```
+#[macro_export]
+macro_rules! array_min_nonzero {
+    ($int:ident, $arr:expr $(,)?) => {
+        $crate::__reduce_nonzero!($int, $arr, <)
+    };
+}
+
+/// Find the largest element of a constant array of integers at compile time,
+/// producing the `NonZero` type matching the given integer type.
+///
+/// This takes the same arguments as
+/// [`nonzero_lit::sum_nonzero!`](crate::sum_nonzero). An empty array, or a
+/// maximum of zero, fails the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroUsize;
+///
+/// const MESSAGE_LENS: [usize; 3] = [12, 64, 20];
+/// const WORST_CASE: NonZeroUsize = nonzero_lit::array_max_nonzero!(usize, MESSAGE_LENS);
+/// assert_eq!(WORST_CASE.get(), 64);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::array_max_nonzero!(i8, [-3, 0, -1]);
+/// ```
+#[macro_export]
+macro_rules! array_max_nonzero {
+    ($int:ident, $arr:expr $(,)?) => {
+        $crate::__reduce_nonzero!($int, $arr, >)
+    };
+}
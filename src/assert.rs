@@ -0,0 +1,282 @@
+use core::marker::PhantomData;
+
+#[doc(hidden)]
+pub struct NotZst<T>(PhantomData<T>);
+
+impl<T> NotZst<T> {
+    #[doc(hidden)]
+    pub const CHECK: () = {
+        // Note: Hacky const assert.
+        let _ = ["assert_not_zst! type must not be zero-sized"]
+            [(core::mem::size_of::<T>() == 0) as usize];
+    };
+}
+
+/// Assert at compile time that a type isn't zero-sized.
+///
+/// The expansion is an expression of type `()`, so it can be used as a
+/// statement in a function, including a generic one (where it's checked for
+/// each type the function is instantiated with), or at module level as
+/// `const _: () = assert_not_zst!(T);`.
+///
+/// This is useful for containers which compute element strides or offsets,
+/// and would otherwise need a runtime check (or a division by zero) to exclude
+/// zero-sized types.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// struct Packet {
+///     len: u16,
+/// }
+/// const _: () = nonzero_lit::assert_not_zst!(Packet);
+///
+/// fn stride<T>() -> core::num::NonZeroUsize {
+///     nonzero_lit::assert_not_zst!(T);
+///     core::num::NonZeroUsize::new(core::mem::size_of::<T>()).unwrap()
+/// }
+/// assert_eq!(stride::<u32>().get(), 4);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// const _: () = nonzero_lit::assert_not_zst!(());
+/// ```
+///
+/// Including in generic functions, when instantiated with a zero-sized type.
+/// ```compile_fail
+/// fn stride<T>() -> usize {
+///     nonzero_lit::assert_not_zst!(T);
+///     core::mem::size_of::<T>()
+/// }
+/// let s = stride::<[u8; 0]>();
+/// ```
+#[macro_export]
+macro_rules! assert_not_zst {
+    ($t:ty $(,)?) => {
+        $crate::_private::NotZst::<$t>::CHECK
+    };
+}
+
+// Saturates `v` to `usize`, for reporting it in an error.
+const fn saturate(v: u128) -> usize {
+    if v > usize::MAX as u128 {
+        usize::MAX
+    } else {
+        v as usize
+    }
+}
+
+#[doc(hidden)]
+pub const fn fits_report(
+    negative: bool,
+    fits: bool,
+    value: u128,
+    min: i128,
+    max: u128,
+) -> (usize, usize) {
+    if fits {
+        (1, 0)
+    } else if negative {
+        // `value` is the value as a `u128`, so this is its magnitude.
+        (
+            saturate(min.wrapping_neg() as u128),
+            saturate(value.wrapping_neg()),
+        )
+    } else {
+        (saturate(max), saturate(value))
+    }
+}
+
+/// Assert at compile time that a constant fits in an integer type.
+///
+/// The first argument is the integer type, and the second is a constant
+/// expression of any integer type. This can be used anywhere an item can,
+/// including at module level, and is for when only the check is wanted, rather
+/// than a conversion.
+///
+/// If the value doesn't fit, the build fails with an "index out of bounds"
+/// error, where the length is the type's `MAX` and the index is the value (or
+/// for negative values, their magnitudes, with the length being that of the
+/// type's `MIN`). Both saturate at `usize::MAX`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const CHANNELS: u32 = 200;
+/// nonzero_lit::assert_fits!(u8, CHANNELS);
+/// nonzero_lit::assert_fits!(i8, -128i64);
+/// nonzero_lit::assert_fits!(u64, u64::MAX as u128);
+/// ```
+///
+/// Misuse is detected at compile time. This fails with "the length is 255 but
+/// the index is 300".
+/// ```compile_fail
+/// const CHANNELS: u32 = 300;
+/// nonzero_lit::assert_fits!(u8, CHANNELS);
+/// ```
+///
+/// Including negative values, for unsigned types.
+/// ```compile_fail
+/// nonzero_lit::assert_fits!(u32, -1);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// fn check(n: u32) {
+///     nonzero_lit::assert_fits!(u8, n);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_fits {
+    ($int:ident, $val:expr $(,)?) => {
+        const _: () = {
+            #[allow(unused_comparisons)]
+            const NEGATIVE: bool = ($val) < 0;
+            const FITS: bool = if NEGATIVE {
+                ($val) as i128 >= $int::MIN as i128
+            } else {
+                ($val) as i128 as u128 <= $int::MAX as u128
+            };
+            const R: (usize, usize) = $crate::_private::fits_report(
+                NEGATIVE,
+                FITS,
+                // Note: Casting through `i128` (which doesn't change the bits)
+                // keeps unsuffixed negative literals from being inferred as
+                // `u128`.
+                ($val) as i128 as u128,
+                $int::MIN as i128,
+                $int::MAX as u128,
+            );
+            // Note: Hacky const assert. The length is the limit, and the index
+            // is the value.
+            let _value_does_not_fit = [(); R.0][R.1];
+        };
+    };
+}
+
+#[doc(hidden)]
+pub const fn pow2_report(negative: bool, value: u128) -> (usize, usize) {
+    if negative {
+        (0, saturate(value.wrapping_neg()))
+    } else if value.is_power_of_two() {
+        (1, 0)
+    } else if value == 0 {
+        (0, 0)
+    } else {
+        (
+            saturate(1 << (127 - value.leading_zeros())),
+            saturate(value),
+        )
+    }
+}
+
+#[doc(hidden)]
+pub const fn aligned_report(value: i128, align: i128) -> (usize, usize) {
+    // Note: Hacky const fn assert.
+    let _ = ["assert_aligned! alignment must not be zero"][(align == 0) as usize];
+    if value % align == 0 {
+        (0, 0)
+    } else {
+        (
+            saturate(value.wrapping_abs() as u128),
+            saturate(align.wrapping_abs() as u128),
+        )
+    }
+}
+
+/// Assert at compile time that a constant is a power of two.
+///
+/// The argument is a constant expression of any integer type. This can be used
+/// anywhere an item can, including at module level.
+///
+/// If the value isn't a power of two, the build fails with an "index out of
+/// bounds" error, where the length is the largest power of two below the value
+/// and the index is the value (or for negative values, the length is zero, and
+/// the index is the value's magnitude). Both saturate at `usize::MAX`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const PAGE_SIZE: usize = 4096;
+/// nonzero_lit::assert_pow2!(PAGE_SIZE);
+/// nonzero_lit::assert_pow2!(1u8);
+/// ```
+///
+/// Misuse is detected at compile time. This fails with "the length is 32 but
+/// the index is 48".
+/// ```compile_fail
+/// const BUCKETS: u32 = 48;
+/// nonzero_lit::assert_pow2!(BUCKETS);
+/// ```
+///
+/// Including zero.
+/// ```compile_fail
+/// nonzero_lit::assert_pow2!(0);
+/// ```
+#[macro_export]
+macro_rules! assert_pow2 {
+    ($val:expr $(,)?) => {
+        const _: () = {
+            #[allow(unused_comparisons)]
+            const NEGATIVE: bool = ($val) < 0;
+            const R: (usize, usize) =
+                $crate::_private::pow2_report(NEGATIVE, ($val) as i128 as u128);
+            // Note: Hacky const assert. The length is the power of two below
+            // the value, and the index is the value.
+            let _not_a_power_of_two = [(); R.0][R.1];
+        };
+    };
+}
+
+/// Assert at compile time that a constant is a multiple of another, such as an
+/// offset or size which must be aligned.
+///
+/// The first argument is the value, and the second is the alignment (which
+/// doesn't need to be a power of two, but mustn't be zero). Both are constant
+/// expressions of any integer type, and are compared as `i128`s. This can be
+/// used anywhere an item can, including at module level.
+///
+/// If the value isn't a multiple of the alignment, the build fails with a
+/// "mismatched types" error, which expects an array whose size is the
+/// alignment, and finds one whose size is the value (with negative values using
+/// their magnitudes, and saturating at `usize::MAX`).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+///     offset: u64,
+/// }
+/// const HEADER_SIZE: usize = core::mem::size_of::<Header>();
+/// nonzero_lit::assert_aligned!(HEADER_SIZE, 8);
+/// nonzero_lit::assert_aligned!(HEADER_SIZE, core::mem::align_of::<Header>());
+/// nonzero_lit::assert_aligned!(96, 12);
+/// ```
+///
+/// Misuse is detected at compile time. This fails with "expected an array with
+/// a size of 16, found one with a size of 40".
+/// ```compile_fail
+/// const STACK_SIZE: usize = 40;
+/// nonzero_lit::assert_aligned!(STACK_SIZE, 16);
+/// ```
+///
+/// Including an alignment of zero.
+/// ```compile_fail
+/// nonzero_lit::assert_aligned!(16, 0);
+/// ```
+#[macro_export]
+macro_rules! assert_aligned {
+    ($val:expr, $align:expr $(,)?) => {
+        const _: () = {
+            const R: (usize, usize) =
+                $crate::_private::aligned_report(($val) as i128, ($align) as i128);
+            // Note: Hacky const assert. The expected size is the alignment,
+            // and the found size is the value.
+            let _misaligned: [(); R.1] = [(); R.0];
+        };
+    };
+}
@@ -0,0 +1,184 @@
+/// Define a set of bit flags, in the style of the `bitflags` crate, which is
+/// backed by a `NonZero` integer so that the empty set can't be represented.
+///
+/// This is useful for protocols where at least one flag must always be set:
+/// that invariant is enforced by the type, and `Option<Flags>` (which is how
+/// "no flags" is spelled) is the same size as the integer.
+///
+/// Each flag is checked to be nonzero at compile time. The generated type has:
+///
+/// - An associated constant for each flag, as well as `ALL` (the union of
+///   every flag) and `FLAGS` (each flag's name and value).
+/// - `bits`, returning the `NonZero` value, and `from_bits` and
+///   `from_bits_truncate`, which return `None` for an empty set (and in
+///   `from_bits`'s case, for unknown bits).
+/// - `contains` and `intersects`.
+/// - `union` (along with the `|` and `|=` operators), plus `intersection` and
+///   `difference`, which return `None` if the result is empty.
+/// - `Copy`, `Eq`, `Ord`, `Hash`, and a `Debug` which lists the flags.
+///
+/// All the methods are `const fn`s.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// nonzero_lit::nonzero_bitflags! {
+///     /// What a session is allowed to do. It's always allowed to do something.
+///     pub struct Perms: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXEC = 0b100;
+///     }
+/// }
+///
+/// let rw = Perms::READ | Perms::WRITE;
+/// assert!(rw.contains(Perms::READ));
+/// assert!(!rw.contains(Perms::EXEC));
+/// assert_eq!(rw.bits().get(), 0b011);
+/// assert_eq!(format!("{:?}", rw), "Perms(READ | WRITE)");
+///
+/// assert_eq!(Perms::from_bits(0b110), Some(Perms::WRITE | Perms::EXEC));
+/// assert_eq!(Perms::from_bits(0), None);
+/// assert_eq!(Perms::from_bits(0b1000), None);
+/// assert_eq!(Perms::from_bits_truncate(0b1001), Some(Perms::READ));
+///
+/// assert_eq!(rw.difference(Perms::READ), Some(Perms::WRITE));
+/// assert_eq!(rw.intersection(Perms::EXEC), None);
+/// assert_eq!(Perms::ALL.bits().get(), 0b111);
+/// assert_eq!(core::mem::size_of::<Option<Perms>>(), 1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// nonzero_lit::nonzero_bitflags! {
+///     struct Perms: u8 {
+///         const NONE = 0;
+///         const READ = 1;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! nonzero_bitflags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $Name:ident : $int:ident {
+            $($(#[$fmeta:meta])* const $Flag:ident = $val:expr;)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $Name($crate::_private::nz::$int);
+
+        #[allow(dead_code)]
+        impl $Name {
+            $(
+                $(#[$fmeta])*
+                pub const $Flag: Self = Self($crate::_private::nz_ctor::$int($val));
+            )+
+
+            /// The union of every flag.
+            pub const ALL: Self = Self($crate::_private::nz_ctor::$int(
+                0 $(| Self::$Flag.0.get())+
+            ));
+
+            /// The name and value of each flag, in the order they were
+            /// declared.
+            pub const FLAGS: &'static [(&'static str, Self)] = &[
+                $((stringify!($Flag), Self::$Flag),)+
+            ];
+
+            /// Returns the bits of the set.
+            #[inline]
+            pub const fn bits(self) -> $crate::_private::nz::$int {
+                self.0
+            }
+
+            /// Converts from bits, returning `None` if they're zero, or if any
+            /// bit doesn't correspond to a flag.
+            #[inline]
+            pub const fn from_bits(bits: $int) -> Option<Self> {
+                if bits & !Self::ALL.0.get() != 0 {
+                    return None;
+                }
+                Self::from_bits_truncate(bits)
+            }
+
+            /// Converts from bits, ignoring any which don't correspond to a
+            /// flag, and returning `None` if none remain.
+            #[inline]
+            pub const fn from_bits_truncate(bits: $int) -> Option<Self> {
+                match $crate::_private::nz::$int::new(bits & Self::ALL.0.get()) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+
+            /// Returns true if every flag in `other` is also in `self`.
+            #[inline]
+            pub const fn contains(self, other: Self) -> bool {
+                self.0.get() & other.0.get() == other.0.get()
+            }
+
+            /// Returns true if any flag in `other` is also in `self`.
+            #[inline]
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0.get() & other.0.get() != 0
+            }
+
+            /// Returns the flags in either `self` or `other`.
+            #[inline]
+            pub const fn union(self, other: Self) -> Self {
+                // Can't be zero, since neither side is.
+                Self($crate::_private::nz_ctor::$int(self.0.get() | other.0.get()))
+            }
+
+            /// Returns the flags in both `self` and `other`, or `None` if there
+            /// aren't any.
+            #[inline]
+            pub const fn intersection(self, other: Self) -> Option<Self> {
+                Self::from_bits_truncate(self.0.get() & other.0.get())
+            }
+
+            /// Returns the flags in `self` but not `other`, or `None` if there
+            /// aren't any.
+            #[inline]
+            pub const fn difference(self, other: Self) -> Option<Self> {
+                Self::from_bits_truncate(self.0.get() & !other.0.get())
+            }
+        }
+
+        // Check every flag, even if none of them are used.
+        const _: $Name = $Name::ALL;
+
+        impl ::core::ops::BitOr for $Name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                self.union(other)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $Name {
+            #[inline]
+            fn bitor_assign(&mut self, other: Self) {
+                *self = self.union(other);
+            }
+        }
+
+        impl ::core::fmt::Debug for $Name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(stringify!($Name))?;
+                let mut sep = "(";
+                for &(name, flag) in Self::FLAGS {
+                    if self.contains(flag) {
+                        f.write_str(sep)?;
+                        f.write_str(name)?;
+                        sep = " | ";
+                    }
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
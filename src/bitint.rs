@@ -0,0 +1,286 @@
+use core::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
+
+macro_rules! define_bitint {
+    ($(
+        $(#[$meta:meta])*
+        pub struct $Name:ident($NonZeroInt:ident: $int:ident) as $name:ident {
+            bits: $bits:literal,
+            bytes: $bytes:literal,
+            range: $min:expr, $max:expr,
+            ctor: $nz_func:ident,
+        }
+    )+) => {$(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $Name($NonZeroInt);
+
+        impl $Name {
+            /// The width of this type, in bits.
+            pub const BITS: u32 = $bits;
+            /// The smallest value of this type.
+            pub const MIN: Self = Self(crate::_private::$nz_func($min));
+            /// The largest value of this type.
+            pub const MAX: Self = Self(crate::_private::$nz_func($max));
+
+            /// Returns `None` if `n` is zero, or doesn't fit in this type.
+            #[inline]
+            pub const fn new(n: $int) -> Option<Self> {
+                if n != 0 && n >= Self::MIN.0.get() && n <= Self::MAX.0.get() {
+                    Some(Self(crate::_private::$nz_func(n)))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `None` if `n` doesn't fit in this type.
+            #[inline]
+            pub const fn from_nonzero(n: $NonZeroInt) -> Option<Self> {
+                Self::new(n.get())
+            }
+
+            /// Returns the value, in the next larger `NonZero` type.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                self.0
+            }
+
+            /// Returns the value as a big-endian byte array.
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; $bytes] {
+                let full = self.0.get().to_be_bytes();
+                let skip = full.len() - $bytes;
+                let mut out = [0; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    out[i] = full[skip + i];
+                    i += 1;
+                }
+                out
+            }
+
+            /// Returns the value as a little-endian byte array.
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; $bytes] {
+                let full = self.0.get().to_le_bytes();
+                let mut out = [0; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    out[i] = full[i];
+                    i += 1;
+                }
+                out
+            }
+
+            /// Reads a value from a big-endian byte array, returning `None` if
+            /// it's zero.
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $bytes]) -> Option<Self> {
+                let mut full = [0; core::mem::size_of::<$int>()];
+                let skip = full.len() - $bytes;
+                let mut i = 0;
+                while i < $bytes {
+                    full[skip + i] = bytes[i];
+                    i += 1;
+                }
+                Self::new(Self::extend($int::from_be_bytes(full)))
+            }
+
+            /// Reads a value from a little-endian byte array, returning `None`
+            /// if it's zero.
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Option<Self> {
+                let mut full = [0; core::mem::size_of::<$int>()];
+                let mut i = 0;
+                while i < $bytes {
+                    full[i] = bytes[i];
+                    i += 1;
+                }
+                Self::new(Self::extend($int::from_le_bytes(full)))
+            }
+
+            // Sign-extends (for signed types) the low `BITS` bits of `n`.
+            #[inline]
+            const fn extend(n: $int) -> $int {
+                let shift = (core::mem::size_of::<$int>() * 8) as u32 - $bits;
+                (n << shift) >> shift
+            }
+        }
+
+        impl core::fmt::Display for $Name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl From<$Name> for $NonZeroInt {
+            #[inline]
+            fn from(n: $Name) -> Self {
+                n.0
+            }
+        }
+
+        impl From<$Name> for $int {
+            #[inline]
+            fn from(n: $Name) -> Self {
+                n.0.get()
+            }
+        }
+
+        #[doc(hidden)]
+        pub mod $name {
+            pub type Repr = $int;
+            pub type NonZero = super::$Name;
+
+            #[inline]
+            pub const fn new(n: $int) -> super::$Name {
+                // Note: Hacky const fn assert.
+                let _ = [concat!("value is out of range for ", stringify!($name))]
+                    [(n < super::$Name::MIN.0.get() || n > super::$Name::MAX.0.get()) as usize];
+                super::$Name(crate::_private::$nz_func(n))
+            }
+        }
+    )+};
+}
+
+define_bitint! {
+    /// A 24-bit unsigned integer that is known not to equal zero, stored in a
+    /// [`NonZeroU32`].
+    ///
+    /// Use [`nonzero_lit::bitint!`](crate::bitint) to create one at compile
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::NonZeroU24;
+    ///
+    /// const SAMPLE_RATE: NonZeroU24 = nonzero_lit::bitint!(u24, 96_000);
+    /// assert_eq!(SAMPLE_RATE.to_be_bytes(), [0x01, 0x77, 0x00]);
+    /// assert_eq!(NonZeroU24::from_be_bytes([0x01, 0x77, 0x00]), Some(SAMPLE_RATE));
+    /// assert_eq!(NonZeroU24::MAX.get().get(), 0xff_ffff);
+    /// assert_eq!(NonZeroU24::new(1 << 24), None);
+    /// ```
+    pub struct NonZeroU24(NonZeroU32: u32) as u24 {
+        bits: 24,
+        bytes: 3,
+        range: 1, (1 << 24) - 1,
+        ctor: nz_u32,
+    }
+
+    /// A 48-bit unsigned integer that is known not to equal zero, stored in a
+    /// [`NonZeroU64`].
+    ///
+    /// Use [`nonzero_lit::bitint!`](crate::bitint) to create one at compile
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::NonZeroU48;
+    ///
+    /// const OUI: NonZeroU48 = nonzero_lit::bitint!(u48, 0x00_1b_63_84_45_e6);
+    /// assert_eq!(OUI.to_le_bytes(), [0xe6, 0x45, 0x84, 0x63, 0x1b, 0x00]);
+    /// assert_eq!(NonZeroU48::from_le_bytes(OUI.to_le_bytes()), Some(OUI));
+    /// assert_eq!(NonZeroU48::from_be_bytes([0; 6]), None);
+    /// ```
+    pub struct NonZeroU48(NonZeroU64: u64) as u48 {
+        bits: 48,
+        bytes: 6,
+        range: 1, (1 << 48) - 1,
+        ctor: nz_u64,
+    }
+
+    /// A 24-bit signed integer that is known not to equal zero, stored in a
+    /// [`NonZeroI32`].
+    ///
+    /// Use [`nonzero_lit::bitint!`](crate::bitint) to create one at compile
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::NonZeroI24;
+    ///
+    /// const OFFSET: NonZeroI24 = nonzero_lit::bitint!(i24, -2);
+    /// assert_eq!(OFFSET.to_be_bytes(), [0xff, 0xff, 0xfe]);
+    /// assert_eq!(NonZeroI24::from_be_bytes([0xff, 0xff, 0xfe]), Some(OFFSET));
+    /// assert_eq!(NonZeroI24::MIN.get().get(), -(1 << 23));
+    /// ```
+    pub struct NonZeroI24(NonZeroI32: i32) as i24 {
+        bits: 24,
+        bytes: 3,
+        range: -(1 << 23), (1 << 23) - 1,
+        ctor: nz_i32,
+    }
+
+    /// A 48-bit signed integer that is known not to equal zero, stored in a
+    /// [`NonZeroI64`].
+    ///
+    /// Use [`nonzero_lit::bitint!`](crate::bitint) to create one at compile
+    /// time.
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::NonZeroI48;
+    ///
+    /// const DELTA: NonZeroI48 = nonzero_lit::bitint!(i48, -(1 << 40));
+    /// assert_eq!(NonZeroI48::from_le_bytes(DELTA.to_le_bytes()), Some(DELTA));
+    /// assert_eq!(NonZeroI48::new(1 << 47), None);
+    /// ```
+    pub struct NonZeroI48(NonZeroI64: i64) as i48 {
+        bits: 48,
+        bytes: 6,
+        range: -(1 << 47), (1 << 47) - 1,
+        ctor: nz_i64,
+    }
+}
+
+/// Create a constant [`NonZeroU24`], [`NonZeroU48`], [`NonZeroI24`] or
+/// [`NonZeroI48`], checking at compile time that the value is nonzero and fits
+/// in the given width.
+///
+/// The first argument names the type, as `u24`, `u48`, `i24` or `i48`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let len = nonzero_lit::bitint!(u24, 1500);
+/// assert_eq!(len.get().get(), 1500);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::NonZeroU48;
+/// const MB: u64 = 1 << 20;
+/// const LIMIT: NonZeroU48 = nonzero_lit::bitint!(u48, 512 * MB);
+/// assert_eq!(u64::from(LIMIT), 1 << 29);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let x = nonzero_lit::bitint!(u24, 0);
+/// ```
+///
+/// Including values which don't fit in the width.
+/// ```compile_fail
+/// let x = nonzero_lit::bitint!(u24, 1 << 24);
+/// ```
+///
+/// ```compile_fail
+/// let x = nonzero_lit::bitint!(i24, -(1 << 23) - 1);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::bitint!(u24, bar);
+/// ```
+#[macro_export]
+macro_rules! bitint {
+    ($ty:ident, $val:expr $(,)?) => {{
+        const __E: $crate::_private::bitint::$ty::Repr = $val;
+        {
+            const N: $crate::_private::bitint::$ty::NonZero =
+                $crate::_private::bitint::$ty::new(__E);
+            N
+        }
+    }};
+}
@@ -0,0 +1,429 @@
+#[doc(hidden)]
+#[inline]
+pub const fn popcount_eq(ones: u32, count: u32) {
+    // Note: Hacky const fn assert.
+    let _ = ["value doesn't have the expected number of set bits"][(ones != count) as usize];
+}
+
+/// Create a `NonZero` constant, checking at compile time that exactly the
+/// given number of bits are set.
+///
+/// The first argument is the integer type, then the value, then the number of
+/// set bits. This is useful for masks which must have a particular weight,
+/// such as parity masks and k-hot encodings. The count must be at least 1,
+/// since otherwise the value would be zero.
+///
+/// For signed types, the bits of the two's complement representation are
+/// counted.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let mask = nonzero_lit::popcount_eq!(u64, 0b1011_0000, 3);
+/// assert_eq!(mask.get(), 0b1011_0000);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU16;
+/// const LANE: u32 = 4;
+/// const ONE_HOT: NonZeroU16 = nonzero_lit::popcount_eq!(u16, 1 << LANE, 1);
+/// assert_eq!(ONE_HOT.get(), 16);
+///
+/// let all = nonzero_lit::popcount_eq!(i8, -1, 8);
+/// assert_eq!(all.get(), -1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let mask = nonzero_lit::popcount_eq!(u64, 0b111, 2);
+/// ```
+///
+/// Including a count of zero.
+/// ```compile_fail
+/// let mask = nonzero_lit::popcount_eq!(u64, 0, 0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let mask = nonzero_lit::popcount_eq!(u64, bar, 2);
+/// ```
+#[macro_export]
+macro_rules! popcount_eq {
+    ($int:ident, $val:expr, $count:expr $(,)?) => {{
+        const __E: $int = $val;
+        const __C: u32 = $count;
+        {
+            const NZ: $crate::_private::nz::$int = {
+                $crate::_private::popcount_eq(__E.count_ones(), __C);
+                $crate::_private::nz_ctor::$int(__E)
+            };
+            NZ
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn morton_steps(int_bits: u32, dims: u32, bits: u32) -> usize {
+    // Note: Hacky const fn assert.
+    let _ = ["morton_masks! requires at least two dimensions"][(dims < 2) as usize];
+    // Note: Hacky const fn assert.
+    let _ = ["morton_masks! requires at least one bit per coordinate"][(bits == 0) as usize];
+    // Note: Hacky const fn assert.
+    let _ = ["morton_masks! coordinates don't fit in the type"]
+        [(dims as u64 * bits as u64 > int_bits as u64) as usize];
+    // The number of times the chunk size needs halving to get from one chunk
+    // holding every bit, to chunks of one bit.
+    let mut steps = 0;
+    while 1 << steps < bits {
+        steps += 1;
+    }
+    steps
+}
+
+#[doc(hidden)]
+pub const fn morton_mask(dims: u32, bits: u32, step: u32) -> u128 {
+    // With the bits in chunks of `2^step`, where each chunk starts at the
+    // position its first bit ends up at.
+    let mut m = 0;
+    let mut i = 0;
+    while i < bits {
+        let chunk = i >> step << step;
+        m |= 1 << (chunk * dims + (i - chunk));
+        i += 1;
+    }
+    m
+}
+
+/// Generate the masks and shifts used to interleave the bits of several
+/// coordinates into a Morton (Z-order) code, and to get them back out.
+///
+/// `morton_masks!(int, dims)` spreads `BITS / dims` bits of each coordinate
+/// across an unsigned `int`, and `morton_masks!(int, dims, bits)` spreads
+/// `bits` bits instead. The result is a tuple of `(masks, shifts)`, where
+/// `shifts` is an array of `u32`, and `masks` is an array of `NonZero` values
+/// with one more entry. Spreading a coordinate `x` looks like this:
+///
+/// ```text
+/// x &= masks[0];
+/// for i in 0..shifts.len() {
+///     x = (x | (x << shifts[i])) & masks[i + 1];
+/// }
+/// ```
+///
+/// And gathering the bits back is the same in reverse:
+///
+/// ```text
+/// x &= masks[shifts.len()];
+/// for i in (0..shifts.len()).rev() {
+///     x = (x | (x >> shifts[i])) & masks[i];
+/// }
+/// ```
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let (masks, shifts) = nonzero_lit::morton_masks!(u32, 2);
+/// let masks = masks.iter().map(|m| m.get()).collect::<Vec<_>>();
+/// assert_eq!(masks, [0xffff, 0x00ff_00ff, 0x0f0f_0f0f, 0x3333_3333, 0x5555_5555]);
+/// assert_eq!(shifts, [8, 4, 2, 1]);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// const DIMS: u32 = 3;
+/// const MORTON: ([NonZeroU64; 6], [u32; 5]) = nonzero_lit::morton_masks!(u64, DIMS);
+/// const MASKS: [NonZeroU64; 6] = MORTON.0;
+/// const SHIFTS: [u32; 5] = MORTON.1;
+///
+/// fn spread(mut x: u64) -> u64 {
+///     x &= MASKS[0].get();
+///     for i in 0..SHIFTS.len() {
+///         x = (x | (x << SHIFTS[i])) & MASKS[i + 1].get();
+///     }
+///     x
+/// }
+///
+/// fn gather(mut x: u64) -> u64 {
+///     x &= MASKS[SHIFTS.len()].get();
+///     for i in (0..SHIFTS.len()).rev() {
+///         x = (x | (x >> SHIFTS[i])) & MASKS[i].get();
+///     }
+///     x
+/// }
+///
+/// let code = spread(0b101) | spread(0b011) << 1 | spread(0b110) << 2;
+/// assert_eq!(code, 0b101_110_011);
+/// assert_eq!(gather(code >> 1), 0b011);
+/// assert_eq!(MASKS[5].get(), 0x1249_2492_4924_9249);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let (masks, shifts) = nonzero_lit::morton_masks!(u32, 3, 11);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let dims = 2;
+/// let (masks, shifts) = nonzero_lit::morton_masks!(u32, dims);
+/// ```
+#[macro_export]
+macro_rules! morton_masks {
+    ($int:ident, $dims:expr $(,)?) => {
+        $crate::morton_masks!(
+            $int,
+            $dims,
+            (::core::mem::size_of::<$int>() as u32 * 8) / ($dims)
+        )
+    };
+    ($int:ident, $dims:expr, $bits:expr $(,)?) => {{
+        const __D: u32 = $dims;
+        const __W: u32 = $bits;
+        {
+            const __S: usize =
+                $crate::_private::morton_steps(::core::mem::size_of::<$int>() as u32 * 8, __D, __W);
+            const T: ([$crate::_private::nz::$int; __S + 1], [u32; __S]) = {
+                // Note: Hacky const assert.
+                let _ = ["morton_masks! requires an unsigned type"][($int::MIN != 0) as usize];
+                let mut masks = [$crate::_private::nz_ctor::$int(1); __S + 1];
+                let mut shifts = [0; __S];
+                let mut i = 0;
+                while i <= __S {
+                    let m = $crate::_private::morton_mask(__D, __W, (__S - i) as u32);
+                    masks[i] = $crate::_private::nz_ctor::$int(m as $int);
+                    if i < __S {
+                        shifts[i] = (1 << (__S - 1 - i)) * (__D - 1);
+                    }
+                    i += 1;
+                }
+                (masks, shifts)
+            };
+            T
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn shift_check(amount: u32, bits: u32, result: bool) {
+    // Note: Hacky const fn assert.
+    let _ = ["shift amount must be less than the number of bits in the type"]
+        [(amount >= bits) as usize];
+    // Note: Hacky const fn assert.
+    let _ = ["shift moved every set bit out of the value"][(!result) as usize];
+}
+
+/// Shift a constant left, producing a `NonZero` value, and checking at compile
+/// time that the shift amount is in range and that some set bit is left.
+///
+/// The first argument is the integer type, then the value, then the shift
+/// amount (a `u32`). This catches a flag or field built by shifting into (or
+/// past) the top of the type, which would otherwise silently become zero.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let flag = nonzero_lit::shl_nonzero!(u32, 1, 31);
+/// assert_eq!(flag.get(), 0x8000_0000);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU16;
+/// const FIELD_OFFSET: u32 = 12;
+/// const FIELD: NonZeroU16 = nonzero_lit::shl_nonzero!(u16, 0xf, FIELD_OFFSET);
+/// assert_eq!(FIELD.get(), 0xf000);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let flag = nonzero_lit::shl_nonzero!(u32, 1, 32);
+/// ```
+///
+/// Including shifting every set bit out.
+/// ```compile_fail
+/// let flag = nonzero_lit::shl_nonzero!(u8, 0b10, 7);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bit = 3;
+/// let flag = nonzero_lit::shl_nonzero!(u32, 1, bit);
+/// ```
+#[macro_export]
+macro_rules! shl_nonzero {
+    ($int:ident, $val:expr, $amount:expr $(,)?) => {{
+        const __E: $int = $val;
+        const __A: u32 = $amount;
+        {
+            const NZ: $crate::_private::nz::$int = {
+                let v = __E.wrapping_shl(__A);
+                $crate::_private::shift_check(
+                    __A,
+                    ::core::mem::size_of::<$int>() as u32 * 8,
+                    v != 0,
+                );
+                $crate::_private::nz_ctor::$int(v)
+            };
+            NZ
+        }
+    }};
+}
+
+/// Shift a constant right, producing a `NonZero` value, and checking at
+/// compile time that the shift amount is in range and that some set bit is
+/// left.
+///
+/// The first argument is the integer type, then the value, then the shift
+/// amount (a `u32`). For signed types, this is an arithmetic shift, like `>>`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let page = nonzero_lit::shr_nonzero!(u64, 0x20_0000, 12);
+/// assert_eq!(page.get(), 0x200);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::{NonZeroI32, NonZeroU32};
+/// const TOP: NonZeroU32 = nonzero_lit::shr_nonzero!(u32, u32::MAX, 32 - 4);
+/// assert_eq!(TOP.get(), 0xf);
+///
+/// const NEG: NonZeroI32 = nonzero_lit::shr_nonzero!(i32, -64, 31);
+/// assert_eq!(NEG.get(), -1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let page = nonzero_lit::shr_nonzero!(u64, 0x20_0000, 64);
+/// ```
+///
+/// Including shifting every set bit out.
+/// ```compile_fail
+/// let page = nonzero_lit::shr_nonzero!(u64, 0xfff, 12);
+/// ```
+#[macro_export]
+macro_rules! shr_nonzero {
+    ($int:ident, $val:expr, $amount:expr $(,)?) => {{
+        const __E: $int = $val;
+        const __A: u32 = $amount;
+        {
+            const NZ: $crate::_private::nz::$int = {
+                let v = __E.wrapping_shr(__A);
+                $crate::_private::shift_check(
+                    __A,
+                    ::core::mem::size_of::<$int>() as u32 * 8,
+                    v != 0,
+                );
+                $crate::_private::nz_ctor::$int(v)
+            };
+            NZ
+        }
+    }};
+}
+
+// Parses a bit pattern of `0`, `1` and `x` (with `_` separators) into a
+// `(mask, value)` pair, for a type of `bits` bits.
+#[doc(hidden)]
+pub const fn parse_bitpattern(s: &str, bits: u32) -> (u128, u128) {
+    let b = s.as_bytes();
+    let mut mask: u128 = 0;
+    let mut value: u128 = 0;
+    let mut n = 0;
+    let mut i = 0;
+    while i < b.len() {
+        let c = b[i];
+        i += 1;
+        if c == b'_' {
+            continue;
+        }
+        // Note: Hacky const fn asserts, here and below.
+        let _ = ["bit pattern must only contain `0`, `1`, `x` and `_`"]
+            [!(c == b'0' || c == b'1' || c == b'x' || c == b'X') as usize];
+        let _ = ["bit pattern is longer than the type"][(n >= bits) as usize];
+        mask <<= 1;
+        value <<= 1;
+        if c != b'x' && c != b'X' {
+            mask |= 1;
+            value |= (c - b'0') as u128;
+        }
+        n += 1;
+    }
+    let _ = ["bit pattern is shorter than the type"][(n < bits) as usize];
+    let _ = ["bit pattern must have at least one `0` or `1`"][(mask == 0) as usize];
+    (mask, value)
+}
+
+/// Parse a bit pattern such as `"1010_xxxx"` at compile time, producing a
+/// `(mask, value)` pair for matching it.
+///
+/// The first argument is the (unsigned) integer type, and the second is the
+/// pattern, most significant bit first, with one character per bit: `0` or `1`
+/// for a bit which must have that value, or `x` for a bit which may have
+/// either. Underscores are ignored, for grouping. The pattern must have exactly
+/// as many bits as the type, and at least one of them must be fixed.
+///
+/// The result is a `NonZero` mask of the fixed bits, and the value those bits
+/// must have, so `n & mask.get() == value` checks whether `n` matches. This is
+/// the usual way to match instruction encodings and protocol fields.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let (mask, value) = nonzero_lit::bitpattern!(u8, "10xx_xxx1");
+/// assert_eq!(mask.get(), 0b1100_0001);
+/// assert_eq!(value, 0b1000_0001);
+/// assert_eq!(0b1011_0101 & mask.get(), value);
+/// ```
+///
+/// Works for consts, and with any unsigned type.
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// // AArch64 `B imm26`.
+/// const B_IMM: (NonZeroU32, u32) = nonzero_lit::bitpattern!(
+///     u32,
+///     "0001_01xx_xxxx_xxxx_xxxx_xxxx_xxxx_xxxx"
+/// );
+/// let is_branch = |insn: u32| insn & B_IMM.0.get() == B_IMM.1;
+/// assert!(is_branch(0x1400_0010));
+/// assert!(!is_branch(0x9400_0010));
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let p = nonzero_lit::bitpattern!(u8, "xxxx_xxxx");
+/// ```
+///
+/// Including patterns of the wrong length.
+/// ```compile_fail
+/// let p = nonzero_lit::bitpattern!(u16, "10xx_xxx1");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "10xx_xxx1";
+/// let p = nonzero_lit::bitpattern!(u8, bar);
+/// ```
+#[macro_export]
+macro_rules! bitpattern {
+    ($int:ident, $s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const T: ($crate::_private::nz::$int, $int) = {
+                // Note: Hacky const assert.
+                let _ = ["bitpattern! requires an unsigned type"][($int::MIN != 0) as usize];
+                let p = $crate::_private::parse_bitpattern(
+                    __E,
+                    ::core::mem::size_of::<$int>() as u32 * 8,
+                );
+                ($crate::_private::nz_ctor::$int(p.0 as $int), p.1 as $int)
+            };
+            T
+        }
+    }};
+}
@@ -0,0 +1,163 @@
+//! `BorshSerialize` and `BorshDeserialize` impls for this crate's
+//! `NonZero`-backed types, enabled by the `borsh` feature (which requires Rust
+//! 1.77, like `borsh` itself).
+//!
+//! Each type is encoded as the `NonZero` value it holds (so a [`Port`] is two
+//! little-endian bytes), and deserializing checks the same invariant as the
+//! type's constructors, so invalid bytes (such as a zero) are an error rather
+//! than a broken invariant.
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::{Percent, Port};
+//!
+//! let bytes = borsh::to_vec(&nonzero_lit::port!(8080)).unwrap();
+//! assert_eq!(bytes, 8080u16.to_le_bytes());
+//! assert_eq!(borsh::from_slice::<Port>(&bytes).unwrap(), nonzero_lit::port!(8080));
+//!
+//! assert!(borsh::from_slice::<Port>(&[0, 0]).is_err());
+//! assert!(borsh::from_slice::<Percent>(&[101]).is_err());
+//! ```
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    Capacity, EvenNonZero, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI48, NonZeroIndex,
+    NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU48, Percent, Port, PowerOfTwo,
+    RingCapacity,
+};
+
+macro_rules! impl_borsh {
+    ($($T:ty => $int:ident, $ctor:expr, $expected:literal;)+) => {$(
+        impl BorshSerialize for $T {
+            #[inline]
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                self.get().get().serialize(writer)
+            }
+        }
+
+        impl BorshDeserialize for $T {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                let n = $int::deserialize_reader(reader)?;
+                ($ctor)(n).ok_or_else(|| Error::new(ErrorKind::InvalidData, $expected))
+            }
+        }
+    )+};
+}
+
+// Note: `usize` and `isize` are encoded as 64-bit integers by `borsh`, which
+// doesn't implement its traits for `NonZeroUsize` and `NonZeroIsize`, so these
+// go through the primitive types.
+impl_borsh! {
+    Port => u16, Port::new_checked, "expected a nonzero port number";
+    Percent => u8, Percent::new_checked, "expected a percentage from 1 to 100";
+    NonZeroExitCode => i32, NonZeroExitCode::new_checked, "expected a nonzero exit code";
+    RingCapacity => usize, RingCapacity::new,
+        "expected a power of two no larger than `isize::MAX`";
+    Capacity => usize, Capacity::new_checked, "expected a nonzero capacity";
+    NonZeroU24 => u32, NonZeroU24::new, "expected a nonzero 24-bit integer";
+    NonZeroU48 => u64, NonZeroU48::new, "expected a nonzero 48-bit integer";
+    NonZeroI24 => i32, NonZeroI24::new, "expected a nonzero 24-bit integer";
+    NonZeroI48 => i64, NonZeroI48::new, "expected a nonzero 48-bit integer";
+    NonZeroIndex<usize> => usize, NonZeroIndex::<usize>::from_one_based,
+        "expected a one-based index";
+    NonZeroIndex<u8> => u8, NonZeroIndex::<u8>::from_one_based, "expected a one-based index";
+    NonZeroIndex<u16> => u16, NonZeroIndex::<u16>::from_one_based, "expected a one-based index";
+    NonZeroIndex<u32> => u32, NonZeroIndex::<u32>::from_one_based, "expected a one-based index";
+    NonZeroIndex<u64> => u64, NonZeroIndex::<u64>::from_one_based, "expected a one-based index";
+    PowerOfTwo<u8> => u8, PowerOfTwo::<u8>::new, "expected a power of two";
+    PowerOfTwo<u16> => u16, PowerOfTwo::<u16>::new, "expected a power of two";
+    PowerOfTwo<u32> => u32, PowerOfTwo::<u32>::new, "expected a power of two";
+    PowerOfTwo<u64> => u64, PowerOfTwo::<u64>::new, "expected a power of two";
+    PowerOfTwo<u128> => u128, PowerOfTwo::<u128>::new, "expected a power of two";
+    PowerOfTwo<usize> => usize, PowerOfTwo::<usize>::new, "expected a power of two";
+    EvenNonZero<NonZeroU8> => u8,
+        |n| NonZeroU8::new(n).and_then(EvenNonZero::<NonZeroU8>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroU16> => u16,
+        |n| NonZeroU16::new(n).and_then(EvenNonZero::<NonZeroU16>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroU32> => u32,
+        |n| NonZeroU32::new(n).and_then(EvenNonZero::<NonZeroU32>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroU64> => u64,
+        |n| NonZeroU64::new(n).and_then(EvenNonZero::<NonZeroU64>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroU128> => u128,
+        |n| NonZeroU128::new(n).and_then(EvenNonZero::<NonZeroU128>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroUsize> => usize,
+        |n| NonZeroUsize::new(n).and_then(EvenNonZero::<NonZeroUsize>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroI8> => i8,
+        |n| NonZeroI8::new(n).and_then(EvenNonZero::<NonZeroI8>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroI16> => i16,
+        |n| NonZeroI16::new(n).and_then(EvenNonZero::<NonZeroI16>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroI32> => i32,
+        |n| NonZeroI32::new(n).and_then(EvenNonZero::<NonZeroI32>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroI64> => i64,
+        |n| NonZeroI64::new(n).and_then(EvenNonZero::<NonZeroI64>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroI128> => i128,
+        |n| NonZeroI128::new(n).and_then(EvenNonZero::<NonZeroI128>::new),
+        "expected a nonzero even integer";
+    EvenNonZero<NonZeroIsize> => isize,
+        |n| NonZeroIsize::new(n).and_then(EvenNonZero::<NonZeroIsize>::new),
+        "expected a nonzero even integer";
+}
+
+impl<T: BorshSerialize> BorshSerialize for NonZeroSaturating<T> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for NonZeroSaturating<T> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // `T` is a `NonZero` type, which already rejects zero.
+        T::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for NonZeroChecked<T> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for NonZeroChecked<T> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        // `T` is a `NonZero` type, which already rejects zero.
+        T::deserialize_reader(reader).map(Self)
+    }
+}
+
+// Encoded as a `(numerator, denominator)` pair. Deserializing reduces the ratio
+// to lowest terms, like `NonZeroRatio::new`.
+impl BorshSerialize for NonZeroRatio {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.numer(), self.denom()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for NonZeroRatio {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (num, den) = <(NonZeroU32, NonZeroU32)>::deserialize_reader(reader)?;
+        Ok(NonZeroRatio::new(num, den))
+    }
+}
@@ -0,0 +1,188 @@
+use core::num::NonZeroUsize;
+
+/// The capacity of a collection or arena, in elements, which is never zero.
+///
+/// This has the overflow-checked arithmetic needed to turn a capacity into an
+/// allocation size: the methods which return a size in bytes fail if it
+/// exceeds `isize::MAX` (the largest size Rust allows for an allocation), so
+/// their result can be given to `Layout::from_size_align` as-is. All of them
+/// are usable in `const`.
+///
+/// Use [`nonzero_lit::capacity!`](crate::capacity) to create one at compile
+/// time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::Capacity;
+///
+/// const SLOTS: Capacity = nonzero_lit::capacity!(100);
+/// assert_eq!(SLOTS.bytes_for::<u64>(), Some(800));
+/// assert_eq!(SLOTS.next_pow2().map(|c| c.get().get()), Some(128));
+///
+/// let huge = Capacity::new_checked(usize::MAX / 2).unwrap();
+/// assert_eq!(huge.bytes_for::<u8>(), Some(usize::MAX / 2));
+/// assert_eq!(huge.bytes_for::<u16>(), None);
+/// assert_eq!(huge.bytes_for::<()>(), Some(0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Capacity(NonZeroUsize);
+
+impl Capacity {
+    /// Wraps a `NonZeroUsize` capacity.
+    #[inline]
+    pub const fn new(n: NonZeroUsize) -> Self {
+        Self(n)
+    }
+
+    /// Wraps a capacity, returning `None` if it's zero.
+    #[inline]
+    pub const fn new_checked(n: usize) -> Option<Self> {
+        match NonZeroUsize::new(n) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Returns the capacity.
+    #[inline]
+    pub const fn get(self) -> NonZeroUsize {
+        self.0
+    }
+
+    /// Returns the smallest power of two at least as large as this capacity,
+    /// or `None` if that overflows `usize`.
+    #[inline]
+    pub const fn next_pow2(self) -> Option<Self> {
+        let n = self.0.get();
+        let shift = core::mem::size_of::<usize>() as u32 * 8 - (n - 1).leading_zeros();
+        if shift == core::mem::size_of::<usize>() as u32 * 8 {
+            None
+        } else {
+            Some(Self(crate::_private::nz_usize(1 << shift)))
+        }
+    }
+
+    /// Returns the size in bytes of this many elements of `elem_size` bytes
+    /// each, or `None` if it exceeds `isize::MAX`.
+    #[inline]
+    pub const fn checked_mul_elem_size(self, elem_size: NonZeroUsize) -> Option<NonZeroUsize> {
+        match self.0.get().checked_mul(elem_size.get()) {
+            Some(n) if n <= isize::MAX as usize => Some(crate::_private::nz_usize(n)),
+            _ => None,
+        }
+    }
+
+    /// Returns the size in bytes of this many `T`s, or `None` if it exceeds
+    /// `isize::MAX`.
+    ///
+    /// This is zero if `T` is zero-sized.
+    #[inline]
+    pub const fn bytes_for<T>(self) -> Option<usize> {
+        match self.0.get().checked_mul(core::mem::size_of::<T>()) {
+            Some(n) if n <= isize::MAX as usize => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Capacity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<NonZeroUsize> for Capacity {
+    #[inline]
+    fn from(n: NonZeroUsize) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Capacity> for NonZeroUsize {
+    #[inline]
+    fn from(c: Capacity) -> Self {
+        c.0
+    }
+}
+
+impl From<Capacity> for usize {
+    #[inline]
+    fn from(c: Capacity) -> Self {
+        c.0.get()
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn capacity(n: usize) -> Capacity {
+    Capacity(crate::_private::nz_usize(n))
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn capacity_for(n: usize, bytes: Option<usize>) -> Capacity {
+    // Note: Hacky const fn assert.
+    let _ = ["capacity in bytes must not exceed isize::MAX"][match bytes {
+        Some(_) => 0,
+        None => 1,
+    }];
+    capacity(n)
+}
+
+/// Create a constant [`Capacity`].
+///
+/// Following the argument with `, for T` additionally checks that the
+/// capacity's size in bytes for the type `T` doesn't exceed `isize::MAX` (that
+/// is, that [`bytes_for`](crate::Capacity::bytes_for) returns `Some`).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let cap = nonzero_lit::capacity!(64);
+/// assert_eq!(cap.get().get(), 64);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::Capacity;
+/// const PAGE: usize = 4096;
+/// const NODES: Capacity = nonzero_lit::capacity!(PAGE / 32, for [u64; 4]);
+/// assert_eq!(NODES.bytes_for::<[u64; 4]>(), Some(PAGE));
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let cap = nonzero_lit::capacity!(0);
+/// ```
+///
+/// Including capacities too large for the given type.
+/// ```compile_fail
+/// let cap = nonzero_lit::capacity!(usize::MAX / 2, for u32);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::capacity!(bar);
+/// ```
+#[macro_export]
+macro_rules! capacity {
+    ($val:expr, for $T:ty $(,)?) => {{
+        const __E: usize = $val;
+        {
+            const C: $crate::Capacity = $crate::_private::capacity_for(
+                __E,
+                $crate::_private::capacity(__E).bytes_for::<$T>(),
+            );
+            C
+        }
+    }};
+    ($val:expr $(,)?) => {{
+        const __E: usize = $val;
+        {
+            const C: $crate::Capacity = $crate::_private::capacity(__E);
+            C
+        }
+    }};
+}
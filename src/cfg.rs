@@ -0,0 +1,76 @@
+/// Select a `NonZero` constant based on the target configuration, checking
+/// every branch (not just the active one) at compile time.
+///
+/// The first argument is the integer type (e.g. `u32` for a `NonZeroU32`).
+/// This is followed by a list of `cfg` predicates and values, in braces. The
+/// first predicate which holds is used, and a final `_` branch may provide a
+/// default. If no branch matches and there's no default, it's a compile error.
+///
+/// Because every branch is checked, the values must be computable on every
+/// target, not just the one they're for.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const PAGE: NonZeroU32 = nonzero_lit::cfg_nonzero!(u32, {
+///     target_os = "linux" => 4096,
+///     target_os = "windows" => 65536,
+///     _ => 8192,
+/// });
+/// # #[cfg(target_os = "linux")]
+/// assert_eq!(PAGE.get(), 4096);
+/// ```
+///
+/// Any `cfg` predicate can be used.
+/// ```
+/// let width = nonzero_lit::cfg_nonzero!(usize, {
+///     any(target_pointer_width = "16", target_pointer_width = "32") => 4,
+///     _ => 8,
+/// });
+/// assert_eq!(width.get(), core::mem::size_of::<usize>().max(4));
+/// ```
+///
+/// Misuse is detected at compile time, even in inactive branches.
+/// ```compile_fail
+/// let x = nonzero_lit::cfg_nonzero!(u32, {
+///     target_os = "some-os-that-does-not-exist" => 0,
+///     _ => 5,
+/// });
+/// ```
+///
+/// It's an error if no branch applies.
+/// ```compile_fail
+/// let x = nonzero_lit::cfg_nonzero!(u32, {
+///     target_os = "some-os-that-does-not-exist" => 1,
+/// });
+/// ```
+///
+/// Note: values must be constant expressions.
+/// ```compile_fail
+/// let bar = 3;
+/// let x = nonzero_lit::cfg_nonzero!(u32, { _ => bar });
+/// ```
+#[macro_export]
+macro_rules! cfg_nonzero {
+    ($int:ident, { $($branches:tt)+ }) => {{
+        $crate::cfg_nonzero!(@select $int, [], $($branches)+);
+        __CFG_NONZERO
+    }};
+    (@select $int:ident, [$($prev:meta),*], _ => $val:expr $(,)?) => {
+        const _: $crate::_private::nz::$int = $crate::$int!($val);
+        #[cfg(not(any($($prev),*)))]
+        const __CFG_NONZERO: $crate::_private::nz::$int = $crate::$int!($val);
+    };
+    (@select $int:ident, [$($prev:meta),*] $(,)?) => {
+        #[cfg(not(any($($prev),*)))]
+        compile_error!("no `cfg_nonzero!` branch applies to this configuration");
+    };
+    (@select $int:ident, [$($prev:meta),*], $cfg:meta => $val:expr $(, $($rest:tt)*)?) => {
+        const _: $crate::_private::nz::$int = $crate::$int!($val);
+        #[cfg(all($cfg, not(any($($prev),*))))]
+        const __CFG_NONZERO: $crate::_private::nz::$int = $crate::$int!($val);
+        $crate::cfg_nonzero!(@select $int, [$($prev,)* $cfg], $($($rest)*)?);
+    };
+}
@@ -0,0 +1,152 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::ops::{Add, Mul, Sub};
+
+/// Provides checked arithmetic on `NonZero` values, which stays within the
+/// `NonZero` types.
+///
+/// The `+`, `-` and `*` operators return `Option<NonZeroChecked<T>>`, which is
+/// `None` if the result overflows, or would be zero. The operators are also
+/// implemented for `Option<NonZeroChecked<T>>` on the left, so a chain of
+/// operations needs no unwrapping until the end, much like floating-point
+/// `NaN`.
+///
+/// This is the checked counterpart to [`NonZeroSaturating`](crate::NonZeroSaturating),
+/// and has the same operations as `const fn`s, as `checked_add` and so on.
+///
+/// # Examples
+/// ```
+/// use core::num::{NonZeroI32, NonZeroU8};
+/// use nonzero_lit::NonZeroChecked;
+///
+/// let a = NonZeroChecked(nonzero_lit::u8!(100));
+/// let b = NonZeroChecked(nonzero_lit::u8!(50));
+///
+/// assert_eq!((a + b).map(NonZeroU8::from), Some(nonzero_lit::u8!(150)));
+/// assert_eq!(a + a + b + b, None);
+/// assert_eq!(b - a, None);
+/// assert_eq!((a * b - b), None);
+///
+/// let c = NonZeroChecked(nonzero_lit::i32!(-3));
+/// assert_eq!((c * c - c).map(NonZeroI32::from), Some(nonzero_lit::i32!(12)));
+/// assert_eq!(c - c, None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroChecked<T>(pub T);
+
+impl<T: core::fmt::Display> core::fmt::Display for NonZeroChecked<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_checked {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl NonZeroChecked<$NonZeroInt> {
+            /// Wraps a `NonZero` value.
+            #[inline]
+            pub const fn new(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+
+            /// Returns the wrapped `NonZero` value.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                self.0
+            }
+
+            /// Checked addition, returning `None` on overflow, or if the result
+            /// would be zero.
+            #[inline]
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.0.get().checked_add(rhs.0.get()) {
+                    Some(n) => Self::wrap(n),
+                    None => None,
+                }
+            }
+
+            /// Checked subtraction, returning `None` on overflow, or if the
+            /// result would be zero.
+            #[inline]
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.0.get().checked_sub(rhs.0.get()) {
+                    Some(n) => Self::wrap(n),
+                    None => None,
+                }
+            }
+
+            /// Checked multiplication, returning `None` on overflow.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.0.get().checked_mul(rhs.0.get()) {
+                    Some(n) => Self::wrap(n),
+                    None => None,
+                }
+            }
+
+            #[inline]
+            const fn wrap(n: $int) -> Option<Self> {
+                match $NonZeroInt::new(n) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+        }
+
+        impl From<$NonZeroInt> for NonZeroChecked<$NonZeroInt> {
+            #[inline]
+            fn from(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+        }
+
+        impl From<NonZeroChecked<$NonZeroInt>> for $NonZeroInt {
+            #[inline]
+            fn from(n: NonZeroChecked<$NonZeroInt>) -> Self {
+                n.0
+            }
+        }
+
+        impl_checked!(@op $NonZeroInt, Add, add, checked_add);
+        impl_checked!(@op $NonZeroInt, Sub, sub, checked_sub);
+        impl_checked!(@op $NonZeroInt, Mul, mul, checked_mul);
+    )+};
+    (@op $NonZeroInt:ident, $Op:ident, $op:ident, $method:ident) => {
+        impl $Op for NonZeroChecked<$NonZeroInt> {
+            type Output = Option<Self>;
+            #[inline]
+            fn $op(self, rhs: Self) -> Option<Self> {
+                self.$method(rhs)
+            }
+        }
+
+        impl $Op<NonZeroChecked<$NonZeroInt>> for Option<NonZeroChecked<$NonZeroInt>> {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: NonZeroChecked<$NonZeroInt>) -> Self {
+                match self {
+                    Some(lhs) => lhs.$method(rhs),
+                    None => None,
+                }
+            }
+        }
+    };
+}
+
+impl_checked! {
+    NonZeroUsize => usize;
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroIsize => isize;
+    NonZeroI8 => i8;
+    NonZeroI16 => i16;
+    NonZeroI32 => i32;
+    NonZeroI64 => i64;
+    NonZeroI128 => i128;
+}
@@ -0,0 +1,91 @@
+use core::num::NonZeroU32;
+
+#[doc(hidden)]
+#[inline]
+pub const fn codepoint(n: u32) -> NonZeroU32 {
+    // Note: Hacky const fn asserts.
+    let _ = ["codepoint must not be U+0000"][(n == 0) as usize];
+    let _ = ["codepoint must not be a surrogate"][(n >= 0xD800 && n <= 0xDFFF) as usize];
+    let _ = ["codepoint is out of range (above U+10FFFF)"][(n > 0x10FFFF) as usize];
+    crate::_private::nz_u32(n)
+}
+
+// Parses the hex digits following `U+`.
+#[doc(hidden)]
+pub const fn parse_codepoint(s: &str) -> NonZeroU32 {
+    let b = s.as_bytes();
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["expected 1 to 6 hex digits after `U+`"][(b.is_empty() || b.len() > 6) as usize];
+    let mut n = 0u32;
+    let mut i = 0;
+    while i < b.len() {
+        let c = b[i];
+        let _ = ["expected a hex digit after `U+`"][!c.is_ascii_hexdigit() as usize];
+        let d = if c <= b'9' {
+            c - b'0'
+        } else {
+            (c | 0x20) - b'a' + 10
+        };
+        n = (n << 4) | d as u32;
+        i += 1;
+    }
+    codepoint(n)
+}
+
+/// Create a constant Unicode scalar value as a `NonZeroU32`, from either a
+/// `char` or the `U+XXXX` notation.
+///
+/// U+0000 is rejected at compile time, as are surrogates and values above
+/// U+10FFFF (which a `char` can't hold, but `U+XXXX` could name).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const A: NonZeroU32 = nonzero_lit::codepoint!('A');
+/// const SMILE: NonZeroU32 = nonzero_lit::codepoint!(U+1F600);
+/// assert_eq!(A.get(), 0x41);
+/// assert_eq!(SMILE.get(), '😀' as u32);
+/// assert_eq!(nonzero_lit::codepoint!(U+00E9).get(), 'é' as u32);
+/// ```
+///
+/// Works for consts, and the `char` can be any const expression.
+/// ```
+/// const REPLACEMENT: char = char::REPLACEMENT_CHARACTER;
+/// assert_eq!(nonzero_lit::codepoint!(REPLACEMENT), nonzero_lit::codepoint!(U+FFFD));
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let c = nonzero_lit::codepoint!('\0');
+/// ```
+///
+/// ```compile_fail
+/// let c = nonzero_lit::codepoint!(U+D800);
+/// ```
+///
+/// ```compile_fail
+/// let c = nonzero_lit::codepoint!(U+110000);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 'a';
+/// let c = nonzero_lit::codepoint!(bar);
+/// ```
+#[macro_export]
+macro_rules! codepoint {
+    (U+ $hex:tt $(,)?) => {{
+        const NZ: $crate::_private::NonZeroU32 =
+            $crate::_private::parse_codepoint(stringify!($hex));
+        NZ
+    }};
+    ($c:expr $(,)?) => {{
+        const __E: char = $c;
+        {
+            const NZ: $crate::_private::NonZeroU32 = $crate::_private::codepoint(__E as u32);
+            NZ
+        }
+    }};
+}
@@ -0,0 +1,210 @@
+use core::iter::FilterMap;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The primitive integer types, which each have a `NonZero` counterpart.
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait ZeroableInt: sealed::Sealed + Copy {
+    /// The matching `NonZero` type (e.g. `NonZeroU32` for `u32`).
+    type NonZero: Copy;
+
+    /// Converts to the `NonZero` type, returning `None` if `self` is zero.
+    fn to_nonzero(self) -> Option<Self::NonZero>;
+
+    /// Converts from the `NonZero` type.
+    fn from_nonzero(n: Self::NonZero) -> Self;
+}
+
+macro_rules! impl_zeroable_int {
+    ($($int:ident => $NonZeroInt:ident;)+) => {$(
+        impl sealed::Sealed for $int {}
+
+        impl ZeroableInt for $int {
+            type NonZero = $NonZeroInt;
+
+            #[inline]
+            fn to_nonzero(self) -> Option<$NonZeroInt> {
+                $NonZeroInt::new(self)
+            }
+
+            #[inline]
+            fn from_nonzero(n: $NonZeroInt) -> Self {
+                n.get()
+            }
+        }
+    )+};
+}
+
+impl_zeroable_int! {
+    u8 => NonZeroU8;
+    u16 => NonZeroU16;
+    u32 => NonZeroU32;
+    u64 => NonZeroU64;
+    u128 => NonZeroU128;
+    usize => NonZeroUsize;
+    i8 => NonZeroI8;
+    i16 => NonZeroI16;
+    i32 => NonZeroI32;
+    i64 => NonZeroI64;
+    i128 => NonZeroI128;
+    isize => NonZeroIsize;
+}
+
+/// The error returned when converting a sequence of integers to `NonZero`
+/// values fails, holding the index of the first zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ZeroAt(pub usize);
+
+impl core::fmt::Display for ZeroAt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "found a zero at index {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZeroAt {}
+
+/// Extension methods for converting iterators of integers into iterators (or
+/// collections) of `NonZero` values.
+///
+/// This is implemented for every iterator whose items are a primitive integer
+/// type.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU32;
+/// use nonzero_lit::{CollectNonZero, ZeroAt};
+///
+/// let ok: Result<Vec<NonZeroU32>, ZeroAt> = vec![3u32, 1, 2].into_iter().collect_nonzero();
+/// assert_eq!(ok.unwrap()[0].get(), 3);
+///
+/// let err: Result<Vec<NonZeroU32>, ZeroAt> = [5u32, 0, 7].iter().copied().collect_nonzero();
+/// assert_eq!(err, Err(ZeroAt(1)));
+///
+/// let filtered: Vec<NonZeroU32> = [0u32, 4, 0, 8].iter().copied().filter_nonzero().collect();
+/// assert_eq!(filtered, [nonzero_lit::u32!(4), nonzero_lit::u32!(8)]);
+/// ```
+pub trait CollectNonZero: Iterator + Sized
+where
+    Self::Item: ZeroableInt,
+{
+    /// Converts each item to its `NonZero` type, skipping any zeros.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn filter_nonzero(
+        self,
+    ) -> FilterMap<Self, fn(Self::Item) -> Option<<Self::Item as ZeroableInt>::NonZero>> {
+        self.filter_map(ZeroableInt::to_nonzero)
+    }
+
+    /// Converts each item to its `NonZero` type, and collects them into `C`.
+    ///
+    /// Stops at the first zero, returning its index as the error.
+    #[inline]
+    fn collect_nonzero<C>(self) -> Result<C, ZeroAt>
+    where
+        C: core::iter::FromIterator<<Self::Item as ZeroableInt>::NonZero>,
+    {
+        self.enumerate()
+            .map(|(i, n)| n.to_nonzero().ok_or(ZeroAt(i)))
+            .collect()
+    }
+}
+
+impl<I: Iterator> CollectNonZero for I where I::Item: ZeroableInt {}
+
+/// Converts a `Vec` of integers into a `Vec` of the matching `NonZero` type,
+/// returning the index of the first zero if there is one.
+///
+/// This reuses the allocation on Rust versions where `collect` can do so.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::ZeroAt;
+///
+/// let sizes = nonzero_lit::try_nonzero_vec(vec![4096u32, 512]).unwrap();
+/// assert_eq!(sizes[1].get(), 512);
+///
+/// assert_eq!(nonzero_lit::try_nonzero_vec(vec![1u8, 2, 0]), Err(ZeroAt(2)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn try_nonzero_vec<T: ZeroableInt>(v: Vec<T>) -> Result<Vec<T::NonZero>, ZeroAt> {
+    v.into_iter().collect_nonzero()
+}
+
+/// Converts a `Vec` of `NonZero` values back into a `Vec` of the matching
+/// integer type.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// let ids = vec![nonzero_lit::u64!(10), nonzero_lit::u64!(20)];
+/// let raw: Vec<u64> = nonzero_lit::from_nonzero_vec(ids);
+/// assert_eq!(raw, [10, 20]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn from_nonzero_vec<T: ZeroableInt>(v: Vec<T::NonZero>) -> Vec<T> {
+    v.into_iter().map(T::from_nonzero).collect()
+}
+
+/// Convert a runtime value to its `NonZero` type, using a fallback constant
+/// (which is checked to be nonzero at compile time) if the value is zero.
+///
+/// The first argument is the integer type, which can be any of the primitive
+/// integer types. The value may be a runtime value, but the fallback must be a
+/// constant expression, as it's created with the matching literal macro (such
+/// as [`nonzero_lit::u32!`](crate::u32)).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroUsize;
+///
+/// fn buffer_size(configured: usize) -> NonZeroUsize {
+///     nonzero_lit::nonzero_or!(usize, configured, 64)
+/// }
+/// assert_eq!(buffer_size(4096).get(), 4096);
+/// assert_eq!(buffer_size(0).get(), 64);
+///
+/// let retries: u8 = "0".parse().unwrap();
+/// assert_eq!(nonzero_lit::nonzero_or!(u8, retries, 3).get(), 3);
+/// ```
+///
+/// A zero fallback is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::nonzero_or!(u32, 5, 0);
+/// ```
+///
+/// As is a fallback which is truncated to zero by the type.
+/// ```compile_fail
+/// const K: u16 = 256;
+/// let n = nonzero_lit::nonzero_or!(u8, 5, K as u8);
+/// ```
+///
+/// Note: the fallback must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let n = nonzero_lit::nonzero_or!(u32, 5, bar);
+/// ```
+#[macro_export]
+macro_rules! nonzero_or {
+    ($int:ident, $val:expr, $fallback:expr $(,)?) => {
+        match $crate::_private::nz::$int::new($val) {
+            ::core::option::Option::Some(n) => n,
+            ::core::option::Option::None => $crate::$int!($fallback),
+        }
+    };
+}
@@ -0,0 +1,146 @@
+use core::num::NonZeroU32;
+
+#[doc(hidden)]
+#[inline]
+pub const fn color(rgba: u32) -> NonZeroU32 {
+    // Note: Hacky const fn assert.
+    let _ = ["color must not be fully transparent black (zero)"][(rgba == 0) as usize];
+    crate::_private::nz_u32(rgba)
+}
+
+const fn hex_digit(c: u8) -> u32 {
+    // Note: Hacky const fn assert.
+    let _ = ["expected a hex digit in color"][!c.is_ascii_hexdigit() as usize];
+    if c <= b'9' {
+        (c - b'0') as u32
+    } else {
+        ((c | 0x20) - b'a' + 10) as u32
+    }
+}
+
+// Parses the source text of a literal, which is either a `"#rrggbb"`-style
+// string, or an integer.
+#[doc(hidden)]
+pub const fn parse_color(s: &str) -> NonZeroU32 {
+    let b = s.as_bytes();
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["expected a string or integer literal"][b.is_empty() as usize];
+    if b[0] != b'"' {
+        return color(parse_color_int(b));
+    }
+    let _ = ["color string must start with `#`"][(b.len() < 2 || b[1] != b'#') as usize];
+    let _ = ["unexpected characters after color string"][(b[b.len() - 1] != b'"') as usize];
+    let (start, n) = (2, b.len() - 3);
+    let short = n == 3 || n == 4;
+    let _ = ["color string must be `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`"]
+        [!(short || n == 6 || n == 8) as usize];
+    let mut rgba: u32 = 0;
+    let mut i = 0;
+    while i < n {
+        let d = hex_digit(b[start + i]);
+        rgba = if short {
+            (rgba << 8) | (d * 0x11)
+        } else {
+            (rgba << 4) | d
+        };
+        i += 1;
+    }
+    // Colors without an alpha channel are opaque.
+    if n == 3 || n == 6 {
+        rgba = (rgba << 8) | 0xff;
+    }
+    color(rgba)
+}
+
+// Parses an integer literal in any base, with `_` separators and an optional
+// `u32` suffix.
+const fn parse_color_int(b: &[u8]) -> u32 {
+    let mut end = b.len();
+    if end > 3 && b[end - 3] == b'u' && b[end - 2] == b'3' && b[end - 1] == b'2' {
+        end -= 3;
+    }
+    let (radix, mut i) = if end > 2 && b[0] == b'0' {
+        match b[1] {
+            b'x' => (16, 2),
+            b'o' => (8, 2),
+            b'b' => (2, 2),
+            _ => (10, 0),
+        }
+    } else {
+        (10, 0)
+    };
+    let mut n: u64 = 0;
+    while i < end {
+        if b[i] != b'_' {
+            // Note: Hacky const fn asserts.
+            let _ = ["expected an integer literal"][!b[i].is_ascii_hexdigit() as usize];
+            let d = hex_digit(b[i]) as u64;
+            let _ = ["invalid digit in integer literal"][(d >= radix) as usize];
+            n = n * radix + d;
+            let _ = ["color doesn't fit in a `u32`"][(n > u32::MAX as u64) as usize];
+        }
+        i += 1;
+    }
+    n as u32
+}
+
+/// Create a constant packed RGBA color as a `NonZeroU32`, from either a
+/// hex color string or an integer.
+///
+/// The color is packed as `0xRRGGBBAA`. A string is `#` followed by
+/// `rgb`, `rgba`, `rrggbb` or `rrggbbaa` hex digits, as in CSS, and a color
+/// without an alpha component is opaque. An integer (either a literal, or any
+/// `u32` const expression) is used as is.
+///
+/// The fully-zero value (transparent black) is rejected at compile time, as
+/// it's often reserved as a sentinel, such as for "no color".
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let orange = nonzero_lit::color!("#ff8800");
+/// assert_eq!(orange.get(), 0xff88_00ff);
+/// assert_eq!(nonzero_lit::color!(0xff8800ff), orange);
+/// assert_eq!(nonzero_lit::color!("#f80"), orange);
+/// ```
+///
+/// Works for consts, and integers can be any const expression.
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const SHADOW: NonZeroU32 = nonzero_lit::color!("#00000080");
+/// const RED: u32 = 0xff00_0000;
+/// const OPAQUE_RED: NonZeroU32 = nonzero_lit::color!(RED | 0xff);
+/// assert_eq!(SHADOW.get().to_be_bytes(), [0, 0, 0, 0x80]);
+/// assert_eq!(OPAQUE_RED.get(), 0xff00_00ff);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let c = nonzero_lit::color!("#00000000");
+/// ```
+///
+/// Including malformed strings.
+/// ```compile_fail
+/// let c = nonzero_lit::color!("#ff880");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 0xff8800ff;
+/// let c = nonzero_lit::color!(bar);
+/// ```
+#[macro_export]
+macro_rules! color {
+    ($lit:literal $(,)?) => {{
+        const NZ: $crate::_private::NonZeroU32 = $crate::_private::parse_color(stringify!($lit));
+        NZ
+    }};
+    ($val:expr $(,)?) => {{
+        const __E: u32 = $val;
+        {
+            const NZ: $crate::_private::NonZeroU32 = $crate::_private::color(__E);
+            NZ
+        }
+    }};
+}
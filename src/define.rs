@@ -0,0 +1,148 @@
+/// Define literal macros like this crate's own, for your own types with an
+/// invariant which can be checked in a `const fn`.
+///
+/// Each entry is `name => constructor;`, and defines a macro `name!` which
+/// calls the constructor with a constant expression, evaluating it at compile
+/// time. The constructor must be a `const fn` taking one argument, and should
+/// reject invalid values by failing const evaluation, such as with the
+/// `["message"][cond as usize]` indexing trick this crate uses (or `panic!`,
+/// on Rust 1.57 and later).
+///
+/// The generated macros are ordinary `macro_rules!` macros, and are only in
+/// scope after the `define_nonzero_macros!` invocation. Attributes (such as
+/// doc comments or `#[macro_export]`) can be given before each entry.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// pub struct MyId(u32);
+///
+/// impl MyId {
+///     pub const fn new(n: u32) -> Self {
+///         // Note: Hacky const fn assert.
+///         let _ = ["MyId must be below 1000"][(n >= 1000) as usize];
+///         MyId(n)
+///     }
+/// }
+///
+/// nonzero_lit::define_nonzero_macros! {
+///     /// Create a `MyId` checked at compile time.
+///     my_id => MyId::new;
+/// }
+///
+/// const ADMIN: MyId = my_id!(1);
+/// let guest = my_id!(10 * 99);
+/// assert_eq!(guest, MyId(990));
+/// # let _ = ADMIN;
+/// ```
+///
+/// Misuse of the generated macro is detected at compile time.
+/// ```compile_fail
+/// # pub struct MyId(u32);
+/// # impl MyId {
+/// #     pub const fn new(n: u32) -> Self {
+/// #         let _ = ["MyId must be below 1000"][(n >= 1000) as usize];
+/// #         MyId(n)
+/// #     }
+/// # }
+/// nonzero_lit::define_nonzero_macros! {
+///     my_id => MyId::new;
+/// }
+/// let id = my_id!(1000);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// # pub struct MyId(u32);
+/// # impl MyId {
+/// #     pub const fn new(n: u32) -> Self {
+/// #         MyId(n)
+/// #     }
+/// # }
+/// nonzero_lit::define_nonzero_macros! {
+///     my_id => MyId::new;
+/// }
+/// let bar = 3;
+/// let id = my_id!(bar);
+/// ```
+#[macro_export]
+macro_rules! define_nonzero_macros {
+    (@imp ($d:tt) $(#[$meta:meta])* $name:ident => $ctor:path) => {
+        $(#[$meta])*
+        macro_rules! $name {
+            ($d val:expr $d(,)?) => {{
+                // Force the constructor's checks to run at compile time.
+                const _: () = {
+                    let _ = $ctor($d val);
+                };
+                $ctor($d val)
+            }};
+        }
+    };
+    ($($(#[$meta:meta])* $name:ident => $ctor:path;)+) => {$(
+        $crate::define_nonzero_macros!(@imp ($) $(#[$meta])* $name => $ctor);
+    )+};
+}
+
+/// Evaluate a constant expression at compile time, failing the build if a
+/// predicate rejects it.
+///
+/// This is the general form of the check done by the other macros in this
+/// crate. For example, `nonzero_lit::u32!(X)` checks the same thing as
+/// `force_const!(u32, X, |x| x != 0, "N must not be zero")`, before converting
+/// to `NonZeroU32`. It's intended for use by other macros, which can provide
+/// a more specific error message than the one from `assert!` (which can't be
+/// used in `const` before Rust 1.57).
+///
+/// The arguments are the type of the expression, the expression, a predicate
+/// written like a closure (but which must be usable in `const`), and the
+/// message. The expression's value is returned.
+///
+/// The message isn't printed directly, since Rust 1.47 can't format a message
+/// during const evaluation. Instead, the compiler reports an out of bounds
+/// index, pointing at the invocation (where the message is written).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const PAGE: usize = nonzero_lit::force_const!(
+///     usize,
+///     1 << 12,
+///     |n| n.is_power_of_two(),
+///     "page size must be a power of two"
+/// );
+/// assert_eq!(PAGE, 4096);
+/// ```
+///
+/// Works for any type which can be used in `const`.
+/// ```
+/// let name = nonzero_lit::force_const!(&str, "eth0", |s| s.len() <= 15, "name is too long");
+/// assert_eq!(name, "eth0");
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::force_const!(u32, 7, |n| n % 2 == 0, "must be even");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 4;
+/// let n = nonzero_lit::force_const!(u32, bar, |n| n % 2 == 0, "must be even");
+/// ```
+#[macro_export]
+macro_rules! force_const {
+    ($ty:ty, $val:expr, |$v:ident| $pred:expr, $msg:literal $(,)?) => {{
+        const __E: $ty = $val;
+        {
+            const V: $ty = {
+                let $v = __E;
+                // Note: Hacky const fn assert.
+                let _ = [$msg][(!($pred)) as usize];
+                __E
+            };
+            V
+        }
+    }};
+}
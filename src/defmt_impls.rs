@@ -0,0 +1,109 @@
+//! `defmt::Format` impls for this crate's wrapper types, enabled by the `defmt`
+//! feature (which requires Rust 1.76, like `defmt` itself).
+//!
+//! Each type logs the way its `Display` impl writes it (so a [`Percent`] logs
+//! as `75%`, and a [`NonZeroRatio`] as `3/4`), with the value encoded as a
+//! `NonZero` integer, rather than formatted on the device.
+//!
+//! # Examples
+//! ```
+//! fn log<T: defmt::Format>(_: T) {}
+//!
+//! log(nonzero_lit::port!(8080));
+//! log(nonzero_lit::percent!(75));
+//! log(nonzero_lit::NonZeroSaturating(nonzero_lit::u8!(3)));
+//! ```
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use defmt::{Format, Formatter};
+
+use crate::{
+    Capacity, EvenNonZero, NonEmptyStr, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI48,
+    NonZeroIndex, NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU48, NulFreeStr, Percent,
+    Port, PowerOfTwo, RingCapacity,
+};
+
+macro_rules! impl_format_get {
+    ($($T:ty => $fmt:literal;)+) => {$(
+        impl Format for $T {
+            #[inline]
+            fn format(&self, f: Formatter<'_>) {
+                defmt::write!(f, $fmt, self.get())
+            }
+        }
+    )+};
+}
+
+impl_format_get! {
+    Port => "{}";
+    Percent => "{}%";
+    NonZeroExitCode => "{}";
+    RingCapacity => "{}";
+    Capacity => "{}";
+    NonZeroU24 => "{}";
+    NonZeroU48 => "{}";
+    NonZeroI24 => "{}";
+    NonZeroI48 => "{}";
+    NonZeroIndex<usize> => "{}";
+    NonZeroIndex<u8> => "{}";
+    NonZeroIndex<u16> => "{}";
+    NonZeroIndex<u32> => "{}";
+    NonZeroIndex<u64> => "{}";
+    PowerOfTwo<u8> => "{}";
+    PowerOfTwo<u16> => "{}";
+    PowerOfTwo<u32> => "{}";
+    PowerOfTwo<u64> => "{}";
+    PowerOfTwo<u128> => "{}";
+    PowerOfTwo<usize> => "{}";
+    EvenNonZero<NonZeroU8> => "{}";
+    EvenNonZero<NonZeroU16> => "{}";
+    EvenNonZero<NonZeroU32> => "{}";
+    EvenNonZero<NonZeroU64> => "{}";
+    EvenNonZero<NonZeroU128> => "{}";
+    EvenNonZero<NonZeroUsize> => "{}";
+    EvenNonZero<NonZeroI8> => "{}";
+    EvenNonZero<NonZeroI16> => "{}";
+    EvenNonZero<NonZeroI32> => "{}";
+    EvenNonZero<NonZeroI64> => "{}";
+    EvenNonZero<NonZeroI128> => "{}";
+    EvenNonZero<NonZeroIsize> => "{}";
+}
+
+impl<T: Format> Format for NonZeroSaturating<T> {
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        self.0.format(f)
+    }
+}
+
+impl<T: Format> Format for NonZeroChecked<T> {
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        self.0.format(f)
+    }
+}
+
+impl Format for NonZeroRatio {
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{}/{}", self.numer(), self.denom())
+    }
+}
+
+impl Format for NonEmptyStr<'_> {
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
+
+impl Format for NulFreeStr {
+    #[inline]
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
@@ -0,0 +1,168 @@
+/// Define a module of error codes as `NonZeroI32` constants, along with lookup
+/// functions for their names and descriptions.
+///
+/// Each entry is written `NAME = value => "description"`, where the value is
+/// any constant expression, and is checked to be nonzero at compile time. The
+/// description becomes the constant's documentation, and other attributes are
+/// passed through.
+///
+/// The generated module contains:
+///
+/// - A `pub const NAME: NonZeroI32` for each entry.
+/// - `pub const CODES: &[NonZeroI32]`, listing every code in the order given.
+/// - `pub const fn name(code: NonZeroI32) -> Option<&'static str>`, returning
+///   the name of the constant for a code.
+/// - `pub const fn description(code: NonZeroI32) -> Option<&'static str>`,
+///   returning its description.
+///
+/// Several names may share a value (like `EAGAIN` and `EWOULDBLOCK` on many
+/// platforms), in which case the lookups find the first one. Expressions in the
+/// values can refer to items in the enclosing module.
+///
+/// A table of the traditional Unix codes is available as [`unix_errno`].
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroI32;
+///
+/// nonzero_lit::errno! {
+///     /// Errors returned by the driver.
+///     pub mod driver_error {
+///         ETIMEOUT = 1 => "Timed out waiting for the device";
+///         ENODEVICE = 2 => "No device is attached";
+///         #[deprecated]
+///         EGONE = ENODEVICE.get() => "No device is attached";
+///     }
+/// }
+///
+/// fn poll(attached: bool) -> Result<(), NonZeroI32> {
+///     if attached { Ok(()) } else { Err(driver_error::ENODEVICE) }
+/// }
+///
+/// let err = poll(false).unwrap_err();
+/// assert_eq!(driver_error::name(err), Some("ENODEVICE"));
+/// assert_eq!(driver_error::description(err), Some("No device is attached"));
+/// assert_eq!(driver_error::name(nonzero_lit::i32!(99)), None);
+/// assert_eq!(driver_error::CODES.len(), 3);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// nonzero_lit::errno! {
+///     mod errors {
+///         EOK = 0 => "Not actually an error";
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! errno {
+    (
+        $(#[$mod_meta:meta])*
+        $vis:vis mod $module:ident {
+            $($(#[$meta:meta])* $NAME:ident = $val:expr => $desc:literal;)+
+        }
+    ) => {
+        $(#[$mod_meta])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[doc = $desc]
+                $(#[$meta])*
+                pub const $NAME: $crate::_private::NonZeroI32 = $crate::_private::nz_i32($val);
+            )+
+
+            /// Every code in this module, in the order they were defined.
+            #[allow(deprecated)]
+            pub const CODES: &[$crate::_private::NonZeroI32] = &[$($NAME),+];
+
+            #[allow(deprecated)]
+            const ENTRIES: &[(i32, &str, &str)] = &[$(($NAME.get(), stringify!($NAME), $desc)),+];
+
+            const fn find(code: $crate::_private::NonZeroI32) -> Option<usize> {
+                let mut i = 0;
+                while i < ENTRIES.len() {
+                    if ENTRIES[i].0 == code.get() {
+                        return Some(i);
+                    }
+                    i += 1;
+                }
+                None
+            }
+
+            /// Returns the name of the constant for `code`, if it's defined in
+            /// this module.
+            #[inline]
+            pub const fn name(code: $crate::_private::NonZeroI32) -> Option<&'static str> {
+                match find(code) {
+                    Some(i) => Some(ENTRIES[i].1),
+                    None => None,
+                }
+            }
+
+            /// Returns the description of `code`, if it's defined in this
+            /// module.
+            #[inline]
+            pub const fn description(code: $crate::_private::NonZeroI32) -> Option<&'static str> {
+                match find(code) {
+                    Some(i) => Some(ENTRIES[i].2),
+                    None => None,
+                }
+            }
+        }
+    };
+}
+
+crate::errno! {
+    /// The traditional Unix error codes, as `NonZeroI32` constants.
+    ///
+    /// These are the codes from 1 to 34 which have the same values on Linux,
+    /// macOS, and the BSDs (so `EAGAIN`, which is 11 on Linux but 35 on
+    /// macOS, is not included). For anything else, define your own table with
+    /// [`nonzero_lit::errno!`](crate::errno).
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::unix_errno;
+    ///
+    /// assert_eq!(unix_errno::ENOENT.get(), 2);
+    /// assert_eq!(unix_errno::name(unix_errno::EACCES), Some("EACCES"));
+    /// assert_eq!(unix_errno::description(nonzero_lit::i32!(22)), Some("Invalid argument"));
+    /// ```
+    pub mod unix_errno {
+        EPERM = 1 => "Operation not permitted";
+        ENOENT = 2 => "No such file or directory";
+        ESRCH = 3 => "No such process";
+        EINTR = 4 => "Interrupted system call";
+        EIO = 5 => "Input/output error";
+        ENXIO = 6 => "No such device or address";
+        E2BIG = 7 => "Argument list too long";
+        ENOEXEC = 8 => "Exec format error";
+        EBADF = 9 => "Bad file descriptor";
+        ECHILD = 10 => "No child processes";
+        ENOMEM = 12 => "Cannot allocate memory";
+        EACCES = 13 => "Permission denied";
+        EFAULT = 14 => "Bad address";
+        ENOTBLK = 15 => "Block device required";
+        EBUSY = 16 => "Device or resource busy";
+        EEXIST = 17 => "File exists";
+        EXDEV = 18 => "Invalid cross-device link";
+        ENODEV = 19 => "No such device";
+        ENOTDIR = 20 => "Not a directory";
+        EISDIR = 21 => "Is a directory";
+        EINVAL = 22 => "Invalid argument";
+        ENFILE = 23 => "Too many open files in system";
+        EMFILE = 24 => "Too many open files";
+        ENOTTY = 25 => "Inappropriate ioctl for device";
+        ETXTBSY = 26 => "Text file busy";
+        EFBIG = 27 => "File too large";
+        ENOSPC = 28 => "No space left on device";
+        ESPIPE = 29 => "Illegal seek";
+        EROFS = 30 => "Read-only file system";
+        EMLINK = 31 => "Too many links";
+        EPIPE = 32 => "Broken pipe";
+        EDOM = 33 => "Numerical argument out of domain";
+        ERANGE = 34 => "Numerical result out of range";
+    }
+}
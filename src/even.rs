@@ -0,0 +1,139 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// A `NonZero` value which is also even, such as a size which must be 2-byte
+/// aligned, or a sample count for stereo audio.
+///
+/// Use [`nonzero_lit::even!`](crate::even) to create one at compile time.
+/// Since the value is at least 2 (or at most -2), [`half`](Self::half) can
+/// divide it by two without leaving the `NonZero` domain.
+///
+/// # Examples
+/// ```
+/// use core::num::{NonZeroI8, NonZeroU32, NonZeroU8};
+/// use nonzero_lit::EvenNonZero;
+///
+/// const SAMPLES: EvenNonZero<NonZeroU32> = nonzero_lit::even!(u32, 960);
+/// assert_eq!(SAMPLES.get().get(), 960);
+/// assert_eq!(SAMPLES.half().get(), 480);
+/// assert_eq!(SAMPLES.to_string(), "960");
+///
+/// assert!(EvenNonZero::<NonZeroI8>::new(nonzero_lit::i8!(-4)).is_some());
+/// assert!(EvenNonZero::<NonZeroU8>::new(nonzero_lit::u8!(3)).is_none());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct EvenNonZero<T>(T);
+
+impl<T: core::fmt::Display> core::fmt::Display for EvenNonZero<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_even {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl EvenNonZero<$NonZeroInt> {
+            /// Wraps `n`, returning `None` if it's odd.
+            #[inline]
+            pub const fn new(n: $NonZeroInt) -> Option<Self> {
+                if n.get() % 2 == 0 {
+                    Some(Self(n))
+                } else {
+                    None
+                }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_const(n: $int) -> Self {
+                // Note: Hacky const fn assert.
+                let _ = ["value must be even"][(n % 2 != 0) as usize];
+                Self(crate::_private::nz_ctor::$int(n))
+            }
+
+            /// Returns the wrapped `NonZero` value.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                self.0
+            }
+
+            /// Returns half the value, which is never zero.
+            #[inline]
+            pub const fn half(self) -> $NonZeroInt {
+                crate::_private::nz_ctor::$int(self.0.get() / 2)
+            }
+        }
+
+        impl From<EvenNonZero<$NonZeroInt>> for $NonZeroInt {
+            #[inline]
+            fn from(n: EvenNonZero<$NonZeroInt>) -> Self {
+                n.0
+            }
+        }
+    )+};
+}
+
+impl_even! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroUsize => usize;
+    NonZeroI8 => i8;
+    NonZeroI16 => i16;
+    NonZeroI32 => i32;
+    NonZeroI64 => i64;
+    NonZeroI128 => i128;
+    NonZeroIsize => isize;
+}
+
+/// Create a constant [`EvenNonZero`], checking that it's nonzero and even.
+///
+/// The first argument is the integer type, and the second is the value.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let n = nonzero_lit::even!(u16, 512);
+/// assert_eq!(n.half().get(), 256);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroI64;
+/// use nonzero_lit::EvenNonZero;
+/// const CHANNELS: i64 = 2;
+/// const FRAME: EvenNonZero<NonZeroI64> = nonzero_lit::even!(i64, -CHANNELS * 3);
+/// assert_eq!(FRAME.half().get(), -3);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::even!(u16, 0);
+/// ```
+///
+/// Including odd values.
+/// ```compile_fail
+/// let n = nonzero_lit::even!(u16, 511);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 4;
+/// let n = nonzero_lit::even!(u16, bar);
+/// ```
+#[macro_export]
+macro_rules! even {
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const E: $crate::EvenNonZero<$crate::_private::nz::$int> =
+                $crate::EvenNonZero::<$crate::_private::nz::$int>::__from_const(__E);
+            E
+        }
+    }};
+}
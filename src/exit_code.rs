@@ -0,0 +1,166 @@
+use core::num::NonZeroI32;
+
+/// A process exit code indicating failure, which is never zero.
+///
+/// Zero means success to essentially every OS, so a failure code that turns
+/// out to be zero is a bug which is usually only noticed once something else
+/// starts trusting the exit status. Use
+/// [`nonzero_lit::exit_code!`](crate::exit_code) to create one at compile
+/// time.
+///
+/// With the `std` feature enabled, this can be converted to a
+/// `std::process::ExitCode` (requires Rust 1.61), or used to exit the process
+/// directly with `NonZeroExitCode::exit`.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroExitCode;
+///
+/// const USAGE_ERROR: NonZeroExitCode = nonzero_lit::exit_code!(2);
+/// assert_eq!(i32::from(USAGE_ERROR), 2);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroExitCode(NonZeroI32);
+
+impl NonZeroExitCode {
+    /// The conventional general failure code, `1`.
+    pub const FAILURE: Self = Self(crate::_private::nz_i32(1));
+
+    /// Wraps a `NonZeroI32` exit code.
+    #[inline]
+    pub const fn new(n: NonZeroI32) -> Self {
+        Self(n)
+    }
+
+    /// Wraps an exit code, returning `None` if it's zero.
+    #[inline]
+    pub const fn new_checked(n: i32) -> Option<Self> {
+        match NonZeroI32::new(n) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Returns the exit code.
+    #[inline]
+    pub const fn get(self) -> NonZeroI32 {
+        self.0
+    }
+
+    /// Terminates the current process with this exit code, via
+    /// [`std::process::exit`].
+    ///
+    /// Unix only keeps the low 8 bits of the exit code, so codes where those
+    /// are all zero (such as `256`) exit with [`FAILURE`](Self::FAILURE)
+    /// instead, rather than indicating success.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn exit(self) -> ! {
+        let n = self.0.get();
+        if n & 0xff == 0 {
+            std::process::exit(Self::FAILURE.0.get())
+        } else {
+            std::process::exit(n)
+        }
+    }
+}
+
+impl core::fmt::Display for NonZeroExitCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<NonZeroI32> for NonZeroExitCode {
+    #[inline]
+    fn from(n: NonZeroI32) -> Self {
+        Self(n)
+    }
+}
+
+impl From<NonZeroExitCode> for NonZeroI32 {
+    #[inline]
+    fn from(c: NonZeroExitCode) -> Self {
+        c.0
+    }
+}
+
+impl From<NonZeroExitCode> for i32 {
+    #[inline]
+    fn from(c: NonZeroExitCode) -> Self {
+        c.0.get()
+    }
+}
+
+/// `std::process::ExitCode` can only hold a `u8`, so codes outside of `1..=255`
+/// become [`std::process::ExitCode::FAILURE`], rather than being truncated
+/// (which could turn them into zero, indicating success).
+#[cfg(feature = "std")]
+#[clippy::msrv = "1.61"]
+impl From<NonZeroExitCode> for std::process::ExitCode {
+    fn from(c: NonZeroExitCode) -> Self {
+        let n = c.0.get();
+        if n > 0 && n <= 255 {
+            std::process::ExitCode::from(n as u8)
+        } else {
+            std::process::ExitCode::FAILURE
+        }
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn exit_code(n: i32) -> NonZeroExitCode {
+    // Note: Hacky const fn assert.
+    let _ = ["exit code must not be zero (which indicates success)"][(n == 0) as usize];
+    let _ = ["exit code must not be a multiple of 256 (which Unix truncates to zero)"]
+        [(n & 0xff == 0) as usize];
+    NonZeroExitCode(crate::_private::nz_i32(n))
+}
+
+/// Create a constant [`NonZeroExitCode`].
+///
+/// Unix truncates exit codes to their low 8 bits, so multiples of 256 (such as
+/// `256` or `-512`) are rejected along with zero.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let code = nonzero_lit::exit_code!(3);
+/// assert_eq!(code.get().get(), 3);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::NonZeroExitCode;
+/// const EX_BASE: i32 = 64;
+/// const EX_DATAERR: NonZeroExitCode = nonzero_lit::exit_code!(EX_BASE + 1);
+/// assert_eq!(i32::from(EX_DATAERR), 65);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let code = nonzero_lit::exit_code!(0);
+/// ```
+///
+/// ```compile_fail
+/// let code = nonzero_lit::exit_code!(256);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::exit_code!(bar);
+/// ```
+#[macro_export]
+macro_rules! exit_code {
+    ($val:expr $(,)?) => {{
+        const __E: i32 = $val;
+        {
+            const C: $crate::NonZeroExitCode = $crate::_private::exit_code(__E);
+            C
+        }
+    }};
+}
@@ -0,0 +1,85 @@
+/// Export checked `NonZero` constants to C, as `#[no_mangle]` statics of the
+/// underlying integer type.
+///
+/// Each item is written like a `static` whose type is an integer type, and
+/// whose value is a constant expression, which is checked to be nonzero (just
+/// like [`nonzero_lit::u32!`](crate::u32) and friends) before being exported.
+/// Since the statics have plain integer types, C callers (and tools such as
+/// `cbindgen`) see the same values that Rust code validated.
+///
+/// By default each static is exported under its own name. Starting the list
+/// with `prefix = "...";` exports each under its name with that prefix
+/// instead, for a header-friendly naming scheme such as `MYLIB_MAX_CONNECTIONS`
+/// (the names in Rust are unchanged). The `prefix` form requires Rust 1.54.
+///
+/// Note that exporting symbols is flagged by the `unsafe_code` lint, as two
+/// symbols with the same name would conflict at link time.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// nonzero_lit::ffi_consts! {
+///     /// The most connections a server accepts.
+///     pub static MAX_CONNECTIONS: u32 = 64;
+///     pub static DEFAULT_PORT: u16 = 8000 + 80;
+///     pub static SENTINEL: i64 = -1;
+/// }
+/// assert_eq!(MAX_CONNECTIONS, 64);
+/// assert_eq!(DEFAULT_PORT, 8080);
+/// ```
+///
+/// Existing `NonZero` constants can be exported with `.get()`.
+/// ```
+/// const RETRIES: core::num::NonZeroU8 = nonzero_lit::u8!(3);
+/// nonzero_lit::ffi_consts! {
+///     pub static MAX_RETRIES: u8 = RETRIES.get();
+/// }
+/// assert_eq!(MAX_RETRIES, 3);
+/// ```
+///
+/// With a prefix, which only changes the exported names.
+/// ```
+/// nonzero_lit::ffi_consts! {
+///     prefix = "MYLIB_";
+///     pub static MAX_CONNECTIONS: u32 = 64;
+///     pub static DEFAULT_PORT: u16 = 8080;
+/// }
+/// assert_eq!(MAX_CONNECTIONS, 64);
+///
+/// // What C code sees.
+/// extern "C" {
+///     static MYLIB_DEFAULT_PORT: u16;
+/// }
+/// assert_eq!(unsafe { MYLIB_DEFAULT_PORT }, 8080);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// nonzero_lit::ffi_consts! {
+///     pub static MAX_CONNECTIONS: u32 = 0;
+/// }
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let n = 64;
+/// nonzero_lit::ffi_consts! {
+///     pub static MAX_CONNECTIONS: u32 = n;
+/// }
+/// ```
+#[macro_export]
+macro_rules! ffi_consts {
+    (
+        prefix = $prefix:literal;
+        $($(#[$attr:meta])* $vis:vis static $name:ident: $int:ident = $val:expr;)*
+    ) => {$(
+        $(#[$attr])*
+        #[export_name = ::core::concat!($prefix, ::core::stringify!($name))]
+        $vis static $name: $int = $crate::$int!($val).get();
+    )*};
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $int:ident = $val:expr;)*) => {$(
+        $(#[$attr])*
+        #[no_mangle]
+        $vis static $name: $int = $crate::$int!($val).get();
+    )*};
+}
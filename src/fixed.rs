@@ -0,0 +1,176 @@
+#[doc(hidden)]
+pub const fn parse_fixed(
+    q: &str,
+    frac_bits: &str,
+    neg: bool,
+    lit: &str,
+    bits: u32,
+    signed: bool,
+    exact: bool,
+) -> i128 {
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["fixed-point values must use a signed integer type"][!signed as usize];
+    let q = q.as_bytes();
+    let _ = ["expected a Q format like `Q16.16`"][(q.len() < 2 || q[0] != b'Q') as usize];
+    let int_bits = parse_bits(q, 1);
+    let frac_bits = parse_bits(frac_bits.as_bytes(), 0);
+    let _ = ["Q format doesn't match the width of the integer type"]
+        [(int_bits + frac_bits != bits && int_bits + frac_bits + 1 != bits) as usize];
+    let _ = ["Q format must leave room for the sign bit"][(frac_bits >= bits) as usize];
+
+    // Parse the literal as `digits / 10^scale`, exactly.
+    let lit = lit.as_bytes();
+    let mut digits: u128 = 0;
+    let mut scale: u128 = 1;
+    let mut seen_dot = false;
+    let mut i = 0;
+    while i < lit.len() {
+        let c = lit[i];
+        i += 1;
+        if c == b'_' {
+            continue;
+        }
+        if c == b'.' && !seen_dot {
+            seen_dot = true;
+            continue;
+        }
+        let _ = ["expected a decimal literal without a suffix or exponent"]
+            [!c.is_ascii_digit() as usize];
+        digits = checked(digits.checked_mul(10));
+        digits = checked(digits.checked_add((c - b'0') as u128));
+        if seen_dot {
+            scale = checked(scale.checked_mul(10));
+        }
+    }
+
+    let num = checked(digits.checked_mul(1 << frac_bits));
+    let mut v = num / scale;
+    let rem = num % scale;
+    let _ = ["value can't be represented exactly in this Q format"][(exact && rem != 0) as usize];
+    // Round to nearest, with ties away from zero.
+    if rem >= scale - rem {
+        v += 1;
+    }
+
+    let limit = 1u128 << (bits - 1);
+    let _ =
+        ["value is out of range for this Q format"][(v > limit || (!neg && v == limit)) as usize];
+    let _ = ["fixed-point value rounds to zero"][(v == 0) as usize];
+    if neg {
+        (v as i128).wrapping_neg()
+    } else {
+        v as i128
+    }
+}
+
+const fn parse_bits(b: &[u8], start: usize) -> u32 {
+    let mut v = 0;
+    let mut i = start;
+    while i < b.len() {
+        let _ = ["expected a Q format like `Q16.16`"][!b[i].is_ascii_digit() as usize];
+        v = v * 10 + (b[i] - b'0') as u32;
+        let _ = ["Q format has too many bits"][(v > 128) as usize];
+        i += 1;
+    }
+    v
+}
+
+const fn checked(v: Option<u128>) -> u128 {
+    let (v, overflowed) = match v {
+        Some(v) => (v, false),
+        None => (0, true),
+    };
+    // Note: Hacky const fn assert.
+    let _ = ["fixed-point literal has too many digits"][overflowed as usize];
+    v
+}
+
+/// Convert a decimal literal to a Q-format fixed-point value at compile time,
+/// producing the `NonZero` type matching the given signed integer type.
+///
+/// The format is written as `Qm.n`, where `n` is the number of fractional
+/// bits. The integer part `m` may count the sign bit or not (so both `Q1.31`
+/// and `Q0.31` describe an `i32` with 31 fractional bits), but must add up to
+/// the width of the type.
+///
+/// By default the value is rounded to the nearest representable value (with
+/// ties rounding away from zero). Prefix the arguments with `exact:` to reject
+/// literals which can't be represented exactly instead.
+///
+/// Values which are out of range, or which round to zero, fail to compile.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let gain = nonzero_lit::fixed!(i32, Q16.16, 1.5);
+/// assert_eq!(gain.get(), 0x0001_8000);
+/// ```
+///
+/// Works for consts, with negative values, and rounds by default.
+/// ```
+/// use core::num::{NonZeroI16, NonZeroI32};
+/// const COEFF: NonZeroI16 = nonzero_lit::fixed!(i16, Q1.15, -0.1);
+/// assert_eq!(COEFF.get(), -3277);
+/// const MIN: NonZeroI32 = nonzero_lit::fixed!(i32, Q0.31, -1.0);
+/// assert_eq!(MIN.get(), i32::MIN);
+/// ```
+///
+/// With `exact:`, any precision loss is an error.
+/// ```
+/// let half = nonzero_lit::fixed!(exact: i8, Q1.7, 0.5);
+/// assert_eq!(half.get(), 64);
+/// ```
+///
+/// ```compile_fail
+/// let tenth = nonzero_lit::fixed!(exact: i16, Q1.15, 0.1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let tiny = nonzero_lit::fixed!(i16, Q8.8, 0.001);
+/// ```
+///
+/// Including values which don't fit, and mismatched formats.
+/// ```compile_fail
+/// let big = nonzero_lit::fixed!(i16, Q8.8, 128.0);
+/// ```
+///
+/// ```compile_fail
+/// let x = nonzero_lit::fixed!(i32, Q8.8, 1.5);
+/// ```
+///
+/// Note: the value must be a literal.
+/// ```compile_fail
+/// const BAR: f32 = 1.5;
+/// let foo = nonzero_lit::fixed!(i32, Q16.16, BAR);
+/// ```
+#[macro_export]
+macro_rules! fixed {
+    (@imp $exact:expr, $int:ident, $q:ident . $n:literal, $neg:expr, $lit:literal) => {{
+        const __E: i128 = $crate::_private::parse_fixed(
+            stringify!($q),
+            stringify!($n),
+            $neg,
+            stringify!($lit),
+            (::core::mem::size_of::<$int>() * 8) as u32,
+            $int::MIN != 0,
+            $exact,
+        );
+        {
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(__E as $int);
+            NZ
+        }
+    }};
+    (exact: $int:ident, $q:ident . $n:literal, - $lit:literal $(,)?) => {
+        $crate::fixed!(@imp true, $int, $q.$n, true, $lit)
+    };
+    (exact: $int:ident, $q:ident . $n:literal, $lit:literal $(,)?) => {
+        $crate::fixed!(@imp true, $int, $q.$n, false, $lit)
+    };
+    ($int:ident, $q:ident . $n:literal, - $lit:literal $(,)?) => {
+        $crate::fixed!(@imp false, $int, $q.$n, true, $lit)
+    };
+    ($int:ident, $q:ident . $n:literal, $lit:literal $(,)?) => {
+        $crate::fixed!(@imp false, $int, $q.$n, false, $lit)
+    };
+}
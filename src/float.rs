@@ -0,0 +1,167 @@
+use core::num::{NonZeroU32, NonZeroU64};
+
+#[doc(hidden)]
+#[inline]
+pub const fn float_bits_u32(bits: u32) -> NonZeroU32 {
+    // Note: Hacky const fn assert.
+    let _ = ["`+0.0` has an all-zero bit pattern"][(bits == 0) as usize];
+    crate::_private::nz_u32(bits)
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn float_bits_u64(bits: u64) -> NonZeroU64 {
+    // Note: Hacky const fn assert.
+    let _ = ["`+0.0` has an all-zero bit pattern"][(bits == 0) as usize];
+    crate::_private::nz_u64(bits)
+}
+
+// `to_bits` isn't usable in const until Rust 1.83, so we take the float apart
+// by hand. This has to happen in a `const` item rather than a `const fn`, since
+// older compilers only allow float arithmetic in the former. Every operation
+// here is exact: we only ever scale by powers of two, and extract mantissas
+// which fit in the float's precision.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __float_bits {
+    ($x:expr, $F:ident, $U:ident, $mantissa_bits:expr, $bias:expr) => {{
+        let x: $F = $x;
+        let neg = x < 0.0 || (x == 0.0 && 1.0 / x < 0.0);
+        let sign: $U = if neg {
+            1 << (::core::mem::size_of::<$U>() * 8 - 1)
+        } else {
+            0
+        };
+        let mut a = if neg { -x } else { x };
+        let max_exp: $U = ($bias as $U) * 2 + 1;
+        // Note: `x != x` is `x.is_nan()`, which isn't const on older compilers.
+        #[allow(clippy::eq_op)]
+        let is_nan = x != x;
+        let body: $U = if is_nan {
+            (max_exp << $mantissa_bits) | (1 << ($mantissa_bits - 1))
+        } else if a == 0.0 {
+            0
+        } else if a == $F::INFINITY {
+            max_exp << $mantissa_bits
+        } else {
+            let mut e: i32 = 0;
+            while a >= 2.0 {
+                a /= 2.0;
+                e += 1;
+            }
+            while a < 1.0 && e > 1 - $bias {
+                a *= 2.0;
+                e -= 1;
+            }
+            let scale = ((1 as $U) << $mantissa_bits) as $F;
+            if a >= 1.0 {
+                (((e + $bias) as $U) << $mantissa_bits) | ((a - 1.0) * scale) as $U
+            } else {
+                // Subnormal.
+                (a * scale) as $U
+            }
+        };
+        sign | body
+    }};
+}
+
+/// Compute the IEEE-754 bit pattern of an `f32` constant at compile time, as a
+/// [`NonZeroU32`](core::num::NonZeroU32).
+///
+/// The only `f32` with an all-zero bit pattern is `+0.0`, which is rejected.
+/// Note that `-0.0` is allowed, as its sign bit is set. NaNs always produce the
+/// canonical quiet NaN, `0x7fc0_0000`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let bits = nonzero_lit::f32_bits!(1.5);
+/// assert_eq!(bits.get(), 1.5f32.to_bits());
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU32;
+/// const HALF_PI: NonZeroU32 = nonzero_lit::f32_bits!(core::f32::consts::PI / 2.0);
+/// assert_eq!(f32::from_bits(HALF_PI.get()), core::f32::consts::FRAC_PI_2);
+///
+/// # macro_rules! check { ($($v:expr),*) => {$(
+/// #     assert_eq!(nonzero_lit::f32_bits!($v).get(), ($v as f32).to_bits(), "{}", $v);
+/// # )*}; }
+/// # check!(-0.0, 1.0, -1.0, 0.1, 3.4028235e38, -1.0e-45, 1.17549435e-38, 1.0e-40,
+/// #        f32::INFINITY, f32::NEG_INFINITY, f32::MAX, f32::MIN_POSITIVE, f32::EPSILON);
+/// # assert_eq!(nonzero_lit::f32_bits!(f32::NAN).get(), 0x7fc0_0000);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let bits = nonzero_lit::f32_bits!(0.0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3.0;
+/// let foo = nonzero_lit::f32_bits!(bar);
+/// ```
+#[macro_export]
+macro_rules! f32_bits {
+    ($val:expr $(,)?) => {{
+        const __E: f32 = $val;
+        {
+            const BITS: u32 = $crate::__float_bits!(__E, f32, u32, 23, 127);
+            const NZ: $crate::_private::NonZeroU32 = $crate::_private::float_bits_u32(BITS);
+            NZ
+        }
+    }};
+}
+
+/// Compute the IEEE-754 bit pattern of an `f64` constant at compile time, as a
+/// [`NonZeroU64`](core::num::NonZeroU64).
+///
+/// The only `f64` with an all-zero bit pattern is `+0.0`, which is rejected.
+/// Note that `-0.0` is allowed, as its sign bit is set. NaNs always produce the
+/// canonical quiet NaN, `0x7ff8_0000_0000_0000`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let bits = nonzero_lit::f64_bits!(-2.25);
+/// assert_eq!(bits.get(), (-2.25f64).to_bits());
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// const TAU: NonZeroU64 = nonzero_lit::f64_bits!(core::f64::consts::PI * 2.0);
+/// assert_eq!(f64::from_bits(TAU.get()), core::f64::consts::PI * 2.0);
+///
+/// # macro_rules! check { ($($v:expr),*) => {$(
+/// #     assert_eq!(nonzero_lit::f64_bits!($v).get(), ($v as f64).to_bits(), "{}", $v);
+/// # )*}; }
+/// # check!(-0.0, 1.0, -1.0, 0.1, 1.7976931348623157e308, 5e-324, -2.2250738585072014e-308,
+/// #        1.0e-310, f64::INFINITY, f64::NEG_INFINITY, f64::MAX, f64::MIN_POSITIVE,
+/// #        f64::EPSILON);
+/// # assert_eq!(nonzero_lit::f64_bits!(f64::NAN).get(), 0x7ff8_0000_0000_0000);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let bits = nonzero_lit::f64_bits!(0.0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3.0;
+/// let foo = nonzero_lit::f64_bits!(bar);
+/// ```
+#[macro_export]
+macro_rules! f64_bits {
+    ($val:expr $(,)?) => {{
+        const __E: f64 = $val;
+        {
+            const BITS: u64 = $crate::__float_bits!(__E, f64, u64, 52, 1023);
+            const NZ: $crate::_private::NonZeroU64 = $crate::_private::float_bits_u64(BITS);
+            NZ
+        }
+    }};
+}
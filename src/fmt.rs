@@ -0,0 +1,93 @@
+//! Adapters for formatting `NonZero` values in hexadecimal or binary, with a
+//! prefix and a fixed width.
+//!
+//! The output is zero-padded to the full width of the type (so a
+//! `NonZeroU16` is always four hex digits), which keeps register dumps and
+//! protocol traces lined up. Signed values are formatted as their two's
+//! complement bits, like `{:x}` does. Both `Display` and `Debug` produce the
+//! same output.
+//!
+//! The number of digits is available in `const` as [`Hex::WIDTH`] and
+//! [`Bin::WIDTH`].
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::fmt::{Bin, Hex};
+//!
+//! let reg = nonzero_lit::u32!(0xbeef);
+//! assert_eq!(format!("{}", Hex(reg)), "0x0000beef");
+//! assert_eq!(format!("{:?}", Bin(nonzero_lit::u8!(5))), "0b00000101");
+//! assert_eq!(Hex(nonzero_lit::i16!(-2)).to_string(), "0xfffe");
+//!
+//! // Widths are constants, for laying out columns.
+//! const COLUMN: usize = 2 + Hex::<core::num::NonZeroU64>::WIDTH;
+//! assert_eq!(COLUMN, 18);
+//! ```
+
+use core::fmt;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// Formats a `NonZero` value as `0x` followed by every hex digit of the type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Hex<T>(pub T);
+
+/// Formats a `NonZero` value as `0b` followed by every bit of the type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bin<T>(pub T);
+
+macro_rules! impl_fmt_adapters {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl Hex<$NonZeroInt> {
+            /// The number of hex digits written, not counting the `0x` prefix.
+            pub const WIDTH: usize = core::mem::size_of::<$int>() * 2;
+        }
+
+        impl Bin<$NonZeroInt> {
+            /// The number of binary digits written, not counting the `0b`
+            /// prefix.
+            pub const WIDTH: usize = core::mem::size_of::<$int>() * 8;
+        }
+
+        impl fmt::Display for Hex<$NonZeroInt> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#0w$x}", self.0.get(), w = Self::WIDTH + 2)
+            }
+        }
+
+        impl fmt::Debug for Hex<$NonZeroInt> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl fmt::Display for Bin<$NonZeroInt> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#0w$b}", self.0.get(), w = Self::WIDTH + 2)
+            }
+        }
+
+        impl fmt::Debug for Bin<$NonZeroInt> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    )+};
+}
+
+impl_fmt_adapters! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroUsize => usize;
+    NonZeroI8 => i8;
+    NonZeroI16 => i16;
+    NonZeroI32 => i32;
+    NonZeroI64 => i64;
+    NonZeroI128 => i128;
+    NonZeroIsize => isize;
+}
@@ -0,0 +1,157 @@
+/// Define `#[repr(transparent)]` ID newtypes backed by a `NonZero` integer.
+///
+/// Each generated type wraps the `NonZero` type for the given integer type, so
+/// `Option<UserId>` is free, and gets:
+///
+/// - The usual derives: `Copy`, `Clone`, `Debug`, `PartialEq`, `Eq`,
+///   `PartialOrd`, `Ord`, and `Hash`.
+/// - `const fn new(NonZeroU64) -> Self`, `const fn get(self) -> NonZeroU64`,
+///   and `const fn new_checked(u64) -> Option<Self>` (with `u64` replaced by
+///   the given type, of course).
+/// - `const fn new_const(u64) -> Self`, which fails to compile if used to
+///   initialize a constant with a zero value. Prefer
+///   [`nonzero_lit::id!`](crate::id), which always checks at compile time.
+/// - `Display` and `FromStr` (which rejects zero, with a
+///   [`ParseNonZeroError`](crate::ParseNonZeroError)).
+/// - `From` conversions to and from the `NonZero` type, and to the primitive
+///   integer type.
+///
+/// Attributes (including doc comments and extra derives) are passed through.
+/// The `;` after the last type is optional.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// nonzero_lit::nonzero_id! {
+///     /// Identifies a user.
+///     pub struct UserId(u64);
+///     pub(crate) struct GroupId(u32);
+/// }
+///
+/// const ADMIN: UserId = nonzero_lit::id!(UserId, 1);
+/// assert_eq!(ADMIN.get().get(), 1);
+///
+/// let parsed: GroupId = "20".parse().unwrap();
+/// assert_eq!(parsed.to_string(), "20");
+/// assert!("0".parse::<GroupId>().is_err());
+///
+/// assert_eq!(core::mem::size_of::<Option<UserId>>(), 8);
+/// ```
+///
+/// A single type can be defined inline.
+/// ```
+/// nonzero_lit::nonzero_id!(pub struct UserId(u64));
+///
+/// assert_eq!(UserId::new_checked(42).map(u64::from), Some(42));
+/// assert_eq!(UserId::new_checked(0), None);
+/// ```
+#[macro_export]
+macro_rules! nonzero_id {
+    ($($(#[$meta:meta])* $vis:vis struct $Name:ident($int:ident));+ $(;)?) => {$(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        $vis struct $Name($crate::_private::nz::$int);
+
+        impl $Name {
+            /// Wraps a `NonZero` value.
+            #[inline]
+            pub const fn new(n: $crate::_private::nz::$int) -> Self {
+                Self(n)
+            }
+
+            /// Wraps a primitive value, returning `None` if it's zero.
+            #[inline]
+            pub const fn new_checked(n: $int) -> Option<Self> {
+                match <$crate::_private::nz::$int>::new(n) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+
+            /// Wraps a primitive value, failing const evaluation if it's zero.
+            ///
+            /// # Panics
+            /// If `n` is zero. When used to initialize a constant, this is a
+            /// compile error instead.
+            #[inline]
+            pub const fn new_const(n: $int) -> Self {
+                Self($crate::_private::nz_ctor::$int(n))
+            }
+
+            /// Returns the wrapped `NonZero` value.
+            #[inline]
+            pub const fn get(self) -> $crate::_private::nz::$int {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Display for $Name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::str::FromStr for $Name {
+            type Err = $crate::ParseNonZeroError;
+            #[inline]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::parse_runtime(s).map(Self)
+            }
+        }
+
+        impl ::core::convert::From<$crate::_private::nz::$int> for $Name {
+            #[inline]
+            fn from(n: $crate::_private::nz::$int) -> Self {
+                Self(n)
+            }
+        }
+
+        impl ::core::convert::From<$Name> for $crate::_private::nz::$int {
+            #[inline]
+            fn from(id: $Name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::convert::From<$Name> for $int {
+            #[inline]
+            fn from(id: $Name) -> Self {
+                id.0.get()
+            }
+        }
+    )+};
+}
+
+/// Create a constant of a type generated by
+/// [`nonzero_id!`](crate::nonzero_id), checking at compile time that it's
+/// nonzero.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// nonzero_lit::nonzero_id!(struct RowId(u32));
+///
+/// let row = nonzero_lit::id!(RowId, 7);
+/// assert_eq!(u32::from(row), 7);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// nonzero_lit::nonzero_id!(struct RowId(u32));
+/// let row = nonzero_lit::id!(RowId, 0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// nonzero_lit::nonzero_id!(struct RowId(u32));
+/// let bar = 3;
+/// let row = nonzero_lit::id!(RowId, bar);
+/// ```
+#[macro_export]
+macro_rules! id {
+    ($T:ty, $val:expr $(,)?) => {{
+        const __ID: $T = <$T>::new_const($val);
+        __ID
+    }};
+}
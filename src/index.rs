@@ -0,0 +1,187 @@
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The unsigned integer types which can be used as the representation of a
+/// [`NonZeroIndex`].
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait IndexInt: sealed::Sealed + Copy {
+    /// The `NonZero` type used to store the index.
+    type NonZero: Copy + Eq + Ord + core::hash::Hash + core::fmt::Debug + core::fmt::Display;
+}
+
+/// A one-based index, as used by many file formats and FFI APIs.
+///
+/// This is stored as a `NonZero` integer (by default, a `NonZeroUsize`), so
+/// `Option<NonZeroIndex>` is the same size as `NonZeroIndex`, and can be used
+/// for an optional index with no overhead.
+///
+/// Use [`nonzero_lit::index!`](crate::index) for constant indices.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroIndex;
+///
+/// let names = ["first", "second", "third"];
+/// let second: NonZeroIndex = nonzero_lit::index!(2);
+/// assert_eq!(second.zero_based(), 1);
+/// assert_eq!(second.get_in(&names), Some(&"second"));
+///
+/// assert_eq!(
+///     core::mem::size_of::<Option<NonZeroIndex<u32>>>(),
+///     core::mem::size_of::<u32>(),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroIndex<T: IndexInt = usize>(T::NonZero);
+
+impl<T: IndexInt> core::fmt::Display for NonZeroIndex<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_index {
+    ($($int:ident => $NonZeroInt:ident, $nz_func:ident;)+) => {$(
+        impl sealed::Sealed for $int {}
+
+        impl IndexInt for $int {
+            type NonZero = $NonZeroInt;
+        }
+
+        impl NonZeroIndex<$int> {
+            /// The first index, `1`.
+            pub const FIRST: Self = Self(crate::_private::$nz_func(1));
+
+            /// Creates an index from its (one-based) `NonZero` value.
+            #[inline]
+            pub const fn new(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+
+            /// Creates an index from a one-based value, returning `None` if it
+            /// is zero.
+            #[inline]
+            pub const fn from_one_based(n: $int) -> Option<Self> {
+                match $NonZeroInt::new(n) {
+                    Some(n) => Some(Self(n)),
+                    None => None,
+                }
+            }
+
+            /// Creates an index from a zero-based value, returning `None` if
+            /// it is `MAX` (and so can't be converted).
+            #[inline]
+            pub const fn from_zero_based(i: $int) -> Option<Self> {
+                match i.checked_add(1) {
+                    Some(n) => Self::from_one_based(n),
+                    None => None,
+                }
+            }
+
+            /// Returns the one-based index as a `NonZero` value.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                self.0
+            }
+
+            /// Returns the one-based index.
+            #[inline]
+            pub const fn one_based(self) -> $int {
+                self.0.get()
+            }
+
+            /// Returns the zero-based index (that is, one less than the
+            /// one-based index). This can't overflow.
+            #[inline]
+            pub const fn zero_based(self) -> $int {
+                self.0.get() - 1
+            }
+
+            /// Returns the element of `slice` that this refers to, if it's in
+            /// bounds.
+            #[inline]
+            pub fn get_in<E>(self, slice: &[E]) -> Option<&E> {
+                use core::convert::TryFrom;
+                slice.get(usize::try_from(self.zero_based()).ok()?)
+            }
+
+            /// Returns a mutable reference to the element of `slice` that this
+            /// refers to, if it's in bounds.
+            #[inline]
+            pub fn get_mut_in<E>(self, slice: &mut [E]) -> Option<&mut E> {
+                use core::convert::TryFrom;
+                slice.get_mut(usize::try_from(self.zero_based()).ok()?)
+            }
+        }
+
+        impl From<$NonZeroInt> for NonZeroIndex<$int> {
+            #[inline]
+            fn from(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+        }
+
+        impl From<NonZeroIndex<$int>> for $NonZeroInt {
+            #[inline]
+            fn from(i: NonZeroIndex<$int>) -> Self {
+                i.0
+            }
+        }
+    )+};
+}
+
+impl_index! {
+    usize => NonZeroUsize, nz_usize;
+    u8 => NonZeroU8, nz_u8;
+    u16 => NonZeroU16, nz_u16;
+    u32 => NonZeroU32, nz_u32;
+    u64 => NonZeroU64, nz_u64;
+}
+
+/// Create a constant [`NonZeroIndex`] from its one-based value.
+///
+/// With one argument, this produces a `NonZeroIndex<usize>`. A different
+/// integer type can be given as the first argument.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let i = nonzero_lit::index!(4);
+/// assert_eq!(i.one_based(), 4);
+/// assert_eq!(i.zero_based(), 3);
+/// ```
+///
+/// Works for consts, and with other integer types.
+/// ```
+/// use nonzero_lit::NonZeroIndex;
+/// const ROW: u32 = 10;
+/// const I: NonZeroIndex<u32> = nonzero_lit::index!(u32, ROW + 1);
+/// assert_eq!(I.zero_based(), 10);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let i = nonzero_lit::index!(0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::index!(bar);
+/// ```
+#[macro_export]
+macro_rules! index {
+    ($int:ident, $val:expr $(,)?) => {{
+        const __I: $crate::NonZeroIndex<$int> =
+            $crate::NonZeroIndex::<$int>::new($crate::$int!($val));
+        __I
+    }};
+    ($val:expr $(,)?) => {
+        $crate::index!(usize, $val)
+    };
+}
@@ -0,0 +1,109 @@
+#[doc(hidden)]
+pub const fn leb128_len(v: u128) -> usize {
+    let mut v = v >> 7;
+    let mut n = 1;
+    while v != 0 {
+        v >>= 7;
+        n += 1;
+    }
+    n
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn leb128_byte(v: u128, i: usize) -> u8 {
+    let more = i + 1 < leb128_len(v);
+    ((v >> (7 * i)) & 0x7f) as u8 | if more { 0x80 } else { 0 }
+}
+
+#[doc(hidden)]
+pub const fn sleb128_len(v: i128) -> usize {
+    let mut v = v;
+    let mut n = 1;
+    // Keep going until the rest of the value is just the sign extension of
+    // the last byte's top bit.
+    while !((v >> 6) == 0 || (v >> 6) == -1) {
+        v >>= 7;
+        n += 1;
+    }
+    n
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn sleb128_byte(v: i128, i: usize) -> u8 {
+    let more = i + 1 < sleb128_len(v);
+    ((v >> (7 * i)) & 0x7f) as u8 | if more { 0x80 } else { 0 }
+}
+
+/// Encode a constant as LEB128 (the variable-length integer encoding used by
+/// DWARF, WebAssembly, and protobuf) at compile time, producing a `[u8; N]`.
+///
+/// The first argument is the integer type. Unsigned types are encoded as
+/// unsigned LEB128, and signed types as signed LEB128. As with the other
+/// macros, the value is checked to be nonzero at compile time.
+///
+/// The length of the encoding is available in `const` as the array's `len()`,
+/// which can be used to name its type.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let bytes = nonzero_lit::leb128!(u32, 624_485);
+/// assert_eq!(bytes, [0xe5, 0x8e, 0x26]);
+///
+/// let bytes = nonzero_lit::leb128!(i64, -123_456);
+/// assert_eq!(bytes, [0xc0, 0xbb, 0x78]);
+/// ```
+///
+/// Works for consts, and the length can be named.
+/// ```
+/// // Protobuf field 1 with wire type 2 (length-delimited).
+/// const TAG: u32 = (1 << 3) | 2;
+/// const TAG_LEN: usize = nonzero_lit::leb128!(u32, TAG).len();
+/// const TAG_BYTES: [u8; TAG_LEN] = nonzero_lit::leb128!(u32, TAG);
+/// assert_eq!(TAG_BYTES, [0x0a]);
+///
+/// assert_eq!(nonzero_lit::leb128!(u8, 128), [0x80, 0x01]);
+/// assert_eq!(nonzero_lit::leb128!(i8, 64), [0xc0, 0x00]);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let bytes = nonzero_lit::leb128!(u32, 0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let bytes = nonzero_lit::leb128!(u32, bar);
+/// ```
+#[macro_export]
+macro_rules! leb128 {
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const V: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(__E);
+            const SIGNED: bool = $int::MIN != 0;
+            const N: usize = if SIGNED {
+                $crate::_private::sleb128_len(V.get() as i128)
+            } else {
+                $crate::_private::leb128_len(V.get() as u128)
+            };
+            const OUT: [u8; N] = {
+                let mut out = [0u8; N];
+                let mut i = 0;
+                while i < N {
+                    out[i] = if SIGNED {
+                        $crate::_private::sleb128_byte(V.get() as i128, i)
+                    } else {
+                        $crate::_private::leb128_byte(V.get() as u128, i)
+                    };
+                    i += 1;
+                }
+                out
+            };
+            OUT
+        }
+    }};
+}
@@ -0,0 +1,116 @@
+use core::num::NonZeroUsize;
+
+#[doc(hidden)]
+#[inline]
+pub const fn len_of(n: usize) -> NonZeroUsize {
+    // Note: Hacky const fn assert.
+    let _ = ["array must not be empty"][(n == 0) as usize];
+    crate::_private::nz_usize(n)
+}
+
+/// Get the length of a constant array (or slice) as a
+/// [`NonZeroUsize`](core::num::NonZeroUsize), checking at compile time that
+/// it isn't empty.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const PRIMES: [u32; 4] = [2, 3, 5, 7];
+/// let n = nonzero_lit::len_of!(PRIMES);
+/// assert_eq!(n.get(), 4);
+/// ```
+///
+/// Works for consts, and with constant slices.
+/// ```
+/// use core::num::NonZeroUsize;
+/// const NAMES: &[&str] = &["alpha", "beta"];
+/// const COUNT: NonZeroUsize = nonzero_lit::len_of!(NAMES);
+/// assert_eq!(COUNT.get(), 2);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// const EMPTY: [u8; 0] = [];
+/// let n = nonzero_lit::len_of!(EMPTY);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = [1, 2, 3];
+/// let foo = nonzero_lit::len_of!(bar);
+/// ```
+#[macro_export]
+macro_rules! len_of {
+    ($arr:expr $(,)?) => {{
+        const __E: usize = $arr.len();
+        {
+            const N: $crate::_private::NonZeroUsize = $crate::_private::len_of(__E);
+            N
+        }
+    }};
+}
+
+/// Get the length of a slice as a [`NonZeroUsize`], or `None` if it's empty.
+///
+/// Unlike [`len_of!`](crate::len_of), this works on runtime values. See also
+/// [`len_nonzero!`](macro@crate::len_nonzero), which works with anything that has a
+/// `len()` method.
+///
+/// # Examples
+/// ```
+/// let data = [1u8, 2, 3];
+/// assert_eq!(nonzero_lit::len_nonzero(&data[..]), Some(nonzero_lit::usize!(3)));
+/// assert_eq!(nonzero_lit::len_nonzero(&data[..0]), None);
+/// ```
+#[inline]
+pub const fn len_nonzero<T>(s: &[T]) -> Option<NonZeroUsize> {
+    NonZeroUsize::new(s.len())
+}
+
+/// Check that a slice isn't empty, returning it along with its length as a
+/// [`NonZeroUsize`].
+///
+/// This keeps the slice and the proof that it's nonempty together, which is
+/// convenient for chunking and allocation code.
+///
+/// # Examples
+/// ```
+/// fn first_chunk(items: &[u32], chunks: usize) -> Option<&[u32]> {
+///     let (items, len) = nonzero_lit::nonempty_with_len(items)?;
+///     let chunks = nonzero_lit::at_least_one!(chunks);
+///     // Neither divides by zero nor produces an empty chunk.
+///     let size = (len.get() + chunks.get() - 1) / chunks.get();
+///     Some(&items[..size])
+/// }
+/// assert_eq!(first_chunk(&[1, 2, 3, 4, 5], 2), Some(&[1, 2, 3][..]));
+/// assert_eq!(first_chunk(&[1, 2, 3], 0), Some(&[1, 2, 3][..]));
+/// assert_eq!(first_chunk(&[], 2), None);
+/// ```
+#[inline]
+pub const fn nonempty_with_len<T>(s: &[T]) -> Option<(&[T], NonZeroUsize)> {
+    match NonZeroUsize::new(s.len()) {
+        Some(n) => Some((s, n)),
+        None => None,
+    }
+}
+
+/// Get the length of a value (anything with a `len()` method, such as a
+/// slice, `str`, or `Vec`) as a [`NonZeroUsize`], or `None` if it's empty.
+///
+/// This is shorthand for `NonZeroUsize::new(x.len())`. Unlike
+/// [`len_of!`](crate::len_of), the argument may be a runtime value.
+///
+/// # Examples
+/// ```
+/// let name = "nonzero";
+/// assert_eq!(nonzero_lit::len_nonzero!(name).map(|n| n.get()), Some(7));
+///
+/// let empty: Vec<u64> = Vec::new();
+/// assert_eq!(nonzero_lit::len_nonzero!(empty), None);
+/// ```
+#[macro_export]
+macro_rules! len_nonzero {
+    ($val:expr $(,)?) => {
+        $crate::_private::NonZeroUsize::new($val.len())
+    };
+}
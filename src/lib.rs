@@ -32,6 +32,259 @@
 //! - [`nonzero_lit::u8!`](crate::u8), producing a [`core::num::NonZeroU8`].
 //! - [`nonzero_lit::i8!`](crate::i8), producing a [`core::num::NonZeroI8`].
 //!
+//! Given several arguments (such as `nonzero_lit::u32!(1, 2, 3)`), each of
+//! these produces an array instead, checking every element.
+//!
+//! Beyond those, a few macros apply the same compile-time checking to other
+//! kinds of constants:
+//!
+//! - [`nonzero_lit::nul_free_str!`](crate::nul_free_str), producing a
+//!   [`NulFreeStr`], a string which is known not to contain any NUL bytes.
+//! - [`nonzero_lit::nonzero_map!`](crate::nonzero_map), producing a
+//!   [`NonZeroMap`], a static lookup table keyed by `NonZero` integers.
+//! - [`nonzero_lit::index!`](crate::index), producing a [`NonZeroIndex`].
+//! - [`nonzero_lit::nonzero_id!`](crate::nonzero_id), which defines ID
+//!   newtypes backed by a `NonZero` integer, and
+//!   [`nonzero_lit::id!`](crate::id) for constants of those types.
+//! - [`nonzero_lit::port!`](crate::port), producing a [`Port`].
+//! - [`nonzero_lit::ipv4!`](crate::ipv4), which parses an IPv4 address into
+//!   a `NonZeroU32`.
+//! - [`nonzero_lit::exit_code!`](crate::exit_code), producing a
+//!   [`NonZeroExitCode`].
+//! - [`nonzero_lit::ring_capacity!`](crate::ring_capacity), producing a
+//!   [`RingCapacity`], a power-of-two capacity and its index mask.
+//! - [`nonzero_lit::capacity!`](crate::capacity), producing a [`Capacity`],
+//!   a collection capacity with overflow-checked allocation size arithmetic.
+//! - [`nonzero_lit::cfg_nonzero!`](crate::cfg_nonzero), which selects a
+//!   `NonZero` constant based on the target configuration.
+//! - [`nonzero_lit::line_nonzero!`](crate::line_nonzero) and
+//!   [`nonzero_lit::column_nonzero!`](crate::column_nonzero), which are
+//!   `line!()` and `column!()` as `NonZeroU32`s.
+//! - [`nonzero_lit::len_of!`](crate::len_of), producing the length of a
+//!   non-empty constant array as a `NonZeroUsize`, and
+//!   [`nonzero_lit::len_nonzero!`](macro@crate::len_nonzero), its runtime
+//!   counterpart.
+//! - [`nonzero_lit::sorted_nonzero_array!`](crate::sorted_nonzero_array),
+//!   which sorts (and optionally deduplicates) a constant array into an array
+//!   of `NonZero` values.
+//! - [`nonzero_lit::assert_all_distinct!`](crate::assert_all_distinct), which
+//!   checks that the elements of a constant array (such as a table of IDs) are
+//!   distinct.
+//! - [`nonzero_lit::assert_not_zst!`](crate::assert_not_zst), which checks that
+//!   a type (including a generic parameter) isn't zero-sized.
+//! - [`nonzero_lit::assert_fits!`](crate::assert_fits), which checks that a
+//!   constant fits in an integer type.
+//! - [`nonzero_lit::assert_pow2!`](crate::assert_pow2) and
+//!   [`nonzero_lit::assert_aligned!`](crate::assert_aligned), which check that a
+//!   constant is a power of two, or a multiple of another.
+//! - [`nonzero_lit::sum_nonzero!`](crate::sum_nonzero) and
+//!   [`nonzero_lit::product_nonzero!`](crate::product_nonzero), which fold a
+//!   constant array with overflow checking, and
+//!   [`nonzero_lit::array_min_nonzero!`](crate::array_min_nonzero) and
+//!   [`nonzero_lit::array_max_nonzero!`](crate::array_max_nonzero), which find
+//!   its smallest or largest element.
+//! - [`nonzero_lit::leb128!`](crate::leb128), which LEB128-encodes a
+//!   constant into a byte array.
+//! - [`nonzero_lit::popcount_eq!`](crate::popcount_eq), which checks that a
+//!   constant has exactly the given number of set bits.
+//! - [`nonzero_lit::morton_masks!`](crate::morton_masks), which generates the
+//!   masks and shifts for interleaving bits into a Morton code.
+//! - [`nonzero_lit::bitpattern!`](crate::bitpattern), which parses a bit
+//!   pattern such as `"10xx_xxx1"` into a mask and value for matching it.
+//! - [`nonzero_lit::shl_nonzero!`](crate::shl_nonzero) and
+//!   [`nonzero_lit::shr_nonzero!`](crate::shr_nonzero), which shift a
+//!   constant, checking that some set bit is left.
+//! - [`nonzero_lit::isqrt_nonzero!`](crate::isqrt_nonzero), which computes
+//!   the integer square root of a constant, and
+//!   [`nonzero_lit::factorial!`](crate::factorial) and
+//!   [`nonzero_lit::binomial!`](crate::binomial), which compute factorials and
+//!   binomial coefficients with overflow checking.
+//! - [`nonzero_lit::fib!`](crate::fib) and
+//!   [`nonzero_lit::fib_table!`](crate::fib_table), producing a Fibonacci
+//!   number or an array of them.
+//! - [`nonzero_lit::powers_of!`](crate::powers_of), producing an array of
+//!   the powers of a base, with overflow checking.
+//! - [`nonzero_lit::primes_up_to!`](crate::primes_up_to), producing an array
+//!   of every prime up to a bound, found with a compile-time sieve.
+//! - [`nonzero_lit::pow_mod!`](crate::pow_mod) and
+//!   [`nonzero_lit::mod_inverse!`](crate::mod_inverse), which compute a modular
+//!   exponentiation or inverse.
+//! - [`nonzero_lit::magic_div!`](crate::magic_div), which computes a
+//!   [`MagicDiv`], the multiplier and shift for dividing by a constant.
+//! - [`nonzero_lit::epoch!`](crate::epoch), which parses an RFC 3339
+//!   timestamp into seconds since the Unix epoch.
+//! - [`nonzero_lit::build_time!`](crate::build_time), the time of the build
+//!   from `SOURCE_DATE_EPOCH` (or another environment variable).
+//! - [`nonzero_lit::duration_str!`](crate::duration_str), which parses a
+//!   duration such as `"1h30m"` into a number of milliseconds (or another
+//!   unit).
+//! - [`nonzero_lit::bytes_str!`](crate::bytes_str), which parses a size such
+//!   as `"64KiB"` into a number of bytes.
+//! - [`nonzero_lit::millis!`](crate::millis),
+//!   [`nonzero_lit::micros!`](crate::micros) and
+//!   [`nonzero_lit::nanos!`](crate::nanos), which convert a duration into ticks
+//!   of a clock with a given rate.
+//! - [`nonzero_lit::backoff_delays!`](crate::backoff_delays), producing an
+//!   array of exponential backoff delays, capped at a maximum.
+//! - [`nonzero_lit::f32_bits!`](crate::f32_bits) and
+//!   [`nonzero_lit::f64_bits!`](crate::f64_bits), which produce the bit
+//!   pattern of a (non-`+0.0`) float.
+//! - [`nonzero_lit::fixed!`](crate::fixed), which converts a decimal literal to
+//!   a Q-format fixed-point value.
+//! - [`nonzero_lit::u256!`](crate::u256) and
+//!   [`nonzero_lit::i256!`](crate::i256), producing a [`NonZeroU256`] or
+//!   [`NonZeroI256`] from a hex string.
+//! - [`nonzero_lit::ratio!`](crate::ratio), producing a [`NonZeroRatio`] in
+//!   lowest terms.
+//! - [`nonzero_lit::bitint!`](crate::bitint), producing one of the odd-width
+//!   integer types described below.
+//! - [`nonzero_lit::nonzero_bitflags!`](crate::nonzero_bitflags), which
+//!   defines a set of bit flags which can't be empty.
+//! - [`nonzero_lit::errno!`](crate::errno), which defines a module of
+//!   `NonZeroI32` error codes with name and description lookup. The
+//!   traditional Unix codes are provided in [`unix_errno`].
+//! - [`nonzero_lit::signal!`](crate::signal), producing a signal number
+//!   which is within the range the target supports.
+//! - [`nonzero_lit::magic!`](crate::magic), which packs a 4 or 8 byte magic
+//!   number (such as a FourCC) into a `NonZeroU32` or `NonZeroU64`.
+//! - [`nonzero_lit::codepoint!`](crate::codepoint), producing a Unicode
+//!   scalar value (other than U+0000) as a `NonZeroU32`.
+//! - [`nonzero_lit::color!`](crate::color), producing a packed RGBA color
+//!   (other than transparent black) as a `NonZeroU32`, from a string such as
+//!   `"#ff8800"` or an integer.
+//! - [`nonzero_lit::ffi_consts!`](crate::ffi_consts), which exports checked
+//!   constants to C as `#[no_mangle]` statics.
+//! - `nonzero_lit::const_to_str!`, which formats a constant as a
+//!   `&'static str` at compile time (requires the `const_str` feature).
+//! - `nonzero_lit::nonnull!`, producing a `NonNull` pointer to a fixed,
+//!   suitably aligned address (requires the `nonnull` feature).
+//! - `nonzero_lit::threads!`, the number of threads to use for a thread pool,
+//!   from `available_parallelism` within bounds checked at compile time
+//!   (requires the `std` feature).
+//!
+//! To define compile-time checked literal macros for your own types, see
+//! [`nonzero_lit::define_nonzero_macros!`](crate::define_nonzero_macros),
+//! and for checking arbitrary constants against a predicate, see
+//! [`nonzero_lit::force_const!`](crate::force_const).
+//!
+//! There are also a few types for working with `NonZero` values once you have
+//! them:
+//!
+//! - [`NonZeroSaturating`], a wrapper whose arithmetic saturates without ever
+//!   producing zero.
+//! - [`NonZeroChecked`], a wrapper whose arithmetic returns `None` on overflow
+//!   or zero, rather than leaving the `NonZero` types.
+//! - [`NonZeroIndex`], a one-based index.
+//! - [`NonZeroU24`], [`NonZeroU48`], [`NonZeroI24`] and [`NonZeroI48`], for
+//!   the odd-width fields common in media containers and network protocols.
+//! - [`NonZeroU256`] and [`NonZeroI256`], 256-bit integers for large
+//!   constants such as those used in cryptography.
+//! - [`NonZeroRatio`], a positive rational number such as a clock prescaler.
+//! - [`NonEmptySlice`], a borrowed slice which is never empty, created with
+//!   [`nonzero_lit::nonempty_slice!`](crate::nonempty_slice).
+//! - [`NonEmptyStr`], a borrowed string which is never empty, created with
+//!   [`nonzero_lit::nonempty_str!`](crate::nonempty_str).
+//! - `NonEmptyVec`, a `Vec` which is never empty, created with
+//!   `nonempty_vec!` (requires the `alloc` feature).
+//! - `NonEmptyArray<T, N>`, an array whose type ensures `N` isn't zero,
+//!   created with `nonempty_array!` (requires the `const_generics` feature).
+//! - [`Percent`], a percentage from 1 to 100, created with
+//!   [`nonzero_lit::percent!`](crate::percent).
+//! - [`EvenNonZero`], a value which is also even, created with
+//!   [`nonzero_lit::even!`](crate::even).
+//! - [`PowerOfTwo`], a power of two stored as its exponent, created with
+//!   [`nonzero_lit::pow2!`](crate::pow2).
+//! - [`NonZeroRange`], an iterator over a range of `NonZero` values, created
+//!   with [`nonzero_lit::nz_range!`](crate::nz_range).
+//! - `NzUsize<N>`, `NzU32<N>` and so on, zero-sized markers for a nonzero
+//!   const generic parameter (requires the `const_generics` feature). Without
+//!   const generics, [`nonzero_lit::nonzero_typeconst!`](crate::nonzero_typeconst)
+//!   declares a type per constant instead, implementing [`TypeConst`].
+//! - `DefaultNonZero<T, N>`, a wrapper whose `Default` is `N`, for using
+//!   `#[derive(Default)]` with `NonZero` fields (also requires the
+//!   `const_generics` feature).
+//!
+//! The [`CollectNonZero`] extension trait converts iterators of integers into
+//! `NonZero` values in bulk, either skipping zeros or reporting where the first
+//! one is.
+//!
+//! The [`SliceNonZeroExt`] extension trait adds versions of slice methods
+//! such as `chunks` and `windows` which take a `NonZeroUsize`, so they can't
+//! panic on a size of zero.
+//!
+//! For runtime values, [`nonzero_lit::at_least_one!`](crate::at_least_one)
+//! converts an unsigned integer to its `NonZero` type, clamping zero up to 1,
+//! and [`nonzero_lit::nonzero_or!`](crate::nonzero_or) replaces zero with a
+//! fallback constant.
+//!
+//! For parsing at runtime, [`parse_runtime`] returns a [`ParseNonZeroError`]
+//! which says why the input was rejected (including when it was zero). This
+//! crate's types also implement `FromStr` and `TryFrom<&str>` with that error,
+//! which reports values the type doesn't allow (such as a percentage above
+//! 100) as `OutOfRange`.
+//!
+//! And the [`ops`] module provides `const fn` arithmetic which stays within the
+//! `NonZero` types, while the [`target`] module provides `NonZero` constants
+//! describing the compilation target, such as its pointer width. The [`fmt`]
+//! module has adapters for formatting `NonZero` values as fixed-width hex or
+//! binary.
+//!
+//! # Cargo features
+//!
+//! - `std`: Enables integration with the standard library, such as converting a
+//!   [`NonZeroExitCode`] into a `std::process::ExitCode`, or implementing
+//!   `std::error::Error` for this crate's error types, and the `threads!`
+//!   macro. Implies `alloc`. Requires Rust 1.61.
+//! - `alloc`: Enables functions which need an allocator, such as
+//!   `try_nonzero_vec`, which converts a `Vec<u32>` into a `Vec<NonZeroU32>`,
+//!   and the `NonEmptyVec` type.
+//! - `macros`: Enables procedural macros: `nonzero_expr!`, which rewrites
+//!   `#5u32`-style marked literals inside an arbitrary expression, and
+//!   `nonzero_counter!`, which derives a stable, unique ID from a string key,
+//!   `#[derive(NonZeroRepr)]`, for enums with nonzero discriminants, and
+//!   `#[nonzero]`, for `const` items with a plain integer initializer. This also
+//!   makes the literal macros reject `as` casts in their argument which change
+//!   the value (such as `u8!(SOME_U32 as u8)`), pointing at the cast, improves
+//!   their error when the argument is a literal with another type's suffix
+//!   (such as `u32!(5u64)`), and enables their `strict:` mode.
+//! - `serde`: Implements `Serialize` and `Deserialize` for this crate's types.
+//!   Deserializing checks the same invariants as the types' constructors.
+//!   Also adds the `serde_adapters` module, for `Option<NonZero*>` fields in
+//!   data which uses `0` for `None`.
+//! - `schemars`: Implements `JsonSchema` for this crate's types, describing
+//!   their invariants (such as `"minimum": 1`) where JSON Schema can.
+//! - `rkyv`: Implements `rkyv`'s `Archive`, `Serialize` and `Deserialize` for
+//!   this crate's `NonZero`-backed types, such that validating an archive
+//!   rejects zero. Requires Rust 1.81.
+//! - `defmt`: Implements `defmt::Format` for this crate's wrapper types, so
+//!   they can be logged directly on embedded targets. Requires Rust 1.76.
+//! - `ufmt`: Implements `ufmt::uDisplay` and `ufmt::uDebug` for this crate's
+//!   wrapper types, for targets which use `ufmt` rather than `core::fmt`.
+//!   Requires Rust 1.56.
+//! - `borsh`: Implements `BorshSerialize` and `BorshDeserialize` for this
+//!   crate's `NonZero`-backed types. Deserializing checks the same invariants
+//!   as the types' constructors. Requires Rust 1.77.
+//! - `inline_const`: Expands the literal macros (such as `u32!`) to inline
+//!   `const` blocks, so that their arguments can use `Self` and the generic
+//!   parameters of the surrounding item, such as `u32!(Self::BLOCK_SIZE)`.
+//!   This disables the improved errors for `as` casts from the `macros`
+//!   feature. Requires Rust 1.79.
+//! - `test_support`: Adds the `test_support` module, with iterators over
+//!   every value of the small `NonZero` types and arrays of boundary values
+//!   for the rest, for use in tests.
+//! - `verify`: Adds the `verify` module, for checking code which uses this
+//!   crate with the Kani model checker, including harnesses for this crate's
+//!   own operations, and `kani::Arbitrary` impls for its types.
+//! - `signals`: Adds the `unix_signal` module, with constants for the
+//!   standard signals.
+//! - `const_str`: Enables the `const_to_str!` macro. Requires Rust 1.63.
+//! - `const_generics`: Enables the `NzUsize<N>`-style marker types, which
+//!   carry a nonzero value in their type, `DefaultNonZero`, and
+//!   `NonEmptyArray`. Requires Rust 1.51.
+//! - `nonnull`: Enables the `nonnull!` macro, for constant `NonNull` pointers
+//!   to fixed addresses such as memory-mapped registers. This is the only part
+//!   of the crate which uses unsafe code.
+//!
 //! # Features
 //!
 //! - Crate fully supports `no_std`.
@@ -44,7 +297,7 @@
 //! - Misuse (trying to make a `NonZero$Int` with a zero value) is always
 //!   detected at compile time, even when the macro is not being used to
 //!   initialize a constant.
-//! - No unsafe code.
+//! - No unsafe code (outside of the opt-in `nonnull` feature).
 //!
 //! # Examples
 //!
@@ -91,6 +344,27 @@
 //! assert_eq!(MASKED.get(), 0xaabb_00dd_00ff_0102_u64);
 //! ```
 //!
+//! #### Use in Patterns
+//!
+//! The macros can't be used in pattern position (Rust has no stable way to
+//! evaluate a constant expression there), but constants defined with them can,
+//! so `NonZero` values can be matched without converting them with `.get()`.
+//! ```
+//! use core::num::NonZeroU8;
+//!
+//! const PING: NonZeroU8 = nonzero_lit::u8!(1);
+//! const PONG: NonZeroU8 = nonzero_lit::u8!(2);
+//!
+//! fn describe(opcode: NonZeroU8) -> &'static str {
+//!     match opcode {
+//!         PING => "ping",
+//!         PONG => "pong",
+//!         _ => "unknown",
+//!     }
+//! }
+//! assert_eq!(describe(nonzero_lit::u8!(2)), "pong");
+//! ```
+//!
 //! ### Zero Detection
 //!
 //! These tests are deliberately fail to compile, to demonstrate that we detect
@@ -123,8 +397,594 @@
 //! const UH_OH: NonZeroU16 = nonzero_lit::u16!(30 / !0);
 //! # let _ = UH_OH; // silence unused warning
 //! ```
+//!
+//! #### Strict mode
+//! With the `macros` feature, prefixing the argument with `strict:` also
+//! rejects `as` casts to integer types which may lose the value (narrowing
+//! casts, and those which change the sign) and `wrapping_*` operations, even
+//! if the result isn't zero. This lets the macros act as a checkpoint for
+//! auditing security-sensitive constants.
+//!
+//! A cast is allowed if the target type implements `From` for the operand's
+//! type, so `u8 as u32` is fine, but `u32 as u16`, `i32 as u32` and (as it's
+//! lossy on some targets) `u32 as usize` aren't. An unsuffixed literal is an
+//! `i32`, as usual.
+//! ```
+//! # #[cfg(feature = "macros")] {
+//! const KEY_BITS: u16 = 256;
+//! const KEY_BYTES: core::num::NonZeroU16 = nonzero_lit::u16!(strict: KEY_BITS / 8);
+//! assert_eq!(KEY_BYTES.get(), 32);
+//!
+//! const ROUNDS: u8 = 14;
+//! const SCHEDULE: core::num::NonZeroU32 = nonzero_lit::u32!(strict: 4 * (ROUNDS as u32 + 1));
+//! assert_eq!(SCHEDULE.get(), 60);
+//! # }
+//! ```
+#![cfg_attr(feature = "macros", doc = "```compile_fail")]
+#![cfg_attr(not(feature = "macros"), doc = "```ignore")]
+//! const KEY_BITS: u32 = 256;
+//! let key_bytes = nonzero_lit::u16!(strict: (KEY_BITS / 8) as u16);
+//! # let _ = key_bytes; // silence unused warning
+//! ```
+#![cfg_attr(feature = "macros", doc = "```compile_fail")]
+#![cfg_attr(not(feature = "macros"), doc = "```ignore")]
+//! let n = nonzero_lit::u32!(strict: 5u32.wrapping_sub(1));
+//! # let _ = n; // silence unused warning
+//! ```
+//!
+//! #### Truncating casts
+//! An `as` cast can silently truncate a value, possibly to zero. With the
+//! `macros` feature, a cast in the argument which changes the value is an
+//! error pointing at the cast (in a constant named
+//! `TRUNCATING_CAST_CHANGED_THE_VALUE`, unless the `inline_const` feature is
+//! enabled), suggesting a checked conversion.
+#![cfg_attr(feature = "macros", doc = "```compile_fail")]
+#![cfg_attr(not(feature = "macros"), doc = "```ignore")]
+//! let oops = nonzero_lit::u8!(300u16 as u8);
+//! # let _ = oops; // silence unused warning
+//! ```
+//!
+//! Casts which don't change the value are fine, as are casts in other parts
+//! of the expression, such as generic arguments.
+//! ```
+//! trait Bits {
+//!     const BITS: u32;
+//! }
+//! struct Byte;
+//! impl Bits for Byte {
+//!     const BITS: u32 = 8;
+//! }
+//!
+//! assert_eq!(nonzero_lit::u8!(core::mem::size_of::<u64>() as u8).get(), 8);
+//! assert_eq!(nonzero_lit::u8!(<Byte as Bits>::BITS as u8).get(), 8);
+//! ```
+//!
+//! #### Conversions
+//! Prefixing the argument with `from:` converts a constant of any integer type
+//! to the macro's type, checking that it fits (with no truncation or loss of
+//! sign) before checking that it isn't zero. Use this instead of an `as` cast.
+//! ```
+//! const SOME_U64: u64 = 0x100;
+//! const SOME_I8: i8 = 100;
+//! assert_eq!(nonzero_lit::u32!(from: SOME_U64).get(), 0x100);
+//! assert_eq!(nonzero_lit::u8!(from: SOME_I8).get(), 100);
+//! ```
+//! ```compile_fail
+//! const SOME_U32: u32 = 0x100;
+//! let oops = nonzero_lit::u8!(from: SOME_U32);
+//! # let _ = oops; // silence unused warning
+//! ```
+//! ```compile_fail
+//! let oops = nonzero_lit::u32!(from: -1i64);
+//! # let _ = oops; // silence unused warning
+//! ```
 #![no_std]
-#![forbid(unsafe_code)]
+// Note: The `nonnull` module needs to allow unsafe code, which `forbid` doesn't
+// permit.
+#![cfg_attr(not(feature = "nonnull"), forbid(unsafe_code))]
+#![cfg_attr(feature = "nonnull", deny(unsafe_code))]
+
+#[cfg(any(feature = "alloc", feature = "schemars"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array;
+mod assert;
+mod bitflags;
+mod bitint;
+mod bits;
+#[cfg(feature = "borsh")]
+#[clippy::msrv = "1.77"]
+mod borsh_impls;
+mod capacity;
+mod cfg;
+mod checked;
+mod codepoint;
+mod collect;
+mod color;
+mod define;
+#[cfg(feature = "defmt")]
+#[clippy::msrv = "1.76"]
+mod defmt_impls;
+mod errno;
+mod even;
+mod exit_code;
+mod ffi;
+mod fixed;
+mod float;
+pub mod fmt;
+mod id;
+mod index;
+mod leb128;
+mod len;
+mod location;
+mod magic;
+mod magic_div;
+mod map;
+mod math;
+mod net;
+mod nonempty;
+#[cfg(feature = "const_generics")]
+#[clippy::msrv = "1.51"]
+mod nonempty_array;
+#[cfg(feature = "nonnull")]
+mod nonnull;
+mod nul_free;
+#[cfg(feature = "const_generics")]
+#[clippy::msrv = "1.51"]
+mod nz_type;
+pub mod ops;
+mod parse;
+mod percent;
+mod port;
+mod pow2;
+mod range;
+mod ratio;
+mod ring;
+#[cfg(feature = "rkyv")]
+#[clippy::msrv = "1.81"]
+mod rkyv_impls;
+mod saturating;
+#[cfg(feature = "schemars")]
+mod schemars_impls;
+#[cfg(feature = "serde")]
+pub mod serde_adapters;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod signal;
+mod size;
+mod slice;
+pub mod target;
+#[cfg(feature = "test_support")]
+pub mod test_support;
+#[cfg(feature = "std")]
+#[clippy::msrv = "1.59"]
+mod threads;
+mod time;
+#[cfg(feature = "const_str")]
+mod to_str;
+mod typeconst;
+#[cfg(feature = "ufmt")]
+#[clippy::msrv = "1.56"]
+mod ufmt_impls;
+#[cfg(feature = "verify")]
+pub mod verify;
+mod wide;
+
+pub use bitint::{NonZeroI24, NonZeroI48, NonZeroU24, NonZeroU48};
+pub use capacity::Capacity;
+pub use checked::NonZeroChecked;
+#[cfg(feature = "alloc")]
+pub use collect::{from_nonzero_vec, try_nonzero_vec};
+pub use collect::{CollectNonZero, ZeroAt, ZeroableInt};
+pub use errno::unix_errno;
+pub use even::EvenNonZero;
+pub use exit_code::NonZeroExitCode;
+pub use index::{IndexInt, NonZeroIndex};
+pub use len::{len_nonzero, nonempty_with_len};
+pub use magic_div::MagicDiv;
+pub use map::NonZeroMap;
+#[cfg(feature = "alloc")]
+pub use nonempty::NonEmptyVec;
+pub use nonempty::{NonEmptySlice, NonEmptyStr};
+#[cfg(feature = "const_generics")]
+pub use nonempty_array::NonEmptyArray;
+pub use nul_free::NulFreeStr;
+#[cfg(feature = "const_generics")]
+pub use nz_type::{
+    DefaultNonZero, NzI128, NzI16, NzI32, NzI64, NzI8, NzIsize, NzU128, NzU16, NzU32, NzU64, NzU8,
+    NzUsize,
+};
+pub use parse::{parse_runtime, ParseNonZero, ParseNonZeroError};
+pub use percent::Percent;
+pub use port::Port;
+pub use pow2::PowerOfTwo;
+pub use range::NonZeroRange;
+pub use ratio::NonZeroRatio;
+pub use ring::RingCapacity;
+pub use saturating::NonZeroSaturating;
+#[cfg(feature = "signals")]
+pub use signal::unix_signal;
+pub use slice::SliceNonZeroExt;
+pub use typeconst::TypeConst;
+pub use wide::{NonZeroI256, NonZeroU256};
+
+/// Rewrite marked integer literals inside an expression into checked `NonZero`
+/// constants.
+///
+/// Any integer literal prefixed with `#` (such as `#5u32` or `#-1i8`) is
+/// replaced with the matching constructor macro from this crate, so `#5u32`
+/// becomes `nonzero_lit::u32!(5u32)`. Everything else in the expression is left
+/// untouched. The literal must have a type suffix, as that is how the `NonZero`
+/// type is chosen.
+///
+/// Requires the `macros` feature.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::{NonZeroU16, NonZeroU8};
+///
+/// struct Config {
+///     retries: NonZeroU8,
+///     port: NonZeroU16,
+///     backoff: [NonZeroU16; 3],
+/// }
+///
+/// let cfg = nonzero_lit::nonzero_expr!(Config {
+///     retries: #3u8,
+///     port: #8080u16,
+///     backoff: [#100u16, #200u16, #400u16],
+/// });
+/// assert_eq!(cfg.retries.get(), 3);
+/// assert_eq!(cfg.port.get(), 8080);
+/// assert_eq!(cfg.backoff[2].get(), 400);
+/// ```
+///
+/// Works for consts, and with negative literals.
+/// ```
+/// const PAIR: (core::num::NonZeroI32, i32) = nonzero_lit::nonzero_expr!((#-1i32, 0));
+/// assert_eq!(PAIR.0.get(), -1);
+/// ```
+///
+/// Works when this crate is renamed, or used through a re-export.
+/// ```
+/// mod deps {
+///     pub(crate) use ::nonzero_lit as nz;
+/// }
+///
+/// fn main() {
+///     let port = crate::deps::nz::nonzero_expr!(#8080u16);
+///     assert_eq!(port.get(), 8080);
+/// }
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let x = nonzero_lit::nonzero_expr!([#1u32, #0u32]);
+/// ```
+///
+/// Marked literals need a type suffix.
+/// ```compile_fail
+/// let x = nonzero_lit::nonzero_expr!(#5);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! nonzero_expr {
+    ($($t:tt)*) => {
+        $crate::_private::nonzero_expr!($crate; $($t)*)
+    };
+}
+
+/// Derive a `NonZeroU32` constant from a string key, for cheap identifiers
+/// (such as for log messages or trace events) without a registry.
+///
+/// The ID is a hash of the crate's name and the key, so it's stable across
+/// builds, and doesn't change when other calls are added or removed.
+///
+/// Every ID in the program is distinct. Each call exports a symbol named after
+/// its ID (`__nonzero_counter_<id>`), so two calls with the same ID fail to
+/// compile if they're in the same crate, and to link if they're not. That
+/// covers using a key twice (store the ID in a `const` to use it in several
+/// places instead), and a hash collision between different keys (change one
+/// of the keys). Since the crate's name is part of the hash, different crates
+/// can use the same keys, but two versions of one crate can't.
+///
+/// Requires the `macros` feature.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const STARTED: NonZeroU32 = nonzero_lit::nonzero_counter!("started");
+/// const STOPPED: NonZeroU32 = nonzero_lit::nonzero_counter!("stopped");
+/// assert_ne!(STARTED, STOPPED);
+///
+/// // One call gives the same ID each time it runs.
+/// let ids: Vec<_> = (0..3).map(|_| nonzero_lit::nonzero_counter!("polled")).collect();
+/// assert!(ids.iter().all(|&id| id == ids[0]));
+/// ```
+///
+/// Using a key twice is detected at compile time.
+/// ```compile_fail
+/// let a = nonzero_lit::nonzero_counter!("started");
+/// let b = nonzero_lit::nonzero_counter!("started");
+/// ```
+///
+/// The key must be a string literal.
+/// ```compile_fail
+/// let id = nonzero_lit::nonzero_counter!();
+/// ```
+///
+/// ```compile_fail
+/// let id = nonzero_lit::nonzero_counter!(5);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! nonzero_counter {
+    ($($t:tt)*) => {
+        $crate::_private::nonzero_counter!($crate; $($t)*)
+    };
+}
+
+// The expansion of `nonzero_counter!`. The `#[used]` reference makes sure the
+// exported symbol is linked even if nothing else from the calling crate is, so
+// that a duplicate is always reported.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nonzero_counter {
+    ($id:literal, $symbol:literal) => {{
+        #[export_name = $symbol]
+        static __ID: u32 = $id;
+        #[used]
+        static __KEEP: &u32 = &__ID;
+        $crate::u32!($id)
+    }};
+}
+
+/// Derive `NonZero` conversions for an existing enum, checking at compile time
+/// that none of its discriminants are zero.
+///
+/// The enum must have an integer `#[repr]` (such as `#[repr(u8)]`), and only
+/// variants without fields. Duplicate discriminants are already rejected by
+/// the compiler, so every variant gets a distinct `NonZero` value. This adds
+/// two inherent `const fn`s:
+///
+/// - `as_nonzero(self)`, which returns the discriminant as the `NonZero`
+///   version of the `repr` type.
+/// - `from_nonzero(n)`, which returns the variant with that discriminant, if
+///   there is one.
+///
+/// Unlike defining the enum with a macro, this works on enums which already
+/// exist, and whose definitions can't be restructured.
+///
+/// The generated code refers to this crate as `::nonzero_lit`. If it's been
+/// renamed, or is used through a re-export, give its path with
+/// `#[nonzero_repr(crate = path)]`.
+///
+/// Requires the `macros` feature.
+///
+/// # Examples
+/// ```
+/// #[derive(Clone, Copy, Debug, PartialEq, nonzero_lit::NonZeroRepr)]
+/// #[repr(u16)]
+/// enum Opcode {
+///     Read = 1,
+///     Write,
+///     Flush = 0x100,
+/// }
+///
+/// const FLUSH: core::num::NonZeroU16 = Opcode::Flush.as_nonzero();
+/// assert_eq!(FLUSH.get(), 0x100);
+/// assert_eq!(Opcode::Write.as_nonzero().get(), 2);
+/// assert_eq!(Opcode::from_nonzero(nonzero_lit::u16!(2)), Some(Opcode::Write));
+/// assert_eq!(Opcode::from_nonzero(nonzero_lit::u16!(3)), None);
+/// ```
+///
+/// Misuse is detected at compile time, including implicit discriminants of
+/// zero.
+/// ```compile_fail
+/// #[derive(nonzero_lit::NonZeroRepr)]
+/// #[repr(u8)]
+/// enum Level {
+///     Off,
+///     Low,
+/// }
+/// ```
+///
+/// Through a re-export.
+/// ```
+/// mod deps {
+///     pub(crate) use ::nonzero_lit as nz;
+/// }
+///
+/// #[derive(Clone, Copy, deps::nz::NonZeroRepr)]
+/// #[nonzero_repr(crate = crate::deps::nz)]
+/// #[repr(i8)]
+/// enum Sign {
+///     Negative = -1,
+///     Positive = 1,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Sign::Negative.as_nonzero().get(), -1);
+/// }
+/// ```
+///
+/// The enum must have an integer `#[repr]`.
+/// ```compile_fail
+/// #[derive(nonzero_lit::NonZeroRepr)]
+/// enum Level {
+///     Low = 1,
+///     High = 2,
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use nonzero_lit_macros::NonZeroRepr;
+
+/// Check the plain integer initializer of a `const` or `static` item with a
+/// `NonZero` type at compile time.
+///
+/// `#[nonzero] const RETRIES: NonZeroU32 = 5;` is the same as
+/// `const RETRIES: NonZeroU32 = nonzero_lit::u32!(5);`, so the initializer can
+/// be any constant expression the literal macros accept. The type must be
+/// written as a `NonZero` integer type (such as `NonZeroU32`, or
+/// `core::num::NonZeroU32`), as that's how the literal macro is chosen, so
+/// type aliases aren't supported.
+///
+/// The rewritten initializer refers to this crate as `::nonzero_lit`. If it's
+/// been renamed, or is used through a re-export, give its path with
+/// `#[nonzero(crate = path)]`.
+///
+/// Requires the `macros` feature.
+///
+/// # Examples
+/// ```
+/// use core::num::{NonZeroI8, NonZeroU32};
+/// use nonzero_lit::nonzero;
+///
+/// #[nonzero]
+/// const RETRIES: NonZeroU32 = 5;
+///
+/// #[nonzero]
+/// pub(crate) static TIMEOUT_MS: core::num::NonZeroU64 = 30 * 1000;
+///
+/// #[nonzero]
+/// const DOWN: NonZeroI8 = -1;
+///
+/// assert_eq!(RETRIES.get(), 5);
+/// assert_eq!(TIMEOUT_MS.get(), 30_000);
+/// assert_eq!(DOWN.get(), -1);
+/// ```
+///
+/// Through a re-export.
+/// ```
+/// mod deps {
+///     pub(crate) use ::nonzero_lit as nz;
+/// }
+///
+/// #[deps::nz::nonzero(crate = crate::deps::nz)]
+/// const RETRIES: core::num::NonZeroU32 = 5;
+///
+/// fn main() {
+///     assert_eq!(RETRIES.get(), 5);
+/// }
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// #[nonzero_lit::nonzero]
+/// const RETRIES: core::num::NonZeroU32 = 0;
+/// ```
+///
+/// The type must be a `NonZero` integer type.
+/// ```compile_fail
+/// #[nonzero_lit::nonzero]
+/// const RETRIES: u32 = 5;
+/// ```
+#[cfg(feature = "macros")]
+pub use nonzero_lit_macros::nonzero;
+
+// With the `macros` feature, the literal macros check their argument for `as`
+// casts which changed the value, and point at the cast in the error.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diagnose_casts {
+    ($($t:tt)+) => {
+        $crate::_private::__diagnose_casts!($($t)+)
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diagnose_casts {
+    ($($t:tt)+) => {};
+}
+
+// With the `macros` feature, the literal macros reject a literal argument
+// whose suffix is for a different type, with a clearer error than the type
+// mismatch it would otherwise cause.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_suffix {
+    ($int:ident, $val:expr) => {
+        $crate::_private::__check_suffix!($int, $val)
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_suffix {
+    ($int:ident, $val:expr) => {};
+}
+
+// The check for `strict:` arguments to the literal macros.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strict_check {
+    ($($t:tt)+) => {
+        $crate::_private::__strict_check!($crate; $($t)+)
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strict_check {
+    ($($t:tt)+) => {
+        ::core::compile_error!("`strict:` requires the `macros` feature of `nonzero_lit`")
+    };
+}
+
+// The expansion of the literal macros for a single value. With the
+// `inline_const` feature, this is an inline `const` block, which (unlike a
+// `const` item) can refer to `Self` and generic parameters.
+#[cfg(not(feature = "inline_const"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nz_const {
+    ($int:ident, $val:expr) => {{
+        $crate::__check_suffix!($int, $val);
+        const __E: $int = $val;
+        $crate::__diagnose_casts!($val);
+        {
+            #[deny(const_err)]
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(__E);
+            NZ
+        }
+    }};
+}
+
+#[cfg(feature = "inline_const")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nz_const {
+    ($int:ident, $val:expr) => {{
+        $crate::__check_suffix!($int, $val);
+        $crate::__diagnose_casts!(const; $val);
+        const { $crate::_private::nz_ctor::$int($val) }
+    }};
+}
+
+// The expansion of the literal macros for `from:` arguments, which converts
+// the value to the macro's type, checking that it fits.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nz_from {
+    ($int:ident, $val:expr) => {
+        $crate::__nz_const!(
+            $int,
+            $crate::_private::checked_from::$int(($val) < 0, ($val) as i128 as u128)
+        )
+    };
+}
 
 /// Create a literal [`NonZeroUsize`](core::num::NonZeroUsize).
 ///
@@ -143,6 +1003,12 @@
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroUsize; 3] = nonzero_lit::usize!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroUsize = nonzero_lit::usize!(0);
@@ -166,14 +1032,19 @@
 /// ```
 #[macro_export]
 macro_rules! usize {
-    ($val:expr $(,)?) => {{
-        const __E: usize = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroUsize = $crate::_private::nz_usize(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::usize!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(usize, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(usize, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::usize!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroIsize`](core::num::NonZeroIsize).
@@ -193,6 +1064,12 @@ macro_rules! usize {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroIsize; 3] = nonzero_lit::isize!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroIsize = nonzero_lit::isize!(0);
@@ -211,14 +1088,19 @@ macro_rules! usize {
 /// ```
 #[macro_export]
 macro_rules! isize {
-    ($val:expr $(,)?) => {{
-        const __E: isize = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroIsize = $crate::_private::nz_isize(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::isize!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(isize, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(isize, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::isize!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroU8`](core::num::NonZeroU8).
@@ -238,6 +1120,12 @@ macro_rules! isize {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroU8; 3] = nonzero_lit::u8!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroU8 = nonzero_lit::u8!(0);
@@ -256,14 +1144,19 @@ macro_rules! isize {
 /// ```
 #[macro_export]
 macro_rules! u8 {
-    ($val:expr $(,)?) => {{
-        const __E: u8 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroU8 = $crate::_private::nz_u8(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::u8!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(u8, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(u8, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::u8!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroI8`](core::num::NonZeroI8).
@@ -283,6 +1176,12 @@ macro_rules! u8 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroI8; 3] = nonzero_lit::i8!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroI8 = nonzero_lit::i8!(0);
@@ -301,14 +1200,19 @@ macro_rules! u8 {
 /// ```
 #[macro_export]
 macro_rules! i8 {
-    ($val:expr $(,)?) => {{
-        const __E: i8 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroI8 = $crate::_private::nz_i8(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::i8!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(i8, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(i8, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::i8!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroU16`](core::num::NonZeroU16).
@@ -328,6 +1232,12 @@ macro_rules! i8 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroU16; 3] = nonzero_lit::u16!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroU16 = nonzero_lit::u16!(0);
@@ -346,14 +1256,19 @@ macro_rules! i8 {
 /// ```
 #[macro_export]
 macro_rules! u16 {
-    ($val:expr $(,)?) => {{
-        const __E: u16 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroU16 = $crate::_private::nz_u16(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::u16!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(u16, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(u16, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::u16!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroI16`](core::num::NonZeroI16).
@@ -373,6 +1288,12 @@ macro_rules! u16 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroI16; 3] = nonzero_lit::i16!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroI16 = nonzero_lit::i16!(0);
@@ -391,14 +1312,19 @@ macro_rules! u16 {
 /// ```
 #[macro_export]
 macro_rules! i16 {
-    ($val:expr $(,)?) => {{
-        const __E: i16 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroI16 = $crate::_private::nz_i16(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::i16!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(i16, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(i16, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::i16!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroU32`](core::num::NonZeroU32).
@@ -418,11 +1344,22 @@ macro_rules! i16 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroU32; 3] = nonzero_lit::u32!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroU32 = nonzero_lit::u32!(0);
 /// ```
 ///
+/// Including in any element of an array.
+/// ```compile_fail
+/// let xs = nonzero_lit::u32!(1, 0, 3);
+/// ```
+///
 /// Even if dodgy code tries to `#[allow(...)]` it.
 /// ```compile_fail
 /// #[allow(const_err)]
@@ -434,16 +1371,42 @@ macro_rules! i16 {
 /// let bar = 3;
 /// let foo = nonzero_lit::u32!(bar);
 /// ```
+///
+/// With the `inline_const` feature, the argument can also use `Self` and the
+/// generic parameters of the surrounding item. For generic parameters, the
+/// check happens when the item is instantiated.
+/// ```
+/// # #[cfg(feature = "inline_const")] {
+/// use core::num::NonZeroU32;
+///
+/// struct Disk;
+/// impl Disk {
+///     const BLOCK_SIZE: u32 = 512;
+///     const BLOCK: NonZeroU32 = nonzero_lit::u32!(Self::BLOCK_SIZE);
+/// }
+///
+/// fn sectors<const N: u32>() -> NonZeroU32 {
+///     nonzero_lit::u32!(N * 8)
+/// }
+/// assert_eq!(Disk::BLOCK.get(), 512);
+/// assert_eq!(sectors::<4>().get(), 32);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! u32 {
-    ($val:expr $(,)?) => {{
-        const __E: u32 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroU32 = $crate::_private::nz_u32(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::u32!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(u32, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(u32, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::u32!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroI32`](core::num::NonZeroI32).
@@ -463,6 +1426,12 @@ macro_rules! u32 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroI32; 3] = nonzero_lit::i32!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroI32 = nonzero_lit::i32!(0);
@@ -481,14 +1450,19 @@ macro_rules! u32 {
 /// ```
 #[macro_export]
 macro_rules! i32 {
-    ($val:expr $(,)?) => {{
-        const __E: i32 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroI32 = $crate::_private::nz_i32(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::i32!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(i32, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(i32, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::i32!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroU64`](core::num::NonZeroU64).
@@ -508,6 +1482,12 @@ macro_rules! i32 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroU64; 3] = nonzero_lit::u64!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroU64 = nonzero_lit::u64!(0);
@@ -526,14 +1506,19 @@ macro_rules! i32 {
 /// ```
 #[macro_export]
 macro_rules! u64 {
-    ($val:expr $(,)?) => {{
-        const __E: u64 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroU64 = $crate::_private::nz_u64(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::u64!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(u64, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(u64, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::u64!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroI64`](core::num::NonZeroI64).
@@ -553,6 +1538,12 @@ macro_rules! u64 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroI64; 3] = nonzero_lit::i64!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroI64 = nonzero_lit::i64!(0);
@@ -571,14 +1562,19 @@ macro_rules! u64 {
 /// ```
 #[macro_export]
 macro_rules! i64 {
-    ($val:expr $(,)?) => {{
-        const __E: i64 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroI64 = $crate::_private::nz_i64(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::i64!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(i64, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(i64, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::i64!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroU128`](core::num::NonZeroU128).
@@ -598,6 +1594,12 @@ macro_rules! i64 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroU128; 3] = nonzero_lit::u128!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroU128 = nonzero_lit::u128!(0);
@@ -616,14 +1618,19 @@ macro_rules! i64 {
 /// ```
 #[macro_export]
 macro_rules! u128 {
-    ($val:expr $(,)?) => {{
-        const __E: u128 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroU128 = $crate::_private::nz_u128(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::u128!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(u128, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(u128, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::u128!($val)),+]
+    };
 }
 
 /// Create a literal [`NonZeroI128`](core::num::NonZeroI128).
@@ -643,6 +1650,12 @@ macro_rules! u128 {
 /// assert_eq!(B.get(), 50);
 /// ```
 ///
+/// With several arguments, produces an array, checking each element.
+/// ```
+/// const XS: [core::num::NonZeroI128; 3] = nonzero_lit::i128!(1, 2, 3);
+/// assert_eq!(XS[2].get(), 3);
+/// ```
+///
 /// Misuse is detected at compile time.
 /// ```compile_fail
 /// const ZERO: core::num::NonZeroI128 = nonzero_lit::i128!(0);
@@ -661,14 +1674,19 @@ macro_rules! u128 {
 /// ```
 #[macro_export]
 macro_rules! i128 {
-    ($val:expr $(,)?) => {{
-        const __E: i128 = $val;
-        {
-            #[deny(const_err)]
-            const NZ: $crate::_private::NonZeroI128 = $crate::_private::nz_i128(__E);
-            NZ
-        }
+    (strict: $($t:tt)+) => {{
+        $crate::__strict_check!($($t)+);
+        $crate::i128!($($t)+)
     }};
+    (from: $val:expr $(,)?) => {
+        $crate::__nz_from!(i128, $val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::__nz_const!(i128, $val)
+    };
+    ($($val:expr),+ $(,)?) => {
+        [$($crate::i128!($val)),+]
+    };
 }
 
 // Implementation detail — not part of public API.
@@ -679,6 +1697,117 @@ pub mod _private {
         NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
     };
 
+    #[cfg(feature = "macros")]
+    pub use nonzero_lit_macros::{
+        __check_suffix, __diagnose_casts, __strict_check, nonzero_counter, nonzero_expr,
+    };
+
+    pub use crate::assert::{aligned_report, fits_report, pow2_report, NotZst};
+    pub use crate::bits::{morton_mask, morton_steps, parse_bitpattern, popcount_eq, shift_check};
+    pub use crate::capacity::{capacity, capacity_for};
+    pub use crate::codepoint::{codepoint, parse_codepoint};
+    pub use crate::color::{color, parse_color};
+    pub use crate::exit_code::exit_code;
+    pub use crate::fixed::parse_fixed;
+    pub use crate::float::{float_bits_u32, float_bits_u64};
+    pub use crate::leb128::{leb128_byte, leb128_len, sleb128_byte, sleb128_len};
+    pub use crate::len::len_of;
+    pub use crate::magic::{magic_u32, magic_u64};
+    pub use crate::map::{assert_distinct_keys, nonzero_map};
+    pub use crate::math::{binomial, fib, mod_inverse, mod_inverse_pow2, pow_mod};
+    pub use crate::net::parse_ipv4;
+    #[cfg(feature = "alloc")]
+    pub use crate::nonempty::nonempty_vec;
+    pub use crate::nonempty::{nonempty_slice, nonempty_str};
+    #[cfg(feature = "nonnull")]
+    pub use crate::nonnull::nonnull;
+    pub use crate::nul_free::{nul_free_byte, nul_free_str};
+    pub use crate::percent::percent;
+    pub use crate::port::{port, unprivileged_port};
+    pub use crate::ratio::ratio;
+    pub use crate::ring::ring_capacity;
+    pub use crate::signal::{signal_i32, signal_u32};
+    pub use crate::size::parse_bytes;
+    #[cfg(feature = "std")]
+    pub use crate::threads::{threads, threads_bounds};
+    pub use crate::time::{
+        backoff_check, backoff_next, parse_duration, parse_epoch, parse_unix_time, time_ticks,
+    };
+    #[cfg(feature = "const_str")]
+    pub use crate::to_str::{digit, digits_len};
+    pub use crate::wide::{parse_i256, parse_u256};
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec;
+    #[cfg(feature = "nonnull")]
+    pub use core::ptr::NonNull;
+
+    /// The check for `as` casts in `strict:` arguments to the literal macros,
+    /// which only allows casts that can't lose the value.
+    #[cfg(feature = "macros")]
+    #[inline]
+    pub fn strict_cast<T: From<U>, U>(u: U) -> T {
+        T::from(u)
+    }
+
+    /// Implemented by the unsigned integer types, for `at_least_one!` when it
+    /// isn't given the type.
+    pub trait AtLeastOne {
+        type NonZero;
+        fn at_least_one(self) -> Self::NonZero;
+    }
+
+    /// The hidden helpers for each `bitint!` type, by name (e.g.
+    /// `bitint::u24::new`).
+    pub mod bitint {
+        pub use crate::bitint::{i24, i48, u24, u48};
+    }
+
+    /// Aliases from each integer type's name to the matching `NonZero` type,
+    /// for macros which are told the integer type (e.g. `nz::u32` is
+    /// `NonZeroU32`).
+    pub mod nz {
+        pub use core::num::{
+            NonZeroI128 as i128, NonZeroI16 as i16, NonZeroI32 as i32, NonZeroI64 as i64,
+            NonZeroI8 as i8, NonZeroIsize as isize, NonZeroU128 as u128, NonZeroU16 as u16,
+            NonZeroU32 as u32, NonZeroU64 as u64, NonZeroU8 as u8, NonZeroUsize as usize,
+        };
+    }
+
+    /// Like `nz`, but for the checked constructor functions (e.g.
+    /// `nz_ctor::u32` is `nz_u32`).
+    pub mod nz_ctor {
+        pub use super::{
+            nz_i128 as i128, nz_i16 as i16, nz_i32 as i32, nz_i64 as i64, nz_i8 as i8,
+            nz_isize as isize, nz_u128 as u128, nz_u16 as u16, nz_u32 as u32, nz_u64 as u64,
+            nz_u8 as u8, nz_usize as usize,
+        };
+    }
+
+    /// The checked conversions for `from:` arguments to the literal macros,
+    /// which take the value's sign and its bits as a `u128` (e.g.
+    /// `checked_from::u8(false, 300)` fails to compile).
+    pub mod checked_from {
+        macro_rules! define_checked_from {
+            ($($int:ident)+) => {$(
+                #[inline]
+                pub const fn $int(negative: bool, v: u128) -> $int {
+                    let fits = if negative {
+                        v as i128 >= $int::MIN as i128
+                    } else {
+                        v <= $int::MAX as u128
+                    };
+                    // Note: Hacky const fn assert.
+                    let _ = ["`from:` value doesn't fit in the macro's type"][!fits as usize];
+                    v as $int
+                }
+            )+};
+        }
+
+        define_checked_from! {
+            usize isize u8 i8 u16 i16 u32 i32 u64 i64 u128 i128
+        }
+    }
+
     macro_rules! define_nz_ctor {
         ($(pub fn $nz_func:ident($n:ident : $int:ident) -> $NonZeroInt:ident;)+) => {$(
             #[inline]
@@ -0,0 +1,41 @@
+/// Expands to the line number on which it was invoked, as a
+/// [`NonZeroU32`](core::num::NonZeroU32).
+///
+/// This is [`line!()`](core::line), but typed so that (for example) an
+/// `Option` of a source location doesn't need any extra space.
+///
+/// # Examples
+/// ```
+/// let (here, expected) = (nonzero_lit::line_nonzero!(), line!());
+/// assert_eq!(here.get(), expected);
+/// ```
+///
+/// Works for consts too.
+/// ```
+/// const LINE: core::num::NonZeroU32 = nonzero_lit::line_nonzero!();
+/// assert_eq!(LINE.get(), line!() - 1);
+/// ```
+#[macro_export]
+macro_rules! line_nonzero {
+    () => {
+        $crate::u32!(line!())
+    };
+}
+
+/// Expands to the column number at which it was invoked, as a
+/// [`NonZeroU32`](core::num::NonZeroU32).
+///
+/// This is [`column!()`](core::column), but typed so that (for example) an
+/// `Option` of a source location doesn't need any extra space.
+///
+/// # Examples
+/// ```
+/// let (col, expected) = (nonzero_lit::column_nonzero!(), column!());
+/// assert!(col.get() < expected);
+/// ```
+#[macro_export]
+macro_rules! column_nonzero {
+    () => {
+        $crate::u32!(column!())
+    };
+}
@@ -0,0 +1,103 @@
+use core::num::{NonZeroU32, NonZeroU64};
+
+use crate::_private::{nz_u32, nz_u64};
+
+#[doc(hidden)]
+#[inline]
+pub const fn magic_u32(b: &[u8], little_endian: bool) -> NonZeroU32 {
+    let b = [b[0], b[1], b[2], b[3]];
+    let n = if little_endian {
+        u32::from_le_bytes(b)
+    } else {
+        u32::from_be_bytes(b)
+    };
+    // Note: Hacky const fn assert.
+    let _ = ["magic number must not be all zero bytes"][(n == 0) as usize];
+    nz_u32(n)
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn magic_u64(b: &[u8], little_endian: bool) -> NonZeroU64 {
+    let b = [b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]];
+    let n = if little_endian {
+        u64::from_le_bytes(b)
+    } else {
+        u64::from_be_bytes(b)
+    };
+    // Note: Hacky const fn assert.
+    let _ = ["magic number must not be all zero bytes"][(n == 0) as usize];
+    nz_u64(n)
+}
+
+/// Pack a 4-byte (or 8-byte) magic number, such as a FourCC, into a
+/// `NonZeroU32` (or `NonZeroU64`), checking at compile time that it isn't all
+/// zero bytes.
+///
+/// By default, the bytes are packed big-endian, so the first byte is the most
+/// significant (and the value reads the same as the string when printed in
+/// hex). Prefix the argument with `le:` to pack them little-endian instead,
+/// which gives the value you'd get by reading the bytes from a file as a
+/// little-endian integer. `be:` is also accepted, for symmetry.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::{NonZeroU32, NonZeroU64};
+///
+/// const RIFF: NonZeroU32 = nonzero_lit::magic!(b"RIFF");
+/// assert_eq!(RIFF.get(), 0x5249_4646);
+///
+/// const PNG: NonZeroU64 = nonzero_lit::magic!(b"\x89PNG\r\n\x1a\n");
+/// assert_eq!(PNG.get().to_be_bytes(), *b"\x89PNG\r\n\x1a\n");
+/// ```
+///
+/// Choosing the endianness.
+/// ```
+/// let le = nonzero_lit::magic!(le: b"fmt ");
+/// let be = nonzero_lit::magic!(be: b"fmt ");
+/// assert_eq!(le.get(), u32::from_le_bytes(*b"fmt "));
+/// assert_eq!(le.get(), be.get().swap_bytes());
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let m = nonzero_lit::magic!(b"\0\0\0\0");
+/// ```
+///
+/// Only 4 and 8 byte magic numbers are supported.
+/// ```compile_fail
+/// let m = nonzero_lit::magic!(b"GIF89a");
+/// ```
+#[macro_export]
+macro_rules! magic {
+    (@imp $little_endian:expr, $bytes:expr) => {{
+        const __E: &[u8] = $bytes;
+        {
+            // Note: Picks the type based on the length of the input. Only the
+            // associated constant for that length is ever evaluated.
+            trait Magic {
+                type NonZero;
+                const NZ: Self::NonZero;
+            }
+            impl Magic for [u8; 4] {
+                type NonZero = $crate::_private::NonZeroU32;
+                const NZ: Self::NonZero = $crate::_private::magic_u32(__E, $little_endian);
+            }
+            impl Magic for [u8; 8] {
+                type NonZero = $crate::_private::NonZeroU64;
+                const NZ: Self::NonZero = $crate::_private::magic_u64(__E, $little_endian);
+            }
+            <[u8; __E.len()] as Magic>::NZ
+        }
+    }};
+    (le: $bytes:expr $(,)?) => {
+        $crate::magic!(@imp true, $bytes)
+    };
+    (be: $bytes:expr $(,)?) => {
+        $crate::magic!(@imp false, $bytes)
+    };
+    ($bytes:expr $(,)?) => {
+        $crate::magic!(@imp false, $bytes)
+    };
+}
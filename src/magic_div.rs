@@ -0,0 +1,187 @@
+use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+// Computes the multiplier (which may need `bits + 1` bits, in which case it's
+// the "add" variant, and the top bit is implied), and the shift, for dividing
+// `bits`-bit integers by `d`. This is the same algorithm as libdivide uses.
+const fn magic(d: u128, bits: u32) -> (u128, u32, bool) {
+    let log2 = 127 - d.leading_zeros();
+    if d & (d - 1) == 0 {
+        // `n / 2^k` is the high half of `n * 2^(bits - k)`.
+        return (1 << (bits - log2), 0, false);
+    }
+    let p = 1u128 << (bits + log2);
+    let (m, rem) = (p / d, p % d);
+    if d - rem < 1 << log2 {
+        (m + 1, log2, false)
+    } else {
+        let m = 2 * m + (2 * rem >= d) as u128 + 1;
+        (m & ((1 << bits) - 1), log2, true)
+    }
+}
+
+/// The constants for dividing by a fixed divisor with a multiplication and a
+/// shift, as compilers (and libdivide) do.
+///
+/// Use [`nonzero_lit::magic_div!`](crate::magic_div) to compute one at compile
+/// time. With `t` as the high half of `n * multiplier` (so `t` is
+/// `(n as u128 * multiplier as u128) >> BITS`), `n / divisor` is `t >> shift`,
+/// or if [`is_add`](Self::is_add) is true (meaning the real multiplier needs
+/// one more bit than the type has, which is left implied),
+/// `((n - t) / 2 + t) >> shift`. [`divide`](Self::divide) does this.
+///
+/// It's implemented for the unsigned types up to 64 bits wide. Division by one
+/// isn't supported, as no multiplier works for it (and there's no need).
+///
+/// Since there's a [`new`](Self::new) for each of those types, calling it at
+/// runtime needs the type spelled out, as in `MagicDiv::<NonZeroU8>::new(d)`
+/// (plain `MagicDiv::new(d)` is rejected as ambiguous).
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU8;
+/// use nonzero_lit::MagicDiv;
+///
+/// const BY_10: MagicDiv<core::num::NonZeroU32> = nonzero_lit::magic_div!(u32, 10);
+/// assert_eq!(BY_10.multiplier().get(), 0xcccc_cccd);
+/// assert_eq!(BY_10.shift(), 3);
+/// assert!(!BY_10.is_add());
+/// assert_eq!(BY_10.divide(12345), 1234);
+///
+/// // Check every `u8` divisor against every `u8` value.
+/// for d in 2..=u8::MAX {
+///     let magic = MagicDiv::<NonZeroU8>::new(NonZeroU8::new(d).unwrap()).unwrap();
+///     for n in 0..=u8::MAX {
+///         assert_eq!(magic.divide(n), n / d);
+///     }
+/// }
+/// assert_eq!(MagicDiv::<NonZeroU8>::new(nonzero_lit::u8!(1)), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MagicDiv<T> {
+    multiplier: T,
+    shift: u32,
+    add: bool,
+}
+
+macro_rules! impl_magic_div {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl MagicDiv<$NonZeroInt> {
+            const BITS: u32 = core::mem::size_of::<$int>() as u32 * 8;
+
+            /// Computes the constants for dividing by `d`, returning `None` if
+            /// `d` is one.
+            ///
+            /// The type must be named when calling this, as in
+            /// `MagicDiv::<NonZeroU32>::new(d)`.
+            #[inline]
+            pub const fn new(d: $NonZeroInt) -> Option<Self> {
+                if d.get() == 1 {
+                    return None;
+                }
+                let (m, shift, add) = magic(d.get() as u128, Self::BITS);
+                match $NonZeroInt::new(m as $int) {
+                    Some(multiplier) => Some(Self { multiplier, shift, add }),
+                    None => None,
+                }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_const(d: $int) -> Self {
+                // Note: Hacky const fn assert.
+                let _ = ["magic_div! divisor must be greater than one"][(d <= 1) as usize];
+                let (m, shift, add) = magic(d as u128, Self::BITS);
+                Self {
+                    multiplier: crate::_private::nz_ctor::$int(m as $int),
+                    shift,
+                    add,
+                }
+            }
+
+            /// Returns the multiplier, leaving out the implied top bit if
+            /// [`is_add`](Self::is_add) is true.
+            #[inline]
+            pub const fn multiplier(self) -> $NonZeroInt {
+                self.multiplier
+            }
+
+            /// Returns the amount to shift right by, after the multiplication.
+            #[inline]
+            pub const fn shift(self) -> u32 {
+                self.shift
+            }
+
+            /// Returns true if the multiplier has an implied top bit, which
+            /// is handled by adding `n` back in.
+            #[inline]
+            pub const fn is_add(self) -> bool {
+                self.add
+            }
+
+            /// Returns `n` divided by the divisor.
+            #[inline]
+            pub const fn divide(self, n: $int) -> $int {
+                let t = ((n as u128 * self.multiplier.get() as u128) >> Self::BITS) as $int;
+                if self.add {
+                    (((n - t) >> 1) + t) >> self.shift
+                } else {
+                    t >> self.shift
+                }
+            }
+        }
+    )+};
+}
+
+impl_magic_div! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroUsize => usize;
+}
+
+/// Compute the constants for dividing by a fixed divisor with a
+/// multiplication and a shift, producing a [`MagicDiv`].
+///
+/// The first argument is the (unsigned) integer type, which can be at most 64
+/// bits wide, and the second is the divisor, which must be greater than one.
+/// See [`MagicDiv`] for how to use the result.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let by_7 = nonzero_lit::magic_div!(u32, 7);
+/// assert_eq!((by_7.multiplier().get(), by_7.shift(), by_7.is_add()), (0x2492_4925, 2, true));
+/// assert_eq!(by_7.divide(u32::MAX), u32::MAX / 7);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// const PAGE_SIZE: u64 = 4096;
+/// const BLOCK: u64 = 3 * PAGE_SIZE;
+/// const BY_BLOCK: NonZeroU64 = nonzero_lit::magic_div!(u64, BLOCK).multiplier();
+/// assert_eq!(BY_BLOCK.get(), 0xaaaa_aaaa_aaaa_aaab);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let by_1 = nonzero_lit::magic_div!(u32, 1);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let d = 7;
+/// let by_d = nonzero_lit::magic_div!(u32, d);
+/// ```
+#[macro_export]
+macro_rules! magic_div {
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const M: $crate::MagicDiv<$crate::_private::nz::$int> =
+                $crate::MagicDiv::<$crate::_private::nz::$int>::__from_const(__E);
+            M
+        }
+    }};
+}
@@ -0,0 +1,202 @@
+/// A static map keyed by `NonZero` integers, built at compile time by
+/// [`nonzero_map!`](crate::nonzero_map).
+///
+/// Keys are sorted when the map is built, and lookups use a binary search.
+/// Duplicate keys (as well as zero keys) are a compile error.
+///
+/// `K` is always one of the `NonZero` types from `core::num`.
+#[derive(Copy, Clone, Debug)]
+pub struct NonZeroMap<K: 'static, V: 'static> {
+    // Sorted.
+    keys: &'static [K],
+    // `values[slots[i]]` is the value for `keys[i]`.
+    slots: &'static [usize],
+    // In the order the user wrote them.
+    values: &'static [V],
+}
+
+impl<K, V> NonZeroMap<K, V> {
+    /// Returns the number of entries in the map.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns true if the map has no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<K: Ord + Copy, V> NonZeroMap<K, V> {
+    /// Returns the value corresponding to `key`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU32;
+    /// use nonzero_lit::NonZeroMap;
+    ///
+    /// static M: NonZeroMap<NonZeroU32, char> = nonzero_lit::nonzero_map!(u32 => char {
+    ///     3 => 'c',
+    ///     1 => 'a',
+    /// });
+    /// assert_eq!(M.get(nonzero_lit::u32!(1)), Some(&'a'));
+    /// assert_eq!(M.get(nonzero_lit::u32!(2)), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&'static V> {
+        match self.keys.binary_search(&key) {
+            Ok(i) => Some(&self.values[self.slots[i]]),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns true if the map contains `key`.
+    #[inline]
+    pub fn contains_key(&self, key: K) -> bool {
+        self.keys.binary_search(&key).is_ok()
+    }
+
+    /// Returns an iterator over the keys, in ascending order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = K> + 'static {
+        self.keys.iter().copied()
+    }
+
+    /// Returns an iterator over the entries, in ascending order of key.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use nonzero_lit::NonZeroMap;
+    ///
+    /// const M: NonZeroMap<NonZeroU8, &str> = nonzero_lit::nonzero_map!(u8 => &'static str {
+    ///     20 => "store",
+    ///     10 => "load",
+    /// });
+    /// let v: Vec<_> = M.iter().map(|(k, v)| (k.get(), *v)).collect();
+    /// assert_eq!(v, [(10, "load"), (20, "store")]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (K, &'static V)> + 'static {
+        let values = self.values;
+        self.keys
+            .iter()
+            .zip(self.slots)
+            .map(move |(k, &s)| (*k, &values[s]))
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn assert_distinct_keys(a: u128, b: u128) {
+    // Note: Hacky const fn assert.
+    let _ = ["nonzero_map! keys must be distinct"][(a == b) as usize];
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn nonzero_map<K, V>(
+    keys: &'static [K],
+    slots: &'static [usize],
+    values: &'static [V],
+) -> NonZeroMap<K, V> {
+    NonZeroMap {
+        keys,
+        slots,
+        values,
+    }
+}
+
+/// Build a [`NonZeroMap`], a static map keyed by compile-time checked `NonZero`
+/// integers.
+///
+/// The first argument is the integer type of the keys (as in, `u32` for
+/// `NonZeroU32` keys), and the second is the type of the values. Keys may be
+/// any constant expression, and may be given in any order. Zero or duplicate
+/// keys fail the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+/// use nonzero_lit::NonZeroMap;
+///
+/// const STORE: u32 = 0x20;
+/// static OPCODES: NonZeroMap<NonZeroU32, &str> = nonzero_lit::nonzero_map!(u32 => &'static str {
+///     0x30 => "jump",
+///     0x10 => "load",
+///     STORE => "store",
+/// });
+/// assert_eq!(OPCODES.get(nonzero_lit::u32!(0x20)), Some(&"store"));
+/// assert_eq!(OPCODES.len(), 3);
+/// ```
+///
+/// The map may be empty.
+/// ```
+/// let m = nonzero_lit::nonzero_map!(u32 => char {});
+/// assert!(m.is_empty());
+/// assert_eq!(m.get(nonzero_lit::u32!(1)), None);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let m = nonzero_lit::nonzero_map!(u32 => &'static str { 0 => "zero" });
+/// ```
+///
+/// Including duplicate keys.
+/// ```compile_fail
+/// let m = nonzero_lit::nonzero_map!(u16 => i32 { 1 => 10, 2 => 20, 1 => 30 });
+/// ```
+///
+/// Note: keys must be constant expressions.
+/// ```compile_fail
+/// let bar = 3;
+/// let m = nonzero_lit::nonzero_map!(u8 => () { bar => () });
+/// ```
+#[macro_export]
+macro_rules! nonzero_map {
+    ($int:ident => $V:ty { $($k:expr => $v:expr),* $(,)? }) => {{
+        // Note: The slice type lets an empty map infer the element type.
+        const __LEN: usize = <[&str]>::len(&[$(stringify!($k)),*]);
+        const __KEYS: [$crate::_private::nz::$int; __LEN] = [$($crate::$int!($k)),*];
+        const __VALUES: &[$V] = &[$($v),*];
+        {
+            // Sorted keys, and for each, the index of its value.
+            const SORTED: ([$crate::_private::nz::$int; __LEN], [usize; __LEN]) = {
+                let mut keys = __KEYS;
+                let mut slots = [0usize; __LEN];
+                let mut i = 0;
+                while i < __LEN {
+                    slots[i] = i;
+                    i += 1;
+                }
+                let mut i = 1;
+                while i < __LEN {
+                    let mut j = i;
+                    while j > 0 && keys[j - 1].get() > keys[j].get() {
+                        let k = keys[j];
+                        keys[j] = keys[j - 1];
+                        keys[j - 1] = k;
+                        let s = slots[j];
+                        slots[j] = slots[j - 1];
+                        slots[j - 1] = s;
+                        j -= 1;
+                    }
+                    i += 1;
+                }
+                let mut i = 1;
+                while i < __LEN {
+                    let (a, b) = (keys[i - 1].get() as u128, keys[i].get() as u128);
+                    $crate::_private::assert_distinct_keys(a, b);
+                    i += 1;
+                }
+                (keys, slots)
+            };
+            const MAP: $crate::NonZeroMap<$crate::_private::nz::$int, $V> =
+                $crate::_private::nonzero_map(&SORTED.0, &SORTED.1, __VALUES);
+            MAP
+        }
+    }};
+}
@@ -0,0 +1,689 @@
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+#[doc(hidden)]
+pub const fn binomial(n: u32, k: u32) -> u128 {
+    // Note: Hacky const fn assert.
+    let _ = ["binomial! requires k <= n"][(k > n) as usize];
+    let k = if k > n - k { n - k } else { k };
+    let mut r: u128 = 1;
+    let mut i: u32 = 0;
+    while i < k {
+        // `r * (n - i) / (i + 1)` is an integer, so after dividing out the
+        // common factor of `r` and `i + 1`, the rest of `i + 1` divides
+        // `n - i`. This only overflows if the result does.
+        let d = (i + 1) as u128;
+        let g = gcd(r, d);
+        let (v, overflowed) = match (r / g).checked_mul((n - i) as u128 / (d / g)) {
+            Some(v) => (v, false),
+            None => (0, true),
+        };
+        let _ = ["binomial! overflowed"][overflowed as usize];
+        r = v;
+        i += 1;
+    }
+    r
+}
+
+// `(a + b) % m`, for `a` and `b` less than `m`, without overflowing.
+const fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+// `(a * b) % m`, for `a` and `b` less than `m`, without overflowing.
+const fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(p) = a.checked_mul(b) {
+        return p % m;
+    }
+    let (mut a, mut b, mut r) = (a, b, 0);
+    while b != 0 {
+        if b & 1 != 0 {
+            r = add_mod(r, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    r
+}
+
+#[doc(hidden)]
+pub const fn pow_mod(base: u128, exp: u128, m: u128) -> u128 {
+    // Note: Hacky const fn assert.
+    let _ = ["pow_mod! modulus must not be zero"][(m == 0) as usize];
+    let (mut base, mut exp, mut r) = (base % m, exp, 1 % m);
+    while exp != 0 {
+        if exp & 1 != 0 {
+            r = mul_mod(r, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    r
+}
+
+// `(a - b) % m`, for `a` and `b` less than `m`, without overflowing.
+const fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        a + (m - b)
+    }
+}
+
+#[doc(hidden)]
+pub const fn mod_inverse(a: u128, m: u128) -> u128 {
+    // Note: Hacky const fn assert.
+    let _ = ["mod_inverse! modulus must be greater than one"][(m <= 1) as usize];
+    // Extended Euclid, keeping the coefficients reduced modulo `m`.
+    let (mut r0, mut r1) = (m, a % m);
+    let (mut t0, mut t1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        let r2 = r0 - q * r1;
+        let t2 = sub_mod(t0, mul_mod(q % m, t1, m), m);
+        r0 = r1;
+        r1 = r2;
+        t0 = t1;
+        t1 = t2;
+    }
+    // Note: Hacky const fn assert.
+    let _ = ["mod_inverse! value has no inverse for this modulus"][(r0 != 1) as usize];
+    t0
+}
+
+#[doc(hidden)]
+pub const fn mod_inverse_pow2(a: u128) -> u128 {
+    // Note: Hacky const fn assert.
+    let _ = ["mod_inverse! value must be odd to have an inverse"][(a & 1 == 0) as usize];
+    // Newton's method: each step doubles the number of correct low bits, and
+    // `a` is its own inverse modulo 8.
+    let mut x = a;
+    let mut i = 0;
+    while i < 6 {
+        x = x.wrapping_mul(2u128.wrapping_sub(a.wrapping_mul(x)));
+        i += 1;
+    }
+    x
+}
+
+#[doc(hidden)]
+pub const fn fib(n: u32) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    // Stops at `b = F(n)`, so that computing `F(n + 1)` can't overflow.
+    let (mut a, mut b): (u128, u128) = (0, 1);
+    let mut i = 1;
+    while i < n {
+        let (v, overflowed) = match a.checked_add(b) {
+            Some(v) => (v, false),
+            None => (0, true),
+        };
+        // Note: Hacky const fn assert.
+        let _ = ["fib! overflowed"][overflowed as usize];
+        a = b;
+        b = v;
+        i += 1;
+    }
+    b
+}
+
+/// Compute the integer square root (rounded down) of a constant at compile
+/// time, producing a `NonZero` value.
+///
+/// The first argument is the (unsigned) integer type. The input must be
+/// nonzero, so the result is at least 1. This is a compile-time wrapper around
+/// `isqrt` in the matching [`ops`](crate::ops) module.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let side = nonzero_lit::isqrt_nonzero!(u32, 1000);
+/// assert_eq!(side.get(), 31);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroUsize;
+/// const CELLS: usize = 64 * 64;
+/// const GRID_SIDE: NonZeroUsize = nonzero_lit::isqrt_nonzero!(usize, CELLS);
+/// assert_eq!(GRID_SIDE.get(), 64);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let side = nonzero_lit::isqrt_nonzero!(u32, 0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let side = nonzero_lit::isqrt_nonzero!(u32, bar);
+/// ```
+#[macro_export]
+macro_rules! isqrt_nonzero {
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const NZ: $crate::_private::nz::$int =
+                $crate::ops::$int::isqrt($crate::_private::nz_ctor::$int(__E));
+            NZ
+        }
+    }};
+}
+
+/// Compute the factorial of a constant at compile time, producing a `NonZero`
+/// value.
+///
+/// The first argument is the integer type of the result, and the second is
+/// `n` (a `u32`). Overflow fails the build, rather than wrapping. Since
+/// `0! = 1`, the result is never zero.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let f = nonzero_lit::factorial!(u64, 12);
+/// assert_eq!(f.get(), 479_001_600);
+/// assert_eq!(nonzero_lit::factorial!(u8, 0).get(), 1);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU128;
+/// const CARDS: u32 = 52;
+/// const ORDERINGS: NonZeroU128 = nonzero_lit::factorial!(u128, CARDS / 2);
+/// assert_eq!(ORDERINGS.get(), 403_291_461_126_605_635_584_000_000);
+/// ```
+///
+/// Overflow is detected at compile time.
+/// ```compile_fail
+/// let f = nonzero_lit::factorial!(u64, 21);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let f = nonzero_lit::factorial!(u64, bar);
+/// ```
+#[macro_export]
+macro_rules! factorial {
+    ($int:ident, $n:expr $(,)?) => {{
+        const __N: u32 = $n;
+        {
+            const V: $int = {
+                let mut acc: $int = 1;
+                let mut i: u32 = 2;
+                while i <= __N {
+                    let (v, overflowed) = match acc.checked_mul(i as $int) {
+                        Some(v) => (v, false),
+                        None => (0, true),
+                    };
+                    // Note: Hacky const assert.
+                    let _ = ["factorial! overflowed"][overflowed as usize];
+                    acc = v;
+                    i += 1;
+                }
+                acc
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Compute the binomial coefficient `C(n, k)` (the number of ways to choose
+/// `k` items from `n`) at compile time, producing a `NonZero` value.
+///
+/// The first argument is the integer type of the result, followed by `n` and
+/// `k` (both `u32`s). `k` must be at most `n` (which is what makes the result
+/// nonzero), and the result must fit in the type.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let c = nonzero_lit::binomial!(u64, 52, 5);
+/// assert_eq!(c.get(), 2_598_960);
+/// assert_eq!(nonzero_lit::binomial!(u8, 7, 0).get(), 1);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU128;
+/// const N: u32 = 100;
+/// const MIDDLE: NonZeroU128 = nonzero_lit::binomial!(u128, N, N / 2);
+/// assert_eq!(MIDDLE.get(), 100_891_344_545_564_193_334_812_497_256);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let c = nonzero_lit::binomial!(u64, 3, 4);
+/// ```
+///
+/// Including a result which doesn't fit in the type.
+/// ```compile_fail
+/// let c = nonzero_lit::binomial!(u8, 12, 6);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let c = nonzero_lit::binomial!(u64, 10, bar);
+/// ```
+#[macro_export]
+macro_rules! binomial {
+    ($int:ident, $n:expr, $k:expr $(,)?) => {{
+        const __N: u32 = $n;
+        const __K: u32 = $k;
+        {
+            const C: u128 = $crate::_private::binomial(__N, __K);
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["binomial! result doesn't fit in the integer type"]
+                    [(C > $int::MAX as u128) as usize];
+                C as $int
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Compute the `n`th Fibonacci number at compile time, producing a `NonZero`
+/// value.
+///
+/// The first argument is the integer type of the result, and the second is
+/// `n` (a `u32`), counting from `F(0) = 0` and `F(1) = 1`. As `F(0)` is zero,
+/// `n` must be at least 1. Overflow fails the build.
+///
+/// See also [`nonzero_lit::fib_table!`](crate::fib_table).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let f = nonzero_lit::fib!(u64, 50);
+/// assert_eq!(f.get(), 12_586_269_025);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU32;
+/// const LEVEL: u32 = 10;
+/// const DELAY_MS: NonZeroU32 = nonzero_lit::fib!(u32, LEVEL + 2);
+/// assert_eq!(DELAY_MS.get(), 144);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let f = nonzero_lit::fib!(u64, 0);
+/// ```
+///
+/// Including overflow.
+/// ```compile_fail
+/// let f = nonzero_lit::fib!(u8, 14);
+/// ```
+#[macro_export]
+macro_rules! fib {
+    ($int:ident, $n:expr $(,)?) => {{
+        const __N: u32 = $n;
+        {
+            const F: u128 = $crate::_private::fib(__N);
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["fib! overflowed"][(F > $int::MAX as u128) as usize];
+                F as $int
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Produce an array of the first `N` nonzero Fibonacci numbers at compile
+/// time, as `NonZero` values.
+///
+/// The first argument is the integer type, and the second is `N` (a `usize`).
+/// The array starts at `F(1)`, so it's `[1, 1, 2, 3, 5, ...]`. Overflow fails
+/// the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let t = nonzero_lit::fib_table!(u16, 8);
+/// let v: Vec<u16> = t.iter().map(|n| n.get()).collect();
+/// assert_eq!(v, [1, 1, 2, 3, 5, 8, 13, 21]);
+/// ```
+///
+/// Works for consts.
+/// ```
+/// use core::num::NonZeroU64;
+/// const BACKOFF: [NonZeroU64; 12] = nonzero_lit::fib_table!(u64, 12);
+/// assert_eq!(BACKOFF[11].get(), 144);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::fib_table!(u8, 14);
+/// ```
+#[macro_export]
+macro_rules! fib_table {
+    ($int:ident, $n:expr $(,)?) => {{
+        const __N: usize = $n;
+        {
+            const T: [$crate::_private::nz::$int; __N] = {
+                let mut out = [$crate::_private::nz_ctor::$int(1); __N];
+                let mut i = 0;
+                while i < __N {
+                    let f = $crate::_private::fib(i as u32 + 1);
+                    // Note: Hacky const assert.
+                    let _ = ["fib_table! overflowed"][(f > $int::MAX as u128) as usize];
+                    out[i] = $crate::_private::nz_ctor::$int(f as $int);
+                    i += 1;
+                }
+                out
+            };
+            T
+        }
+    }};
+}
+
+/// Produce an array of the first `N` powers of a base at compile time, as
+/// `NonZero` values.
+///
+/// The arguments are the integer type, the base (of that type), and `N` (a
+/// `usize`). The array starts at `base^0`, so element `i` is `base^i`. A base
+/// of zero, or overflow, fails the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let t = nonzero_lit::powers_of!(u16, 10, 5);
+/// let v: Vec<u16> = t.iter().map(|n| n.get()).collect();
+/// assert_eq!(v, [1, 10, 100, 1000, 10000]);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// const RADIX: u64 = 36;
+/// const PLACES: [NonZeroU64; 13] = nonzero_lit::powers_of!(u64, RADIX, 13);
+/// assert_eq!(PLACES[12].get(), 36u64.pow(12));
+///
+/// let t = nonzero_lit::powers_of!(i8, -2, 7);
+/// assert_eq!(t[6].get(), 64);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::powers_of!(u64, 2, 65);
+/// ```
+///
+/// Including a base of zero.
+/// ```compile_fail
+/// let t = nonzero_lit::powers_of!(u32, 0, 3);
+/// ```
+///
+/// Note: arguments must be constant expressions.
+/// ```compile_fail
+/// let bar = 3;
+/// let t = nonzero_lit::powers_of!(u32, bar, 3);
+/// ```
+#[macro_export]
+macro_rules! powers_of {
+    ($int:ident, $base:expr, $n:expr $(,)?) => {{
+        const __B: $int = $base;
+        const __N: usize = $n;
+        {
+            const T: [$crate::_private::nz::$int; __N] = {
+                // Note: Hacky const assert.
+                let _ = ["powers_of! base must be nonzero"][(__B == 0) as usize];
+                let mut out = [$crate::_private::nz_ctor::$int(1); __N];
+                let mut p: $int = 1;
+                let mut i = 0;
+                while i < __N {
+                    out[i] = $crate::_private::nz_ctor::$int(p);
+                    i += 1;
+                    if i < __N {
+                        let (next, overflowed) = p.overflowing_mul(__B);
+                        // Note: Hacky const assert.
+                        let _ = ["powers_of! overflowed"][overflowed as usize];
+                        p = next;
+                    }
+                }
+                out
+            };
+            T
+        }
+    }};
+}
+
+/// Produce an array of every prime up to (and including) a bound, as
+/// [`NonZeroU32`](core::num::NonZeroU32)s in ascending order.
+///
+/// The argument is a `u32` constant expression, and the primes are found with
+/// a sieve at compile time, so the length of the array depends on the bound.
+/// A bound below 2 produces an empty array. Bounds much above 100,000 may
+/// exceed the compiler's limit on how long constant evaluation can take.
+///
+/// This is useful for things like hash tables with prime capacities, which
+/// would otherwise embed such a table by hand. As the array is sorted, the
+/// smallest prime at least as large as some value can be found with
+/// [`binary_search`](slice::binary_search).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const PRIMES: [NonZeroU32; 25] = nonzero_lit::primes_up_to!(100);
+/// assert_eq!(PRIMES[0].get(), 2);
+/// assert_eq!(PRIMES[24].get(), 97);
+/// ```
+///
+/// Works as a static slice, and the bound can be any const expression.
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const MAX_BUCKETS: u32 = 1 << 12;
+/// static CAPACITIES: &[NonZeroU32] = &nonzero_lit::primes_up_to!(MAX_BUCKETS);
+///
+/// fn capacity_for(n: u32) -> Option<NonZeroU32> {
+///     let i = CAPACITIES.binary_search_by_key(&n, |p| p.get()).unwrap_or_else(|i| i);
+///     CAPACITIES.get(i).copied()
+/// }
+/// assert_eq!(capacity_for(1000).map(NonZeroU32::get), Some(1009));
+/// assert_eq!(capacity_for(5000), None);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 100;
+/// let foo = nonzero_lit::primes_up_to!(bar);
+/// ```
+#[macro_export]
+macro_rules! primes_up_to {
+    ($bound:expr $(,)?) => {{
+        const __E: u32 = $bound;
+        {
+            const LEN: usize = __E as usize + 1;
+            // Note: `COMPOSITE[i]` is true if `i` isn't prime, including for 0
+            // and 1.
+            const COMPOSITE: [bool; LEN] = {
+                let mut c = [false; LEN];
+                c[0] = true;
+                if LEN > 1 {
+                    c[1] = true;
+                }
+                let mut i = 2;
+                while i * i < LEN {
+                    if !c[i] {
+                        let mut j = i * i;
+                        while j < LEN {
+                            c[j] = true;
+                            j += i;
+                        }
+                    }
+                    i += 1;
+                }
+                c
+            };
+            const N: usize = {
+                let mut n = 0;
+                let mut i = 0;
+                while i < LEN {
+                    if !COMPOSITE[i] {
+                        n += 1;
+                    }
+                    i += 1;
+                }
+                n
+            };
+            const OUT: [$crate::_private::NonZeroU32; N] = {
+                let mut out = [$crate::_private::nz_ctor::u32(2); N];
+                let mut i = 0;
+                let mut j = 0;
+                while i < LEN {
+                    if !COMPOSITE[i] {
+                        out[j] = $crate::_private::nz_ctor::u32(i as u32);
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                out
+            };
+            OUT
+        }
+    }};
+}
+
+/// Compute `base.pow(exp) % modulus` at compile time, producing a `NonZero`
+/// value.
+///
+/// The first argument is the (unsigned) integer type, which the base,
+/// exponent, and modulus all have. Intermediate results don't overflow, even
+/// for `u128`. The modulus must be nonzero, and so must the result.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let r = nonzero_lit::pow_mod!(u64, 3, 200, 1_000_000_007);
+/// assert_eq!(r.get(), 136_318_165);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU128;
+/// // Fermat's little theorem, for the prime 2^127 - 1.
+/// const P: u128 = (1 << 127) - 1;
+/// const G: NonZeroU128 = nonzero_lit::pow_mod!(u128, 3, P - 1, P);
+/// assert_eq!(G.get(), 1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let r = nonzero_lit::pow_mod!(u64, 3, 4, 0);
+/// ```
+///
+/// Including a result of zero.
+/// ```compile_fail
+/// let r = nonzero_lit::pow_mod!(u64, 6, 2, 4);
+/// ```
+#[macro_export]
+macro_rules! pow_mod {
+    ($int:ident, $base:expr, $exp:expr, $modulus:expr $(,)?) => {{
+        const __B: $int = $base;
+        const __X: $int = $exp;
+        const __M: $int = $modulus;
+        {
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["pow_mod! requires an unsigned type"][($int::MIN != 0) as usize];
+                $crate::_private::pow_mod(__B as u128, __X as u128, __M as u128) as $int
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
+
+/// Compute the modular multiplicative inverse of a constant, producing a
+/// `NonZero` value.
+///
+/// `mod_inverse!(int, a, m)` computes the `x` in `0..m` such that
+/// `a * x % m == 1`, and fails to compile if there's no such `x` (when `a` and
+/// `m` aren't coprime). The first argument is the (unsigned) integer type,
+/// which both `a` and `m` have.
+///
+/// Leaving out the modulus computes the inverse modulo `2^BITS` instead (which
+/// can't be written as a value of the type), where `BITS` is the size of the
+/// type. This is the form needed for Montgomery multiplication, and only odd
+/// values have one.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let inv = nonzero_lit::mod_inverse!(u32, 3, 11);
+/// assert_eq!(inv.get(), 4);
+///
+/// let inv = nonzero_lit::mod_inverse!(u64, 0x1234_5679);
+/// assert_eq!(0x1234_5679u64.wrapping_mul(inv.get()), 1);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// const N: u64 = 0xffff_ffff_0000_0001;
+/// // `-N^-1 mod 2^64`, for Montgomery reduction.
+/// const N_PRIME: u64 = nonzero_lit::mod_inverse!(u64, N).get().wrapping_neg();
+/// assert_eq!(N.wrapping_mul(N_PRIME), u64::MAX);
+///
+/// const INV: NonZeroU64 = nonzero_lit::mod_inverse!(u64, 7, N);
+/// assert_eq!((7 * INV.get() as u128 % N as u128), 1);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let inv = nonzero_lit::mod_inverse!(u32, 4, 10);
+/// ```
+///
+/// Including even values modulo `2^BITS`.
+/// ```compile_fail
+/// let inv = nonzero_lit::mod_inverse!(u32, 6);
+/// ```
+#[macro_export]
+macro_rules! mod_inverse {
+    ($int:ident, $val:expr, $modulus:expr $(,)?) => {{
+        const __E: $int = $val;
+        const __M: $int = $modulus;
+        {
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["mod_inverse! requires an unsigned type"][($int::MIN != 0) as usize];
+                $crate::_private::mod_inverse(__E as u128, __M as u128) as $int
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const V: $int = {
+                // Note: Hacky const assert.
+                let _ = ["mod_inverse! requires an unsigned type"][($int::MIN != 0) as usize];
+                $crate::_private::mod_inverse_pow2(__E as u128) as $int
+            };
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(V);
+            NZ
+        }
+    }};
+}
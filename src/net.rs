@@ -0,0 +1,100 @@
+// Parses a dotted-quad IPv4 address into its numeric value, which must be
+// nonzero.
+#[doc(hidden)]
+pub const fn parse_ipv4(s: &str) -> u32 {
+    let b = s.as_bytes();
+    let mut addr: u32 = 0;
+    let mut octets = 0;
+    let mut i = 0;
+    while octets < 4 {
+        if octets > 0 {
+            // Note: Hacky const fn asserts, here and below.
+            let _ = ["expected `.` between octets of IPv4 address"]
+                [!(i < b.len() && b[i] == b'.') as usize];
+            i += 1;
+        }
+        let start = i;
+        let mut n: u32 = 0;
+        while i < b.len() && b[i].is_ascii_digit() && i - start < 3 {
+            n = n * 10 + (b[i] - b'0') as u32;
+            i += 1;
+        }
+        let _ = ["expected a number from 0 to 255 in IPv4 address"]
+            [(i == start || n > 255 || (i < b.len() && b[i].is_ascii_digit())) as usize];
+        let _ = ["octets of IPv4 address must not have leading zeros"]
+            [(b[start] == b'0' && i - start > 1) as usize];
+        addr = (addr << 8) | n;
+        octets += 1;
+    }
+    let _ = ["unexpected trailing characters in IPv4 address"][(i != b.len()) as usize];
+    let _ = ["IPv4 address must not be 0.0.0.0"][(addr == 0) as usize];
+    addr
+}
+
+/// Parse a dotted-quad IPv4 address such as `"10.0.0.1"` at compile time,
+/// producing a [`NonZeroU32`](core::num::NonZeroU32).
+///
+/// By default the result is the address's numeric value, the same as
+/// `u32::from(Ipv4Addr)`, so `"10.0.0.1"` is `0x0a00_0001`. Prefixing the
+/// address with `be:` produces it in network byte order instead (as stored in
+/// a C `in_addr`, for example), which is the same value on big-endian targets,
+/// and byte-swapped on little-endian ones.
+///
+/// Malformed addresses, and the unspecified address `0.0.0.0`, fail the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// let a = nonzero_lit::ipv4!("10.0.0.1");
+/// assert_eq!(a.get(), 0x0a00_0001);
+/// assert_eq!(Ipv4Addr::from(a.get()), Ipv4Addr::new(10, 0, 0, 1));
+/// ```
+///
+/// Works for consts, and in network byte order.
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const GATEWAY: NonZeroU32 = nonzero_lit::ipv4!(be: "192.168.1.1");
+/// assert_eq!(GATEWAY.get().to_ne_bytes(), [192, 168, 1, 1]);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let a = nonzero_lit::ipv4!("0.0.0.0");
+/// ```
+///
+/// Including malformed addresses.
+/// ```compile_fail
+/// let a = nonzero_lit::ipv4!("10.0.0.256");
+/// ```
+///
+/// ```compile_fail
+/// let a = nonzero_lit::ipv4!("10.0.1");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "10.0.0.1";
+/// let a = nonzero_lit::ipv4!(bar);
+/// ```
+#[macro_export]
+macro_rules! ipv4 {
+    (be: $s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const NZ: $crate::_private::NonZeroU32 =
+                $crate::_private::nz_u32($crate::_private::parse_ipv4(__E).to_be());
+            NZ
+        }
+    }};
+    ($s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const NZ: $crate::_private::NonZeroU32 =
+                $crate::_private::nz_u32($crate::_private::parse_ipv4(__E));
+            NZ
+        }
+    }};
+}
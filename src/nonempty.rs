@@ -0,0 +1,653 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::num::NonZeroUsize;
+use core::ops::Deref;
+#[cfg(feature = "alloc")]
+use core::ops::DerefMut;
+
+/// A borrowed slice which always contains at least one element.
+///
+/// This is the borrowed counterpart to `NonEmptyVec`, for functions (such as
+/// parsers, or anything which splits its input into chunks) which can't do
+/// anything useful with empty input, so that they needn't handle it. Its
+/// length is a [`NonZeroUsize`], and accessors which return an `Option` on a
+/// slice (such as [`first`](NonEmptySlice::first)) can't fail on one.
+/// Everything else is available through `Deref`.
+///
+/// This wraps a `&[T]` rather than a `[T]`, as converting between references
+/// to the two would need unsafe code. It's `Copy`, so it can be passed around
+/// like a reference.
+///
+/// Use [`nonempty_slice!`](crate::nonempty_slice) to create one from a list
+/// of elements, or [`new`](NonEmptySlice::new) to check a slice.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonEmptySlice;
+///
+/// fn widest(widths: NonEmptySlice<'_, u32>) -> u32 {
+///     let (&first, rest) = widths.split_first();
+///     rest.iter().fold(first, |a, &b| a.max(b))
+/// }
+///
+/// let data = [3, 9, 4];
+/// let s = NonEmptySlice::new(&data).unwrap();
+/// assert_eq!(s.len().get(), 3);
+/// assert_eq!(widest(s), 9);
+/// assert_eq!((s.first(), s.last()), (&3, &4));
+///
+/// assert!(NonEmptySlice::new(&data[..0]).is_none());
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySlice<'a, T>(&'a [T]);
+
+impl<'a, T> NonEmptySlice<'a, T> {
+    /// Wraps a slice, returning `None` if it's empty.
+    #[inline]
+    pub const fn new(s: &'a [T]) -> Option<Self> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(Self(s))
+        }
+    }
+
+    /// Returns the number of elements, which is never zero.
+    #[inline]
+    pub const fn len(self) -> NonZeroUsize {
+        crate::_private::nz_usize(self.0.len())
+    }
+
+    /// Returns the first element.
+    #[inline]
+    pub const fn first(self) -> &'a T {
+        &self.0[0]
+    }
+
+    /// Returns the last element.
+    #[inline]
+    pub const fn last(self) -> &'a T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns the first element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_first(self) -> (&'a T, &'a [T]) {
+        let (first, rest) = self.0.split_at(1);
+        (&first[0], rest)
+    }
+
+    /// Returns the last element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_last(self) -> (&'a T, &'a [T]) {
+        let (rest, last) = self.0.split_at(self.0.len() - 1);
+        (&last[0], rest)
+    }
+
+    /// Returns the wrapped slice.
+    #[inline]
+    pub const fn as_slice(self) -> &'a [T] {
+        self.0
+    }
+}
+
+impl<T> Clone for NonEmptySlice<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NonEmptySlice<'_, T> {}
+
+impl<T> Deref for NonEmptySlice<'_, T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptySlice<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<'a, T> From<NonEmptySlice<'a, T>> for &'a [T] {
+    #[inline]
+    fn from(s: NonEmptySlice<'a, T>) -> Self {
+        s.0
+    }
+}
+
+// This returns the slice on failure, like `TryFrom<Vec<T>>` for `NonEmptyVec`.
+impl<'a, T> TryFrom<&'a [T]> for NonEmptySlice<'a, T> {
+    type Error = &'a [T];
+    #[inline]
+    fn try_from(s: &'a [T]) -> Result<Self, &'a [T]> {
+        Self::new(s).ok_or(s)
+    }
+}
+
+impl<'a, T> IntoIterator for NonEmptySlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// `nonempty_slice!` only calls this with at least one element.
+#[doc(hidden)]
+#[inline]
+pub const fn nonempty_slice<T>(s: &[T]) -> NonEmptySlice<'_, T> {
+    NonEmptySlice(s)
+}
+
+/// Create a [`NonEmptySlice`] from a list of elements, like `&[a, b, c]`.
+///
+/// The empty form, `nonempty_slice![]`, is a compile error. This is usable
+/// in `const`s, where the elements must be constant expressions.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let s = nonzero_lit::nonempty_slice![1, 2, 3];
+/// assert_eq!(s.len().get(), 3);
+/// assert_eq!(s.split_last(), (&3, &[1, 2][..]));
+/// ```
+///
+/// Works for consts.
+/// ```
+/// use nonzero_lit::NonEmptySlice;
+///
+/// const PORTS: NonEmptySlice<'static, u16> = nonzero_lit::nonempty_slice![80, 443];
+/// const DEFAULT_PORT: u16 = *PORTS.first();
+/// assert_eq!(DEFAULT_PORT, 80);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let s: nonzero_lit::NonEmptySlice<'_, u8> = nonzero_lit::nonempty_slice![];
+/// ```
+#[macro_export]
+macro_rules! nonempty_slice {
+    () => {
+        ::core::compile_error!("`nonempty_slice!` requires at least one element")
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::_private::nonempty_slice(&[$($x),+])
+    };
+}
+
+/// A borrowed string which is never empty.
+///
+/// This is for names, identifiers and keys which must never be empty. Its
+/// length (in bytes) is a [`NonZeroUsize`], and it has infallible accessors
+/// for its first and last characters. Everything else a `str` can do is
+/// available through `Deref`.
+///
+/// Like [`NonEmptySlice`], this wraps a `&str` rather than a `str`, so it's
+/// `Copy`. Use [`nonempty_str!`](crate::nonempty_str) to create one at
+/// compile time, or [`new`](NonEmptyStr::new) to check a string.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonEmptyStr;
+///
+/// const SERVICE: NonEmptyStr<'static> = nonzero_lit::nonempty_str!("ingest");
+/// assert_eq!(SERVICE.len().get(), 6);
+/// assert_eq!((SERVICE.first_char(), SERVICE.last_char()), ('i', 't'));
+/// assert!(SERVICE.starts_with("in"));
+///
+/// let name = String::from("é");
+/// assert_eq!(NonEmptyStr::new(&name).map(|s| s.len().get()), Some(2));
+/// assert!(NonEmptyStr::new("").is_none());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyStr<'a>(&'a str);
+
+impl<'a> NonEmptyStr<'a> {
+    /// Wraps a string, returning `None` if it's empty.
+    #[inline]
+    pub const fn new(s: &'a str) -> Option<Self> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(Self(s))
+        }
+    }
+
+    /// Returns the length of the string in bytes, which is never zero.
+    #[inline]
+    pub const fn len(self) -> NonZeroUsize {
+        crate::_private::nz_usize(self.0.len())
+    }
+
+    /// Returns the wrapped string.
+    #[inline]
+    pub const fn as_str(self) -> &'a str {
+        self.0
+    }
+
+    /// Returns the bytes of the string, as a [`NonEmptySlice`].
+    #[inline]
+    pub const fn as_nonempty_bytes(self) -> NonEmptySlice<'a, u8> {
+        NonEmptySlice(self.0.as_bytes())
+    }
+
+    /// Returns the first character.
+    #[inline]
+    pub fn first_char(self) -> char {
+        // Note: The default is never used, as the string isn't empty.
+        self.0.chars().next().unwrap_or_default()
+    }
+
+    /// Returns the last character.
+    #[inline]
+    pub fn last_char(self) -> char {
+        self.0.chars().next_back().unwrap_or_default()
+    }
+}
+
+impl core::fmt::Display for NonEmptyStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Deref for NonEmptyStr<'_> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyStr<'_> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyStr<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> From<NonEmptyStr<'a>> for &'a str {
+    #[inline]
+    fn from(s: NonEmptyStr<'a>) -> Self {
+        s.0
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonEmptyStr<'a> {
+    type Error = &'a str;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, &'a str> {
+        Self::new(s).ok_or(s)
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn nonempty_str(s: &str) -> NonEmptyStr<'_> {
+    // Note: Hacky const fn assert.
+    let _ = ["string must not be empty"][s.is_empty() as usize];
+    NonEmptyStr(s)
+}
+
+/// Create a [`NonEmptyStr`], checking at compile time that the string isn't
+/// empty.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let s = nonzero_lit::nonempty_str!("key");
+/// assert_eq!(s.as_str(), "key");
+/// assert_eq!(s.len().get(), 3);
+/// ```
+///
+/// Works for consts, and the parameter can be any constant `&str` expression.
+/// ```
+/// use nonzero_lit::NonEmptyStr;
+/// const PREFIX: &str = "app";
+/// const KEY: NonEmptyStr<'static> = nonzero_lit::nonempty_str!(PREFIX);
+/// assert_eq!(KEY.as_nonempty_bytes().first(), &b'a');
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let s = nonzero_lit::nonempty_str!("");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "bar";
+/// let foo = nonzero_lit::nonempty_str!(bar);
+/// ```
+#[macro_export]
+macro_rules! nonempty_str {
+    ($s:expr $(,)?) => {{
+        const __S: &str = $s;
+        {
+            const S: $crate::NonEmptyStr<'static> = $crate::_private::nonempty_str(__S);
+            S
+        }
+    }};
+}
+
+/// A `Vec` which always contains at least one element.
+///
+/// Its length is a [`NonZeroUsize`], and accessors which return an `Option`
+/// on a `Vec` (such as [`first`](NonEmptyVec::first)) can't fail on one.
+/// Everything else a slice can do is available through `Deref`, as the
+/// length can't be changed that way.
+///
+/// Use [`nonempty_vec!`](crate::nonempty_vec) to create one from a list of
+/// elements, or [`from_vec`](NonEmptyVec::from_vec) to check a `Vec`.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonEmptyVec;
+///
+/// let mut v = nonzero_lit::nonempty_vec![3, 1, 4];
+/// assert_eq!(v.len().get(), 3);
+/// assert_eq!((v.first(), v.last()), (&3, &4));
+///
+/// assert_eq!(v.pop(), Some(4));
+/// assert_eq!(v.pop(), Some(1));
+/// assert_eq!(v.pop(), None);
+/// assert_eq!(v.as_slice(), [3]);
+///
+/// assert!(NonEmptyVec::from_vec(Vec::<u8>::new()).is_none());
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+#[cfg(feature = "alloc")]
+impl<T> NonEmptyVec<T> {
+    /// Creates a vector containing just `first`.
+    #[inline]
+    pub fn new(first: T) -> Self {
+        Self(alloc::vec![first])
+    }
+
+    /// Creates a vector containing `n` clones of `elem`.
+    #[inline]
+    pub fn from_elem(elem: T, n: NonZeroUsize) -> Self
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity(n.get());
+        v.resize(n.get(), elem);
+        Self(v)
+    }
+
+    /// Wraps a `Vec`, returning `None` if it's empty.
+    #[inline]
+    pub fn from_vec(v: Vec<T>) -> Option<Self> {
+        if v.is_empty() {
+            None
+        } else {
+            Some(Self(v))
+        }
+    }
+
+    /// Returns the wrapped `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Returns the number of elements, which is never zero.
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        crate::_private::nz_usize(self.0.len())
+    }
+
+    /// Returns the first element.
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns a mutable reference to the first element.
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0[0]
+    }
+
+    /// Returns the last element.
+    #[inline]
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns a mutable reference to the last element.
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut T {
+        let i = self.0.len() - 1;
+        &mut self.0[i]
+    }
+
+    /// Returns the first element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        let (first, rest) = self.0.split_at(1);
+        (&first[0], rest)
+    }
+
+    /// Returns the last element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        let (rest, last) = self.0.split_at(self.0.len() - 1);
+        (&last[0], rest)
+    }
+
+    /// Appends an element to the end of the vector.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.0.push(value)
+    }
+
+    /// Removes the last element and returns it, or returns `None` if it's the
+    /// only element (which is left in place).
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.len() == 1 {
+            None
+        } else {
+            self.0.pop()
+        }
+    }
+
+    /// Returns the elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the elements as a [`NonEmptySlice`].
+    #[inline]
+    pub fn as_nonempty_slice(&self) -> NonEmptySlice<'_, T> {
+        NonEmptySlice(&self.0)
+    }
+
+    /// Returns the elements as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DerefMut for NonEmptyVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsMut<[T]> for NonEmptyVec<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<NonEmptySlice<'_, T>> for NonEmptyVec<T> {
+    #[inline]
+    fn from(s: NonEmptySlice<'_, T>) -> Self {
+        Self(s.0.to_vec())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    #[inline]
+    fn from(v: NonEmptyVec<T>) -> Self {
+        v.0
+    }
+}
+
+// Like `[T; N]`, this returns the `Vec` on failure.
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = Vec<T>;
+    #[inline]
+    fn try_from(v: Vec<T>) -> Result<Self, Vec<T>> {
+        if v.is_empty() {
+            Err(v)
+        } else {
+            Ok(Self(v))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Extend<T> for NonEmptyVec<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+// `nonempty_vec!` only calls this with at least one element.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[inline]
+pub fn nonempty_vec<T>(v: Vec<T>) -> NonEmptyVec<T> {
+    debug_assert!(!v.is_empty());
+    NonEmptyVec(v)
+}
+
+/// Create a [`NonEmptyVec`], with the same syntax as `vec!`.
+///
+/// The empty form, `nonempty_vec![]`, is a compile error. In the
+/// `nonempty_vec![elem; n]` form, `n` must be a nonzero constant expression,
+/// which is checked at compile time.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let v = nonzero_lit::nonempty_vec!["a", "b", "c"];
+/// assert_eq!(v.len().get(), 3);
+/// assert_eq!(v.split_first(), (&"a", &["b", "c"][..]));
+/// ```
+///
+/// With a repeated element.
+/// ```
+/// let v = nonzero_lit::nonempty_vec![0u8; 4];
+/// assert_eq!(v.as_slice(), [0, 0, 0, 0]);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let v: nonzero_lit::NonEmptyVec<u8> = nonzero_lit::nonempty_vec![];
+/// ```
+///
+/// ```compile_fail
+/// let v = nonzero_lit::nonempty_vec![0u8; 0];
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! nonempty_vec {
+    () => {
+        ::core::compile_error!("`nonempty_vec!` requires at least one element")
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::NonEmptyVec::from_elem($elem, $crate::usize!($n))
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::_private::nonempty_vec($crate::_private::vec![$($x),+])
+    };
+}
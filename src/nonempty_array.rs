@@ -0,0 +1,214 @@
+use core::num::NonZeroUsize;
+use core::ops::{Deref, DerefMut};
+
+use crate::NonEmptySlice;
+
+/// A fixed-size array which is known from its type to be non-empty.
+///
+/// Creating one with `N == 0` is a compile error, so its length is a
+/// [`NonZeroUsize`], and [`first`](NonEmptyArray::first) and
+/// [`last`](NonEmptyArray::last) can't fail. Everything else an array can do
+/// is available through `Deref`.
+///
+/// Use [`nonempty_array!`](crate::nonempty_array) to create one from a list of
+/// elements, which can also check `NonZero` elements at compile time.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonEmptyArray;
+///
+/// fn average(samples: &NonEmptyArray<u32, 4>) -> u32 {
+///     // Can't divide by zero.
+///     samples.iter().sum::<u32>() / NonEmptyArray::<u32, 4>::LEN.get() as u32
+/// }
+///
+/// let samples = NonEmptyArray::new([3, 5, 7, 9]);
+/// assert_eq!(average(&samples), 6);
+/// assert_eq!((samples.first(), samples.last()), (&3, &9));
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let a = nonzero_lit::NonEmptyArray::<u8, 0>::new([]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyArray<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> NonEmptyArray<T, N> {
+    /// The length of the array, `N`, as a `NonZeroUsize`.
+    ///
+    /// Using this with `N == 0` is a compile error (which all the ways to
+    /// create a `NonEmptyArray` do).
+    pub const LEN: NonZeroUsize = crate::_private::len_of(N);
+
+    /// Wraps an array, checking at compile time that `N` isn't zero.
+    #[inline]
+    pub const fn new(a: [T; N]) -> Self {
+        let _ = Self::LEN;
+        Self(a)
+    }
+
+    /// Returns [`LEN`](Self::LEN).
+    #[inline]
+    pub const fn len(&self) -> NonZeroUsize {
+        Self::LEN
+    }
+
+    /// Returns the first element.
+    #[inline]
+    pub const fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns a mutable reference to the first element.
+    #[inline]
+    pub fn first_mut(&mut self) -> &mut T {
+        &mut self.0[0]
+    }
+
+    /// Returns the last element.
+    #[inline]
+    pub const fn last(&self) -> &T {
+        &self.0[N - 1]
+    }
+
+    /// Returns a mutable reference to the last element.
+    #[inline]
+    pub fn last_mut(&mut self) -> &mut T {
+        &mut self.0[N - 1]
+    }
+
+    /// Returns the first element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        let (first, rest) = self.0.split_at(1);
+        (&first[0], rest)
+    }
+
+    /// Returns the last element, and a slice of the rest (which may be
+    /// empty).
+    #[inline]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        let (rest, last) = self.0.split_at(N - 1);
+        (&last[0], rest)
+    }
+
+    /// Returns the wrapped array.
+    #[inline]
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Returns the elements as a [`NonEmptySlice`].
+    #[inline]
+    pub fn as_nonempty_slice(&self) -> NonEmptySlice<'_, T> {
+        crate::_private::nonempty_slice(&self.0)
+    }
+
+    /// Returns the wrapped array.
+    #[inline]
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Deref for NonEmptyArray<T, N> {
+    type Target = [T; N];
+    #[inline]
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for NonEmptyArray<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for NonEmptyArray<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for NonEmptyArray<T, N> {
+    #[inline]
+    fn from(a: [T; N]) -> Self {
+        Self::new(a)
+    }
+}
+
+impl<T, const N: usize> From<NonEmptyArray<T, N>> for [T; N] {
+    #[inline]
+    fn from(a: NonEmptyArray<T, N>) -> Self {
+        a.0
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a NonEmptyArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Create a [`NonEmptyArray`] from a list of elements.
+///
+/// The empty form, `nonempty_array![]`, is a compile error. Prefixing the
+/// elements with an integer type, as in `nonempty_array![u32: 1, 2, 3]`,
+/// creates an array of the matching `NonZero` type instead, checking each
+/// element at compile time as the literal macros (such as
+/// [`nonzero_lit::u32!`](crate::u32)) do.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let a = nonzero_lit::nonempty_array!["x", "y"];
+/// assert_eq!(a.len().get(), 2);
+/// assert_eq!(a.split_first(), (&"x", &["y"][..]));
+/// ```
+///
+/// With `NonZero` elements, which works for consts.
+/// ```
+/// use core::num::NonZeroU32;
+/// use nonzero_lit::NonEmptyArray;
+///
+/// const DIVISORS: NonEmptyArray<NonZeroU32, 3> = nonzero_lit::nonempty_array![u32: 2, 3, 5];
+/// assert_eq!(DIVISORS.iter().map(|d| 30 / d.get()).sum::<u32>(), 31);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let a: nonzero_lit::NonEmptyArray<u8, 0> = nonzero_lit::nonempty_array![];
+/// ```
+///
+/// Including zero `NonZero` elements.
+/// ```compile_fail
+/// let a = nonzero_lit::nonempty_array![u32: 2, 0, 5];
+/// ```
+#[macro_export]
+macro_rules! nonempty_array {
+    () => {
+        ::core::compile_error!("`nonempty_array!` requires at least one element")
+    };
+    ($int:ident: $(,)?) => {
+        ::core::compile_error!("`nonempty_array!` requires at least one element")
+    };
+    ($int:ident: $($x:expr),+ $(,)?) => {
+        $crate::NonEmptyArray::new([$($crate::$int!($x)),+])
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::NonEmptyArray::new([$($x),+])
+    };
+}
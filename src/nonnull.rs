@@ -0,0 +1,74 @@
+//! The `nonnull!` macro, enabled by the `nonnull` feature.
+//!
+//! This is the only module in the crate which contains unsafe code.
+#![allow(unsafe_code)]
+
+use core::ptr::NonNull;
+
+#[doc(hidden)]
+#[inline]
+pub const fn nonnull<T>(addr: usize) -> NonNull<T> {
+    // Note: Hacky const fn asserts.
+    let _ = ["address must not be zero"][(addr == 0) as usize];
+    let _ =
+        ["address is not aligned for the type"][(addr % core::mem::align_of::<T>() != 0) as usize];
+    // SAFETY: We've just checked that `addr` is nonzero.
+    unsafe { NonNull::new_unchecked(addr as *mut T) }
+}
+
+/// Create a constant [`NonNull`](core::ptr::NonNull) pointer to a fixed
+/// address, checking at compile time that it's nonzero and aligned for the
+/// pointee type.
+///
+/// This is mainly intended for memory-mapped registers, whose addresses are
+/// known ahead of time. Note that it only produces the pointer: whether it's
+/// valid to dereference is still up to you.
+///
+/// Requires the `nonnull` feature.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::ptr::NonNull;
+///
+/// #[repr(C)]
+/// struct RegisterBlock {
+///     ctrl: u32,
+///     status: u32,
+/// }
+///
+/// const GPIOA: NonNull<RegisterBlock> = nonzero_lit::nonnull!(RegisterBlock, 0x4002_0000);
+/// assert_eq!(GPIOA.as_ptr() as usize, 0x4002_0000);
+/// ```
+///
+/// Works in non-const contexts, and the address can be any const expression.
+/// ```
+/// const PERIPH_BASE: usize = 0x4000_0000;
+/// let uart = nonzero_lit::nonnull!(u32, PERIPH_BASE + 0x1_1000);
+/// assert_eq!(uart.as_ptr() as usize, 0x4001_1000);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let p = nonzero_lit::nonnull!(u32, 0);
+/// ```
+///
+/// ```compile_fail
+/// let p = nonzero_lit::nonnull!(u32, 0x4002_0002);
+/// ```
+///
+/// Note: the address must be a constant expression.
+/// ```compile_fail
+/// let addr = 0x4002_0000;
+/// let p = nonzero_lit::nonnull!(u32, addr);
+/// ```
+#[macro_export]
+macro_rules! nonnull {
+    ($T:ty, $addr:expr $(,)?) => {{
+        const __E: usize = $addr;
+        {
+            const P: $crate::_private::NonNull<$T> = $crate::_private::nonnull::<$T>(__E);
+            P
+        }
+    }};
+}
@@ -0,0 +1,129 @@
+use core::num::NonZeroU8;
+
+/// A `&'static str` which is known to contain no NUL bytes.
+///
+/// Produced by [`nul_free_str!`](crate::nul_free_str), which performs the
+/// check at compile time. The string is available both as a normal `&str`, and
+/// as a slice of [`NonZeroU8`], for handing to C-style APIs without scanning it
+/// at runtime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NulFreeStr {
+    s: &'static str,
+    bytes: &'static [NonZeroU8],
+}
+
+impl NulFreeStr {
+    /// Returns the string as a `&'static str`.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        self.s
+    }
+
+    /// Returns the bytes of the string, each of which is known to be nonzero.
+    #[inline]
+    pub const fn as_nonzero_bytes(self) -> &'static [NonZeroU8] {
+        self.bytes
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.s.len()
+    }
+
+    /// Returns true if the string is empty.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.s.is_empty()
+    }
+}
+
+impl core::fmt::Display for NulFreeStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.s)
+    }
+}
+
+impl AsRef<str> for NulFreeStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.s
+    }
+}
+
+impl AsRef<[NonZeroU8]> for NulFreeStr {
+    #[inline]
+    fn as_ref(&self) -> &[NonZeroU8] {
+        self.bytes
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn nul_free_byte(b: u8) -> NonZeroU8 {
+    // Note: Hacky const fn assert.
+    let _ = ["string must not contain NUL bytes"][(b == 0) as usize];
+    crate::_private::nz_u8(b)
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn nul_free_str(s: &'static str, bytes: &'static [NonZeroU8]) -> NulFreeStr {
+    NulFreeStr { s, bytes }
+}
+
+/// Create a [`NulFreeStr`], checking at compile time that the string contains
+/// no NUL bytes.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let s = nonzero_lit::nul_free_str!("hello");
+/// assert_eq!(s.as_str(), "hello");
+/// assert_eq!(s.as_nonzero_bytes()[0].get(), b'h');
+/// ```
+///
+/// Works for consts, and the parameter can be any constant `&str` expression.
+/// ```
+/// use nonzero_lit::NulFreeStr;
+/// const NAME: &str = "ferris";
+/// const S: NulFreeStr = nonzero_lit::nul_free_str!(NAME);
+/// assert_eq!(S.len(), 6);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let s = nonzero_lit::nul_free_str!("oh\0no");
+/// ```
+///
+/// Including a trailing NUL.
+/// ```compile_fail
+/// let s = nonzero_lit::nul_free_str!("oh no\0");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "bar";
+/// let foo = nonzero_lit::nul_free_str!(bar);
+/// ```
+#[macro_export]
+macro_rules! nul_free_str {
+    ($s:expr $(,)?) => {{
+        const __S: &str = $s;
+        {
+            const LEN: usize = __S.len();
+            const BYTES: [$crate::_private::NonZeroU8; LEN] = {
+                let src = __S.as_bytes();
+                let mut out = [$crate::_private::nz_u8(1); LEN];
+                let mut i = 0;
+                while i < LEN {
+                    out[i] = $crate::_private::nul_free_byte(src[i]);
+                    i += 1;
+                }
+                out
+            };
+            const NFS: $crate::NulFreeStr = $crate::_private::nul_free_str(__S, &BYTES);
+            NFS
+        }
+    }};
+}
@@ -0,0 +1,237 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+macro_rules! define_nz_types {
+    ($($(#[$meta:meta])* $Nz:ident<const N: $int:ident> => $NonZeroInt:ident, $nz_func:ident;)+) => {$(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Nz<const N: $int>(());
+
+        impl<const N: $int> $Nz<N> {
+            /// The value of `N`, as a `NonZero` type.
+            ///
+            /// Nothing checks `N` until this is used (which [`new`](Self::new)
+            /// and [`get`](Self::get) both do), but using it with `N == 0` is a
+            /// compile error.
+            pub const VALUE: $NonZeroInt = crate::_private::$nz_func(N);
+
+            /// Creates the marker, checking that `N` isn't zero.
+            #[inline]
+            pub const fn new() -> Self {
+                let _ = Self::VALUE;
+                Self(())
+            }
+
+            /// Returns [`VALUE`](Self::VALUE).
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                Self::VALUE
+            }
+        }
+
+        impl<const N: $int> Default for $Nz<N> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<const N: $int> From<$Nz<N>> for $NonZeroInt {
+            #[inline]
+            fn from(_: $Nz<N>) -> Self {
+                $Nz::<N>::VALUE
+            }
+        }
+    )+};
+}
+
+define_nz_types! {
+    /// A zero-sized marker for a nonzero `usize`, known from the type.
+    ///
+    /// This lets generic code (such as a fixed-capacity queue) require a
+    /// nonzero parameter in its type, instead of asserting it at runtime.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::marker::PhantomData;
+    /// use nonzero_lit::NzUsize;
+    ///
+    /// struct Ring<const CAP: usize> {
+    ///     cap: PhantomData<NzUsize<CAP>>,
+    /// }
+    ///
+    /// impl<const CAP: usize> Ring<CAP> {
+    ///     fn slot(&self, i: usize) -> usize {
+    ///         // Can't divide by zero.
+    ///         i % NzUsize::<CAP>::VALUE.get()
+    ///     }
+    /// }
+    ///
+    /// let ring = Ring::<8> { cap: PhantomData };
+    /// assert_eq!(ring.slot(10), 2);
+    /// ```
+    ///
+    /// Misuse is detected at compile time.
+    /// ```compile_fail
+    /// let n = nonzero_lit::NzUsize::<0>::VALUE;
+    /// ```
+    NzUsize<const N: usize> => NonZeroUsize, nz_usize;
+    /// A zero-sized marker for a nonzero `isize`, known from the type.
+    NzIsize<const N: isize> => NonZeroIsize, nz_isize;
+    /// A zero-sized marker for a nonzero `u8`, known from the type.
+    NzU8<const N: u8> => NonZeroU8, nz_u8;
+    /// A zero-sized marker for a nonzero `i8`, known from the type.
+    NzI8<const N: i8> => NonZeroI8, nz_i8;
+    /// A zero-sized marker for a nonzero `u16`, known from the type.
+    NzU16<const N: u16> => NonZeroU16, nz_u16;
+    /// A zero-sized marker for a nonzero `i16`, known from the type.
+    NzI16<const N: i16> => NonZeroI16, nz_i16;
+    /// A zero-sized marker for a nonzero `u32`, known from the type.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU32;
+    /// use nonzero_lit::NzU32;
+    ///
+    /// fn rounds<const R: u32>(_: NzU32<R>) -> NonZeroU32 {
+    ///     NzU32::<R>::VALUE
+    /// }
+    /// assert_eq!(rounds(NzU32::<10>::new()).get(), 10);
+    /// assert_eq!(NonZeroU32::from(NzU32::<3>::new()).get(), 3);
+    /// ```
+    ///
+    /// Misuse is detected at compile time.
+    /// ```compile_fail
+    /// let n = nonzero_lit::NzU32::<0>::new();
+    /// ```
+    NzU32<const N: u32> => NonZeroU32, nz_u32;
+    /// A zero-sized marker for a nonzero `i32`, known from the type.
+    NzI32<const N: i32> => NonZeroI32, nz_i32;
+    /// A zero-sized marker for a nonzero `u64`, known from the type.
+    NzU64<const N: u64> => NonZeroU64, nz_u64;
+    /// A zero-sized marker for a nonzero `i64`, known from the type.
+    NzI64<const N: i64> => NonZeroI64, nz_i64;
+    /// A zero-sized marker for a nonzero `u128`, known from the type.
+    NzU128<const N: u128> => NonZeroU128, nz_u128;
+    /// A zero-sized marker for a nonzero `i128`, known from the type.
+    NzI128<const N: i128> => NonZeroI128, nz_i128;
+}
+
+/// A transparent wrapper around a `NonZero` value, whose [`Default`] is `N`.
+///
+/// This allows structs with `NonZero` fields to use `#[derive(Default)]`. `N`
+/// is checked to be nonzero (and within range for `T`) at compile time, when
+/// `default()` is used. It's an `i128` regardless of `T`, so `u128` defaults
+/// above `i128::MAX` aren't supported.
+///
+/// The wrapper derefs to the `NonZero` value.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::{NonZeroU8, NonZeroUsize};
+/// use nonzero_lit::DefaultNonZero;
+///
+/// #[derive(Default)]
+/// struct Config {
+///     workers: DefaultNonZero<NonZeroUsize, 4>,
+///     retries: DefaultNonZero<NonZeroU8, 3>,
+/// }
+///
+/// let mut config = Config::default();
+/// assert_eq!(config.workers.get(), 4);
+/// assert_eq!(config.retries.get(), 3);
+///
+/// config.workers = DefaultNonZero::new(nonzero_lit::usize!(16));
+/// assert_eq!(config.workers.into_inner().get(), 16);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// use nonzero_lit::DefaultNonZero;
+/// let n = DefaultNonZero::<core::num::NonZeroU32, 0>::default();
+/// ```
+///
+/// Including a default which is out of range for the type.
+/// ```compile_fail
+/// use nonzero_lit::DefaultNonZero;
+/// let n = DefaultNonZero::<core::num::NonZeroU8, 256>::default();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DefaultNonZero<T, const N: i128>(pub T);
+
+impl<T, const N: i128> DefaultNonZero<T, N> {
+    /// Wraps a value.
+    #[inline]
+    pub const fn new(v: T) -> Self {
+        Self(v)
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const N: i128> core::ops::Deref for DefaultNonZero<T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const N: i128> core::ops::DerefMut for DefaultNonZero<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T, const N: i128> From<T> for DefaultNonZero<T, N> {
+    #[inline]
+    fn from(v: T) -> Self {
+        Self(v)
+    }
+}
+
+macro_rules! impl_default_nonzero {
+    ($($NonZeroInt:ident => $int:ident, $nz_func:ident;)+) => {$(
+        impl<const N: i128> DefaultNonZero<$NonZeroInt, N> {
+            /// The default value, `N`.
+            pub const DEFAULT: $NonZeroInt = crate::_private::$nz_func({
+                let out_of_range = N as $int as i128 != N || ($int::MIN == 0 && N < 0);
+                // Note: Hacky const fn assert.
+                let _ = ["N is out of range for the type"][out_of_range as usize];
+                N as $int
+            });
+        }
+
+        impl<const N: i128> Default for DefaultNonZero<$NonZeroInt, N> {
+            #[inline]
+            fn default() -> Self {
+                Self(Self::DEFAULT)
+            }
+        }
+    )+};
+}
+
+impl_default_nonzero! {
+    NonZeroUsize => usize, nz_usize;
+    NonZeroIsize => isize, nz_isize;
+    NonZeroU8 => u8, nz_u8;
+    NonZeroI8 => i8, nz_i8;
+    NonZeroU16 => u16, nz_u16;
+    NonZeroI16 => i16, nz_i16;
+    NonZeroU32 => u32, nz_u32;
+    NonZeroI32 => i32, nz_i32;
+    NonZeroU64 => u64, nz_u64;
+    NonZeroI64 => i64, nz_i64;
+    NonZeroU128 => u128, nz_u128;
+    NonZeroI128 => i128, nz_i128;
+}
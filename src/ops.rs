@@ -0,0 +1,230 @@
+//! Const functions for arithmetic on `NonZero` values.
+//!
+//! Every operation here either can't produce zero for nonzero inputs, or
+//! returns an `Option`. They're all `const fn`, so they can be used to build
+//! up constants (including as arguments to this crate's macros), and are just
+//! as usable at runtime.
+//!
+//! There's a module for each `NonZero` type, named after the underlying
+//! integer type (the same way the literal macros are).
+//!
+//! # Examples
+//! ```
+//! use core::num::NonZeroU32;
+//! use nonzero_lit::ops;
+//!
+//! const BLOCK: NonZeroU32 = nonzero_lit::u32!(512);
+//! const COUNT: NonZeroU32 = nonzero_lit::u32!(24);
+//! const TOTAL: Option<NonZeroU32> = ops::u32::mul_checked(BLOCK, COUNT);
+//! assert_eq!(TOTAL.map(NonZeroU32::get), Some(512 * 24));
+//!
+//! const SIDE: NonZeroU32 = ops::u32::isqrt(BLOCK);
+//! assert_eq!(SIDE.get(), 22);
+//! ```
+//!
+//! Overflow results in `None`, rather than a panic.
+//! ```
+//! use nonzero_lit::ops;
+//! let x = nonzero_lit::i8!(-3);
+//! assert_eq!(ops::i8::pow_checked(x, 3).map(|n| n.get()), Some(-27));
+//! assert_eq!(ops::i8::pow_checked(x, 5), None);
+//! ```
+
+macro_rules! define_ops {
+    (@common $int:ident, $NonZeroInt:ident) => {
+        /// Multiplies two values, returning `None` on overflow.
+        #[inline]
+        pub const fn mul_checked(a: $NonZeroInt, b: $NonZeroInt) -> Option<$NonZeroInt> {
+            match a.get().checked_mul(b.get()) {
+                Some(n) => $NonZeroInt::new(n),
+                None => None,
+            }
+        }
+
+        /// Raises `base` to the power of `exp`, returning `None` on overflow.
+        #[inline]
+        pub const fn pow_checked(base: $NonZeroInt, exp: u32) -> Option<$NonZeroInt> {
+            let mut base = base.get();
+            let mut exp = exp;
+            let mut acc: $int = 1;
+            while exp > 0 {
+                if (exp & 1) == 1 {
+                    acc = match acc.checked_mul(base) {
+                        Some(n) => n,
+                        None => return None,
+                    };
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = match base.checked_mul(base) {
+                        Some(n) => n,
+                        None => return None,
+                    };
+                }
+            }
+            $NonZeroInt::new(acc)
+        }
+
+        /// Returns the larger of two values.
+        #[inline]
+        pub const fn max(a: $NonZeroInt, b: $NonZeroInt) -> $NonZeroInt {
+            if a.get() >= b.get() {
+                a
+            } else {
+                b
+            }
+        }
+
+        /// Returns the smaller of two values.
+        #[inline]
+        pub const fn min(a: $NonZeroInt, b: $NonZeroInt) -> $NonZeroInt {
+            if a.get() <= b.get() {
+                a
+            } else {
+                b
+            }
+        }
+    };
+    (unsigned: $($(#[$meta:meta])* $int:ident => $NonZeroInt:ident, $nz_func:ident;)+) => {$(
+        $(#[$meta])*
+        pub mod $int {
+            use core::num::$NonZeroInt;
+
+            define_ops!(@common $int, $NonZeroInt);
+
+            /// Adds an unsigned value, returning `None` on overflow.
+            #[inline]
+            pub const fn add_unsigned(a: $NonZeroInt, b: $int) -> Option<$NonZeroInt> {
+                match a.get().checked_add(b) {
+                    Some(n) => $NonZeroInt::new(n),
+                    None => None,
+                }
+            }
+
+            /// Converts a value to the `NonZero` type, clamping zero up to 1.
+            ///
+            /// See also [`nonzero_lit::at_least_one!`](crate::at_least_one).
+            #[inline]
+            pub const fn at_least_one(n: $int) -> $NonZeroInt {
+                crate::_private::$nz_func(if n == 0 { 1 } else { n })
+            }
+
+            impl crate::_private::AtLeastOne for $int {
+                type NonZero = $NonZeroInt;
+
+                #[inline]
+                fn at_least_one(self) -> $NonZeroInt {
+                    at_least_one(self)
+                }
+            }
+
+            /// Returns the integer square root, rounded down.
+            ///
+            /// The result is never zero, as the input is at least 1.
+            #[inline]
+            pub const fn isqrt(n: $NonZeroInt) -> $NonZeroInt {
+                let mut rem = n.get();
+                let mut res: $int = 0;
+                // The largest power of four representable by the type.
+                let mut bit: $int = 1 << (core::mem::size_of::<$int>() * 8 - 2);
+                while bit > rem {
+                    bit >>= 2;
+                }
+                while bit != 0 {
+                    if rem >= res + bit {
+                        rem -= res + bit;
+                        res = (res >> 1) + bit;
+                    } else {
+                        res >>= 1;
+                    }
+                    bit >>= 2;
+                }
+                crate::_private::$nz_func(res)
+            }
+        }
+    )+};
+    (signed: $($(#[$meta:meta])* $int:ident => $NonZeroInt:ident;)+) => {$(
+        $(#[$meta])*
+        pub mod $int {
+            use core::num::$NonZeroInt;
+
+            define_ops!(@common $int, $NonZeroInt);
+        }
+    )+};
+}
+
+define_ops! {
+    unsigned:
+    /// Operations on [`NonZeroUsize`](core::num::NonZeroUsize).
+    usize => NonZeroUsize, nz_usize;
+    /// Operations on [`NonZeroU8`](core::num::NonZeroU8).
+    u8 => NonZeroU8, nz_u8;
+    /// Operations on [`NonZeroU16`](core::num::NonZeroU16).
+    u16 => NonZeroU16, nz_u16;
+    /// Operations on [`NonZeroU32`](core::num::NonZeroU32).
+    u32 => NonZeroU32, nz_u32;
+    /// Operations on [`NonZeroU64`](core::num::NonZeroU64).
+    u64 => NonZeroU64, nz_u64;
+    /// Operations on [`NonZeroU128`](core::num::NonZeroU128).
+    u128 => NonZeroU128, nz_u128;
+}
+
+define_ops! {
+    signed:
+    /// Operations on [`NonZeroIsize`](core::num::NonZeroIsize).
+    isize => NonZeroIsize;
+    /// Operations on [`NonZeroI8`](core::num::NonZeroI8).
+    i8 => NonZeroI8;
+    /// Operations on [`NonZeroI16`](core::num::NonZeroI16).
+    i16 => NonZeroI16;
+    /// Operations on [`NonZeroI32`](core::num::NonZeroI32).
+    i32 => NonZeroI32;
+    /// Operations on [`NonZeroI64`](core::num::NonZeroI64).
+    i64 => NonZeroI64;
+    /// Operations on [`NonZeroI128`](core::num::NonZeroI128).
+    i128 => NonZeroI128;
+}
+
+/// Convert a value of an unsigned integer type to the matching `NonZero` type,
+/// clamping zero up to 1.
+///
+/// Unlike the other macros, the argument may be a runtime value. For use in
+/// `const fn`, pass the integer type first (or call the `at_least_one`
+/// function in the matching [`ops`](crate::ops) module directly).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// use core::num::NonZeroUsize;
+///
+/// fn chunk_count(len: usize, chunk: usize) -> NonZeroUsize {
+///     nonzero_lit::at_least_one!(len / chunk)
+/// }
+/// assert_eq!(chunk_count(1000, 256).get(), 3);
+/// assert_eq!(chunk_count(100, 256).get(), 1);
+/// ```
+///
+/// With the integer type given, it works in `const fn`.
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const fn workers(cores: u32) -> NonZeroU32 {
+///     nonzero_lit::at_least_one!(u32, cores - cores / 4)
+/// }
+/// assert_eq!(workers(8).get(), 6);
+/// assert_eq!(workers(0).get(), 1);
+/// ```
+///
+/// Only unsigned types are supported.
+/// ```compile_fail
+/// let n = nonzero_lit::at_least_one!(-5i32);
+/// ```
+#[macro_export]
+macro_rules! at_least_one {
+    ($int:ident, $val:expr $(,)?) => {
+        $crate::ops::$int::at_least_one($val)
+    };
+    ($val:expr $(,)?) => {
+        $crate::_private::AtLeastOne::at_least_one($val)
+    };
+}
@@ -0,0 +1,268 @@
+use core::convert::TryFrom;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::str::FromStr;
+
+use crate::{
+    Capacity, EvenNonZero, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI48, NonZeroIndex,
+    NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU48, Percent, Port, PowerOfTwo,
+    RingCapacity,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The reason [`parse_runtime`] failed, or parsing one of this crate's types
+/// (such as a [`Port`](crate::Port)) with `str::parse`.
+///
+/// Unlike [`core::num::ParseIntError`] (before Rust 1.55, anyway), this can be
+/// matched on, and it distinguishes a zero from other invalid input.
+///
+/// # Examples
+/// ```
+/// use core::convert::TryFrom;
+/// use nonzero_lit::{NonZeroRatio, ParseNonZeroError, Percent, Port};
+///
+/// assert_eq!("8080".parse::<Port>(), Ok(nonzero_lit::port!(8080)));
+/// assert_eq!("0".parse::<Port>(), Err(ParseNonZeroError::Zero));
+/// assert_eq!("70000".parse::<Port>(), Err(ParseNonZeroError::Overflow));
+/// assert_eq!(Percent::try_from("101"), Err(ParseNonZeroError::OutOfRange));
+///
+/// assert_eq!("6/8".parse::<NonZeroRatio>(), Ok(nonzero_lit::ratio!(3 / 4)));
+/// assert_eq!("6".parse::<NonZeroRatio>(), Err(ParseNonZeroError::InvalidDigit));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseNonZeroError {
+    /// The string was empty (or only a sign).
+    Empty,
+    /// The string contained something other than an optional sign followed by
+    /// decimal digits.
+    InvalidDigit,
+    /// The number was too large (or too small) for the type.
+    Overflow,
+    /// The number was zero.
+    Zero,
+    /// The number fit in the underlying integer type, but isn't a valid value
+    /// of the type being parsed (such as a percentage above 100, or a ring
+    /// capacity which isn't a power of two).
+    OutOfRange,
+}
+
+impl core::fmt::Display for ParseNonZeroError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large or too small to fit in target type",
+            Self::Zero => "number would be zero for non-zero type",
+            Self::OutOfRange => "number is not a valid value of the target type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNonZeroError {}
+
+/// The `NonZero` types which [`parse_runtime`] can parse.
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait ParseNonZero: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn parse_nonzero(s: &str) -> Result<Self, ParseNonZeroError>;
+}
+
+macro_rules! impl_parse_nonzero {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl sealed::Sealed for $NonZeroInt {}
+
+        impl ParseNonZero for $NonZeroInt {
+            fn parse_nonzero(s: &str) -> Result<Self, ParseNonZeroError> {
+                let (negative, digits) = match s.as_bytes().split_first() {
+                    None => return Err(ParseNonZeroError::Empty),
+                    Some((b'+', rest)) => (false, rest),
+                    // Like `str::parse`, a `-` is an invalid digit for unsigned
+                    // types.
+                    Some((b'-', rest)) if $int::MIN != 0 => (true, rest),
+                    Some(_) => (false, s.as_bytes()),
+                };
+                if digits.is_empty() {
+                    return Err(ParseNonZeroError::Empty);
+                }
+                let mut n: $int = 0;
+                for &c in digits {
+                    let d = match c {
+                        b'0'..=b'9' => (c - b'0') as $int,
+                        _ => return Err(ParseNonZeroError::InvalidDigit),
+                    };
+                    let next = n.checked_mul(10).and_then(|n| {
+                        // Accumulate negative numbers as negative, so that
+                        // `MIN` doesn't overflow.
+                        if negative {
+                            n.checked_sub(d)
+                        } else {
+                            n.checked_add(d)
+                        }
+                    });
+                    n = next.ok_or(ParseNonZeroError::Overflow)?;
+                }
+                $NonZeroInt::new(n).ok_or(ParseNonZeroError::Zero)
+            }
+        }
+    )+};
+}
+
+impl_parse_nonzero! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroUsize => usize;
+    NonZeroI8 => i8;
+    NonZeroI16 => i16;
+    NonZeroI32 => i32;
+    NonZeroI64 => i64;
+    NonZeroI128 => i128;
+    NonZeroIsize => isize;
+}
+
+/// Parse a `NonZero` integer from a string at runtime, with an error which
+/// says what was wrong with it.
+///
+/// This accepts the same input as [`str::parse`]: an optional `+` (or `-`,
+/// for signed types) followed by decimal digits. With the `std` feature,
+/// [`ParseNonZeroError`] implements `std::error::Error`.
+///
+/// # Examples
+/// ```
+/// use core::num::{NonZeroI8, NonZeroU16};
+/// use nonzero_lit::{parse_runtime, ParseNonZeroError};
+///
+/// assert_eq!(parse_runtime::<NonZeroU16>("8080"), Ok(nonzero_lit::u16!(8080)));
+/// assert_eq!(parse_runtime::<NonZeroI8>("-128"), Ok(nonzero_lit::i8!(-128)));
+///
+/// assert_eq!(parse_runtime::<NonZeroU16>(""), Err(ParseNonZeroError::Empty));
+/// assert_eq!(parse_runtime::<NonZeroU16>("80a"), Err(ParseNonZeroError::InvalidDigit));
+/// assert_eq!(parse_runtime::<NonZeroU16>("65536"), Err(ParseNonZeroError::Overflow));
+/// assert_eq!(parse_runtime::<NonZeroU16>("000"), Err(ParseNonZeroError::Zero));
+///
+/// let err = parse_runtime::<NonZeroU16>("0").unwrap_err();
+/// assert_eq!(err.to_string(), "number would be zero for non-zero type");
+/// ```
+#[inline]
+pub fn parse_runtime<T: ParseNonZero>(s: &str) -> Result<T, ParseNonZeroError> {
+    T::parse_nonzero(s)
+}
+
+// `FromStr` and `TryFrom<&str>` for this crate's types, which parse the
+// `NonZero` value they hold, and then check any other invariant they have.
+macro_rules! impl_from_str {
+    ($($T:ty => $NonZeroInt:ident, $ctor:expr;)+) => {$(
+        impl FromStr for $T {
+            type Err = ParseNonZeroError;
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, ParseNonZeroError> {
+                let n = $NonZeroInt::parse_nonzero(s)?;
+                ($ctor)(n).ok_or(ParseNonZeroError::OutOfRange)
+            }
+        }
+
+        impl<'a> TryFrom<&'a str> for $T {
+            type Error = ParseNonZeroError;
+            #[inline]
+            fn try_from(s: &'a str) -> Result<Self, ParseNonZeroError> {
+                s.parse()
+            }
+        }
+    )+};
+}
+
+impl_from_str! {
+    Port => NonZeroU16, |n| Some(Port::new(n));
+    Percent => NonZeroU8, |n: NonZeroU8| Percent::new_checked(n.get());
+    NonZeroExitCode => NonZeroI32, |n| Some(NonZeroExitCode::new(n));
+    RingCapacity => NonZeroUsize, |n: NonZeroUsize| RingCapacity::new(n.get());
+    Capacity => NonZeroUsize, |n| Some(Capacity::new(n));
+    NonZeroU24 => NonZeroU32, NonZeroU24::from_nonzero;
+    NonZeroU48 => NonZeroU64, NonZeroU48::from_nonzero;
+    NonZeroI24 => NonZeroI32, NonZeroI24::from_nonzero;
+    NonZeroI48 => NonZeroI64, NonZeroI48::from_nonzero;
+    NonZeroIndex<usize> => NonZeroUsize, |n| Some(NonZeroIndex::<usize>::new(n));
+    NonZeroIndex<u8> => NonZeroU8, |n| Some(NonZeroIndex::<u8>::new(n));
+    NonZeroIndex<u16> => NonZeroU16, |n| Some(NonZeroIndex::<u16>::new(n));
+    NonZeroIndex<u32> => NonZeroU32, |n| Some(NonZeroIndex::<u32>::new(n));
+    NonZeroIndex<u64> => NonZeroU64, |n| Some(NonZeroIndex::<u64>::new(n));
+    PowerOfTwo<u8> => NonZeroU8, |n: NonZeroU8| PowerOfTwo::<u8>::new(n.get());
+    PowerOfTwo<u16> => NonZeroU16, |n: NonZeroU16| PowerOfTwo::<u16>::new(n.get());
+    PowerOfTwo<u32> => NonZeroU32, |n: NonZeroU32| PowerOfTwo::<u32>::new(n.get());
+    PowerOfTwo<u64> => NonZeroU64, |n: NonZeroU64| PowerOfTwo::<u64>::new(n.get());
+    PowerOfTwo<u128> => NonZeroU128, |n: NonZeroU128| PowerOfTwo::<u128>::new(n.get());
+    PowerOfTwo<usize> => NonZeroUsize, |n: NonZeroUsize| PowerOfTwo::<usize>::new(n.get());
+    EvenNonZero<NonZeroU8> => NonZeroU8, EvenNonZero::<NonZeroU8>::new;
+    EvenNonZero<NonZeroU16> => NonZeroU16, EvenNonZero::<NonZeroU16>::new;
+    EvenNonZero<NonZeroU32> => NonZeroU32, EvenNonZero::<NonZeroU32>::new;
+    EvenNonZero<NonZeroU64> => NonZeroU64, EvenNonZero::<NonZeroU64>::new;
+    EvenNonZero<NonZeroU128> => NonZeroU128, EvenNonZero::<NonZeroU128>::new;
+    EvenNonZero<NonZeroUsize> => NonZeroUsize, EvenNonZero::<NonZeroUsize>::new;
+    EvenNonZero<NonZeroI8> => NonZeroI8, EvenNonZero::<NonZeroI8>::new;
+    EvenNonZero<NonZeroI16> => NonZeroI16, EvenNonZero::<NonZeroI16>::new;
+    EvenNonZero<NonZeroI32> => NonZeroI32, EvenNonZero::<NonZeroI32>::new;
+    EvenNonZero<NonZeroI64> => NonZeroI64, EvenNonZero::<NonZeroI64>::new;
+    EvenNonZero<NonZeroI128> => NonZeroI128, EvenNonZero::<NonZeroI128>::new;
+    EvenNonZero<NonZeroIsize> => NonZeroIsize, EvenNonZero::<NonZeroIsize>::new;
+}
+
+impl<T: ParseNonZero> FromStr for NonZeroSaturating<T> {
+    type Err = ParseNonZeroError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseNonZeroError> {
+        T::parse_nonzero(s).map(Self)
+    }
+}
+
+impl<'a, T: ParseNonZero> TryFrom<&'a str> for NonZeroSaturating<T> {
+    type Error = ParseNonZeroError;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, ParseNonZeroError> {
+        s.parse()
+    }
+}
+
+impl<T: ParseNonZero> FromStr for NonZeroChecked<T> {
+    type Err = ParseNonZeroError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ParseNonZeroError> {
+        T::parse_nonzero(s).map(Self)
+    }
+}
+
+impl<'a, T: ParseNonZero> TryFrom<&'a str> for NonZeroChecked<T> {
+    type Error = ParseNonZeroError;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, ParseNonZeroError> {
+        s.parse()
+    }
+}
+
+// Parsed from `numerator/denominator`, as it's displayed.
+impl FromStr for NonZeroRatio {
+    type Err = ParseNonZeroError;
+    fn from_str(s: &str) -> Result<Self, ParseNonZeroError> {
+        let mut parts = s.splitn(2, '/');
+        let num = NonZeroU32::parse_nonzero(parts.next().unwrap_or(""))?;
+        let den = parts.next().ok_or(ParseNonZeroError::InvalidDigit)?;
+        Ok(NonZeroRatio::new(num, NonZeroU32::parse_nonzero(den)?))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for NonZeroRatio {
+    type Error = ParseNonZeroError;
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, ParseNonZeroError> {
+        s.parse()
+    }
+}
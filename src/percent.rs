@@ -0,0 +1,135 @@
+use core::num::NonZeroU8;
+
+/// A percentage from 1 to 100, such as a rate limit or a backoff factor.
+///
+/// Use [`nonzero_lit::percent!`](crate::percent) to create one at compile
+/// time. The `of_*` methods apply the percentage to a value, rounding in
+/// different ways, and are usable in `const`.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::Percent;
+///
+/// const SAMPLE: Percent = nonzero_lit::percent!(15);
+/// assert_eq!(SAMPLE.of_floor(1010), 151);
+/// assert_eq!(SAMPLE.of_ceil(1010), 152);
+/// assert_eq!(SAMPLE.of_nearest(1010), 152);
+/// assert_eq!(SAMPLE.to_string(), "15%");
+///
+/// assert_eq!(Percent::new_checked(100), Some(Percent::FULL));
+/// assert_eq!(Percent::new_checked(101), None);
+/// assert_eq!(Percent::new_checked(0), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Percent(NonZeroU8);
+
+impl Percent {
+    /// 100%.
+    pub const FULL: Percent = percent(100);
+
+    /// Wraps a percentage, returning `None` if it's zero or above 100.
+    #[inline]
+    pub const fn new_checked(n: u8) -> Option<Self> {
+        match NonZeroU8::new(n) {
+            Some(n) if n.get() <= 100 => Some(Self(n)),
+            _ => None,
+        }
+    }
+
+    /// Returns the percentage, from 1 to 100.
+    #[inline]
+    pub const fn get(self) -> NonZeroU8 {
+        self.0
+    }
+
+    /// Returns this percentage of `v`, rounded down.
+    #[inline]
+    pub const fn of_floor(self, v: u64) -> u64 {
+        (v as u128 * self.0.get() as u128 / 100) as u64
+    }
+
+    /// Returns this percentage of `v`, rounded up.
+    #[inline]
+    pub const fn of_ceil(self, v: u64) -> u64 {
+        ((v as u128 * self.0.get() as u128 + 99) / 100) as u64
+    }
+
+    /// Returns this percentage of `v`, rounded to the nearest integer (with
+    /// halves rounded up).
+    #[inline]
+    pub const fn of_nearest(self, v: u64) -> u64 {
+        ((v as u128 * self.0.get() as u128 + 50) / 100) as u64
+    }
+}
+
+impl core::fmt::Display for Percent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl From<Percent> for NonZeroU8 {
+    #[inline]
+    fn from(p: Percent) -> Self {
+        p.0
+    }
+}
+
+impl From<Percent> for u8 {
+    #[inline]
+    fn from(p: Percent) -> Self {
+        p.0.get()
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn percent(n: u8) -> Percent {
+    // Note: Hacky const fn assert.
+    let _ = ["percentage must not be above 100"][(n > 100) as usize];
+    Percent(crate::_private::nz_u8(n))
+}
+
+/// Create a constant [`Percent`], checking that it's from 1 to 100.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let p = nonzero_lit::percent!(75);
+/// assert_eq!(p.get().get(), 75);
+/// assert_eq!(p.of_floor(200), 150);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::Percent;
+/// const RETRY_JITTER: Percent = nonzero_lit::percent!(100 / 8);
+/// assert_eq!(u8::from(RETRY_JITTER), 12);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let p = nonzero_lit::percent!(0);
+/// ```
+///
+/// Including percentages above 100.
+/// ```compile_fail
+/// let p = nonzero_lit::percent!(101);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::percent!(bar);
+/// ```
+#[macro_export]
+macro_rules! percent {
+    ($val:expr $(,)?) => {{
+        const __E: u8 = $val;
+        {
+            const P: $crate::Percent = $crate::_private::percent(__E);
+            P
+        }
+    }};
+}
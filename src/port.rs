@@ -0,0 +1,150 @@
+use core::num::NonZeroU16;
+
+/// A network port number, which is never zero.
+///
+/// Port zero is reserved (and usually means "pick any port" to the OS), so it
+/// is rarely what you want to put in a configuration constant. Use
+/// [`nonzero_lit::port!`](crate::port) to create one at compile time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::Port;
+///
+/// const HTTP: Port = nonzero_lit::port!(80);
+/// assert!(HTTP.is_privileged());
+/// assert_eq!(u16::from(HTTP), 80);
+///
+/// let p: Port = "8080".parse().unwrap();
+/// assert!(!p.is_privileged());
+/// assert!("0".parse::<Port>().is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Port(NonZeroU16);
+
+impl Port {
+    /// Ports below this are privileged on most Unix-like systems.
+    pub const FIRST_UNPRIVILEGED: u16 = 1024;
+
+    /// Wraps a `NonZeroU16` port number.
+    #[inline]
+    pub const fn new(n: NonZeroU16) -> Self {
+        Self(n)
+    }
+
+    /// Wraps a port number, returning `None` if it's zero.
+    #[inline]
+    pub const fn new_checked(n: u16) -> Option<Self> {
+        match NonZeroU16::new(n) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Returns the port number.
+    #[inline]
+    pub const fn get(self) -> NonZeroU16 {
+        self.0
+    }
+
+    /// Returns true if this port is below
+    /// [`FIRST_UNPRIVILEGED`](Self::FIRST_UNPRIVILEGED).
+    #[inline]
+    pub const fn is_privileged(self) -> bool {
+        self.0.get() < Self::FIRST_UNPRIVILEGED
+    }
+}
+
+impl core::fmt::Display for Port {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<NonZeroU16> for Port {
+    #[inline]
+    fn from(n: NonZeroU16) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Port> for NonZeroU16 {
+    #[inline]
+    fn from(p: Port) -> Self {
+        p.0
+    }
+}
+
+impl From<Port> for u16 {
+    #[inline]
+    fn from(p: Port) -> Self {
+        p.0.get()
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn port(n: u16) -> Port {
+    Port(crate::_private::nz_u16(n))
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn unprivileged_port(n: u16) -> Port {
+    // Note: Hacky const fn assert.
+    let _ = ["port must not be privileged (below 1024)"][(n < Port::FIRST_UNPRIVILEGED) as usize];
+    port(n)
+}
+
+/// Create a constant [`Port`].
+///
+/// Prefixing the argument with `unprivileged:` additionally requires that the
+/// port is not a privileged port (that is, that it's at least 1024).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let p = nonzero_lit::port!(443);
+/// assert_eq!(p.get().get(), 443);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::Port;
+/// const BASE: u16 = 8000;
+/// const ADMIN: Port = nonzero_lit::port!(unprivileged: BASE + 80);
+/// assert_eq!(u16::from(ADMIN), 8080);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let p = nonzero_lit::port!(0);
+/// ```
+///
+/// Including privileged ports, when requested.
+/// ```compile_fail
+/// let p = nonzero_lit::port!(unprivileged: 80);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::port!(bar);
+/// ```
+#[macro_export]
+macro_rules! port {
+    (unprivileged: $val:expr $(,)?) => {{
+        const __E: u16 = $val;
+        {
+            const P: $crate::Port = $crate::_private::unprivileged_port(__E);
+            P
+        }
+    }};
+    ($val:expr $(,)?) => {{
+        const __E: u16 = $val;
+        {
+            const P: $crate::Port = $crate::_private::port(__E);
+            P
+        }
+    }};
+}
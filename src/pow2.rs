@@ -0,0 +1,209 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+/// A power of two, stored as its exponent, such as an alignment or a
+/// capacity.
+///
+/// Use [`nonzero_lit::pow2!`](crate::pow2) to create one at compile time.
+/// It's implemented for the unsigned integer types, and the value is
+/// available as a `NonZero` from [`get`](Self::get).
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::PowerOfTwo;
+///
+/// const PAGE: PowerOfTwo<usize> = nonzero_lit::pow2!(usize, 4096);
+/// assert_eq!(PAGE.get().get(), 4096);
+/// assert_eq!(PAGE.log2(), 12);
+/// assert_eq!(PAGE.mask(), 0xfff);
+/// assert_eq!(PAGE.align_down(5000), 4096);
+/// assert_eq!(PAGE.checked_align_up(5000), Some(8192));
+/// assert!(PAGE.is_aligned(8192));
+///
+/// let huge = PAGE.checked_mul(nonzero_lit::pow2!(usize, log2: 9)).unwrap();
+/// assert_eq!(huge.get().get(), 2 * 1024 * 1024);
+/// assert_eq!(huge.to_string(), "2097152");
+///
+/// assert_eq!(PowerOfTwo::<usize>::new(24), None);
+/// assert_eq!(PowerOfTwo::<u8>::from_log2(8), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PowerOfTwo<T> {
+    log2: u8,
+    _marker: PhantomData<T>,
+}
+
+macro_rules! impl_pow2 {
+    ($($int:ident => $NonZeroInt:ident;)+) => {$(
+        impl PowerOfTwo<$int> {
+            const BITS: u32 = core::mem::size_of::<$int>() as u32 * 8;
+
+            /// Returns `n`, if it's a power of two.
+            #[inline]
+            pub const fn new(n: $int) -> Option<Self> {
+                if n.is_power_of_two() {
+                    Self::from_log2(n.trailing_zeros())
+                } else {
+                    None
+                }
+            }
+
+            /// Returns `2^log2`, if it fits in the type.
+            #[inline]
+            pub const fn from_log2(log2: u32) -> Option<Self> {
+                if log2 < Self::BITS {
+                    Some(Self { log2: log2 as u8, _marker: PhantomData })
+                } else {
+                    None
+                }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_log2_const(log2: u32) -> Self {
+                // Note: Hacky const fn assert.
+                let _ = ["exponent is too large for the type"][(log2 >= Self::BITS) as usize];
+                Self { log2: log2 as u8, _marker: PhantomData }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_const(n: $int) -> Self {
+                // Note: Hacky const fn assert.
+                let _ = ["value must be a power of two"][!n.is_power_of_two() as usize];
+                Self::__from_log2_const(n.trailing_zeros())
+            }
+
+            /// Returns the value.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                crate::_private::nz_ctor::$int(1 << self.log2)
+            }
+
+            /// Returns the exponent, so that the value is `2^log2`.
+            #[inline]
+            pub const fn log2(self) -> u32 {
+                self.log2 as u32
+            }
+
+            /// Returns `self * rhs`, or `None` if it doesn't fit in the type.
+            #[inline]
+            pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Self::from_log2(self.log2 as u32 + rhs.log2 as u32)
+            }
+
+            /// Returns the mask of the bits below the value, `value - 1`.
+            #[inline]
+            pub const fn mask(self) -> $int {
+                self.get().get() - 1
+            }
+
+            /// Returns true if `n` is a multiple of the value.
+            #[inline]
+            pub const fn is_aligned(self, n: $int) -> bool {
+                n & self.mask() == 0
+            }
+
+            /// Rounds `n` down to a multiple of the value.
+            #[inline]
+            pub const fn align_down(self, n: $int) -> $int {
+                n & !self.mask()
+            }
+
+            /// Rounds `n` up to a multiple of the value, returning `None` if
+            /// that overflows.
+            #[inline]
+            pub const fn checked_align_up(self, n: $int) -> Option<$int> {
+                match n.checked_add(self.mask()) {
+                    Some(n) => Some(self.align_down(n)),
+                    None => None,
+                }
+            }
+        }
+
+        impl fmt::Debug for PowerOfTwo<$int> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("PowerOfTwo").field(&self.get()).finish()
+            }
+        }
+
+        impl fmt::Display for PowerOfTwo<$int> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl From<PowerOfTwo<$int>> for $NonZeroInt {
+            #[inline]
+            fn from(p: PowerOfTwo<$int>) -> Self {
+                p.get()
+            }
+        }
+    )+};
+}
+
+impl_pow2! {
+    u8 => NonZeroU8;
+    u16 => NonZeroU16;
+    u32 => NonZeroU32;
+    u64 => NonZeroU64;
+    u128 => NonZeroU128;
+    usize => NonZeroUsize;
+}
+
+/// Create a constant [`PowerOfTwo`], checking that it's a power of two.
+///
+/// The first argument is the (unsigned) integer type, and the second is the
+/// value. Prefix the value with `log2:` to give the exponent instead.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let align = nonzero_lit::pow2!(u32, 64);
+/// assert_eq!(align.log2(), 6);
+///
+/// let align = nonzero_lit::pow2!(u32, log2: 6);
+/// assert_eq!(align.get().get(), 64);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::PowerOfTwo;
+/// const SLOTS: PowerOfTwo<u64> = nonzero_lit::pow2!(u64, 1 << 20);
+/// const HALF: PowerOfTwo<u64> = nonzero_lit::pow2!(u64, log2: SLOTS.log2() - 1);
+/// assert_eq!(HALF.get().get(), 1 << 19);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let align = nonzero_lit::pow2!(u32, 48);
+/// ```
+///
+/// Including exponents which are too large.
+/// ```compile_fail
+/// let align = nonzero_lit::pow2!(u32, log2: 32);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 4;
+/// let align = nonzero_lit::pow2!(u32, bar);
+/// ```
+#[macro_export]
+macro_rules! pow2 {
+    ($int:ident, log2: $log2:expr $(,)?) => {{
+        const __E: u32 = $log2;
+        {
+            const P: $crate::PowerOfTwo<$int> = $crate::PowerOfTwo::<$int>::__from_log2_const(__E);
+            P
+        }
+    }};
+    ($int:ident, $val:expr $(,)?) => {{
+        const __E: $int = $val;
+        {
+            const P: $crate::PowerOfTwo<$int> = $crate::PowerOfTwo::<$int>::__from_const(__E);
+            P
+        }
+    }};
+}
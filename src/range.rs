@@ -0,0 +1,223 @@
+use core::iter::FusedIterator;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+/// An iterator over an inclusive range of unsigned `NonZero` values, from a
+/// start to an end.
+///
+/// Use [`nonzero_lit::nz_range!`](crate::nz_range) to create one from a
+/// constant range, which checks that it doesn't start at zero, or
+/// [`new`](Self::new) at runtime.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU8;
+/// use nonzero_lit::NonZeroRange;
+///
+/// let ids = NonZeroRange::<NonZeroU8>::new(nonzero_lit::u8!(3), nonzero_lit::u8!(5));
+/// assert_eq!(ids.map(NonZeroU8::get).collect::<Vec<_>>(), [3, 4, 5]);
+///
+/// let all = NonZeroRange::<NonZeroU8>::new(nonzero_lit::u8!(1), nonzero_lit::u8!(255));
+/// assert_eq!(all.len(), 255);
+/// assert_eq!(all.rev().next(), Some(nonzero_lit::u8!(255)));
+/// ```
+///
+/// Since the range can't contain zero, its length always fits in a `usize`
+/// for `NonZeroUsize`, as well as for the types which are no wider than
+/// `usize` on the target, so these implement `ExactSizeIterator` too (unlike
+/// `RangeInclusive<usize>`). Like `RangeInclusive`, `NonZeroU8` and
+/// `NonZeroU16` ranges implement it on every target.
+/// ```
+/// use core::num::NonZeroUsize;
+/// use nonzero_lit::NonZeroRange;
+///
+/// let max = NonZeroUsize::new(usize::MAX).unwrap();
+/// let all = NonZeroRange::<NonZeroUsize>::new(nonzero_lit::usize!(1), max);
+/// assert_eq!(all.len(), usize::MAX);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonZeroRange<T> {
+    start: T,
+    end: T,
+    exhausted: bool,
+}
+
+macro_rules! impl_range {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl NonZeroRange<$NonZeroInt> {
+            /// Creates the range `start..=end`, which is empty if `end` is less
+            /// than `start`.
+            #[inline]
+            pub const fn new(start: $NonZeroInt, end: $NonZeroInt) -> Self {
+                Self { start, end, exhausted: end.get() < start.get() }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __from_const(start: $int, end: $int, inclusive: bool) -> Self {
+                // Note: Hacky const fn assert.
+                let _ = ["range must not start at zero"][(start == 0) as usize];
+                let start = crate::_private::nz_ctor::$int(start);
+                if inclusive {
+                    match $NonZeroInt::new(end) {
+                        Some(end) => Self::new(start, end),
+                        None => Self { start, end: start, exhausted: true },
+                    }
+                } else {
+                    match $NonZeroInt::new(end.saturating_sub(1)) {
+                        Some(end) => Self::new(start, end),
+                        None => Self { start, end: start, exhausted: true },
+                    }
+                }
+            }
+
+            /// Returns true if the range contains `n`.
+            #[inline]
+            pub const fn contains(&self, n: $NonZeroInt) -> bool {
+                !self.exhausted && self.start.get() <= n.get() && n.get() <= self.end.get()
+            }
+
+            /// Returns true if there are no values left in the range.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.exhausted
+            }
+        }
+
+        impl Iterator for NonZeroRange<$NonZeroInt> {
+            type Item = $NonZeroInt;
+
+            #[inline]
+            fn next(&mut self) -> Option<$NonZeroInt> {
+                if self.exhausted {
+                    return None;
+                }
+                let n = self.start;
+                if n == self.end {
+                    self.exhausted = true;
+                } else {
+                    // Can't overflow or be zero, as it's at most `end`.
+                    self.start = crate::_private::nz_ctor::$int(n.get() + 1);
+                }
+                Some(n)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+                let len = (self.end.get() - self.start.get()) as u128 + 1;
+                if len > usize::MAX as u128 {
+                    (usize::MAX, None)
+                } else {
+                    (len as usize, Some(len as usize))
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for NonZeroRange<$NonZeroInt> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$NonZeroInt> {
+                if self.exhausted {
+                    return None;
+                }
+                let n = self.end;
+                if n == self.start {
+                    self.exhausted = true;
+                } else {
+                    // Can't be zero, as it's at least `start`.
+                    self.end = crate::_private::nz_ctor::$int(n.get() - 1);
+                }
+                Some(n)
+            }
+        }
+
+        impl FusedIterator for NonZeroRange<$NonZeroInt> {}
+    )+};
+}
+
+impl_range! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroUsize => usize;
+}
+
+// Like `core::ops::RangeInclusive<u8>` and `<u16>`, the length of these always
+// fits in a `usize`.
+impl ExactSizeIterator for NonZeroRange<NonZeroU8> {}
+impl ExactSizeIterator for NonZeroRange<NonZeroU16> {}
+
+// Unlike `RangeInclusive`, these can't contain zero, so their longest length is
+// `MAX` rather than `MAX + 1`, which fits in a `usize` when the type is no wider
+// than one.
+impl ExactSizeIterator for NonZeroRange<NonZeroUsize> {}
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl ExactSizeIterator for NonZeroRange<NonZeroU32> {}
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for NonZeroRange<NonZeroU64> {}
+
+/// Create a [`NonZeroRange`] from a constant range, checking that it doesn't
+/// start at zero.
+///
+/// The first argument is the (unsigned) integer type, and the second is a range,
+/// either `start..end` or `start..=end`. The start must be a literal, a
+/// constant's name, or a parenthesized expression, while the end can be any
+/// constant expression. Iterating the range gives `NonZero` values, without
+/// needing to unwrap them in the loop.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let mut sum = 0;
+/// for id in nonzero_lit::nz_range!(u32, 1..=100) {
+///     sum += id.get();
+/// }
+/// assert_eq!(sum, 5050);
+/// ```
+///
+/// Works for consts, and the bounds can be any const expression.
+/// ```
+/// use core::num::NonZeroU16;
+/// use nonzero_lit::NonZeroRange;
+/// const FIRST: u16 = 1024;
+/// const EPHEMERAL: NonZeroRange<NonZeroU16> = nonzero_lit::nz_range!(u16, FIRST..(FIRST * 2));
+/// assert_eq!(EPHEMERAL.len(), 1024);
+/// assert_eq!(EPHEMERAL.last().map(|p| p.get()), Some(2047));
+///
+/// // Ranges which end before they start are empty.
+/// assert!(nonzero_lit::nz_range!(u16, 5..5).is_empty());
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let r = nonzero_lit::nz_range!(u32, 0..10);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let n = 10;
+/// let r = nonzero_lit::nz_range!(u32, 1..n);
+/// ```
+#[macro_export]
+macro_rules! nz_range {
+    ($int:ident, $start:tt ..= $end:expr) => {
+        $crate::nz_range!(@imp $int, $start, $end, true)
+    };
+    ($int:ident, $start:tt .. $end:expr) => {
+        $crate::nz_range!(@imp $int, $start, $end, false)
+    };
+    (@imp $int:ident, $start:expr, $end:expr, $inclusive:expr) => {{
+        const __S: $int = $start;
+        const __E: $int = $end;
+        {
+            const R: $crate::NonZeroRange<$crate::_private::nz::$int> =
+                $crate::NonZeroRange::<$crate::_private::nz::$int>::__from_const(
+                    __S, __E, $inclusive,
+                );
+            R
+        }
+    }};
+}
@@ -0,0 +1,206 @@
+use core::cmp::Ordering;
+use core::num::NonZeroU32;
+
+use crate::_private::nz_u32;
+
+const fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// A positive rational number, with a nonzero numerator and denominator.
+///
+/// Ratios are always stored in lowest terms, so two ratios are equal exactly
+/// when their numerators and denominators are. Use
+/// [`nonzero_lit::ratio!`](crate::ratio) to create one at compile time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroRatio;
+///
+/// // 48 kHz to 44.1 kHz.
+/// const RESAMPLE: NonZeroRatio = nonzero_lit::ratio!(44_100 / 48_000);
+/// assert_eq!((RESAMPLE.numer().get(), RESAMPLE.denom().get()), (147, 160));
+///
+/// const PRESCALE: NonZeroRatio = nonzero_lit::ratio!(1 / 64);
+/// let both = RESAMPLE.checked_mul(PRESCALE).unwrap();
+/// assert_eq!(both.to_string(), "147/10240");
+/// assert!(both < RESAMPLE);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonZeroRatio {
+    num: NonZeroU32,
+    den: NonZeroU32,
+}
+
+impl NonZeroRatio {
+    /// The ratio `1/1`.
+    pub const ONE: Self = Self {
+        num: nz_u32(1),
+        den: nz_u32(1),
+    };
+
+    /// Creates the ratio `num / den`, reducing it to lowest terms.
+    #[inline]
+    pub const fn new(num: NonZeroU32, den: NonZeroU32) -> Self {
+        let g = gcd(num.get(), den.get());
+        Self {
+            num: nz_u32(num.get() / g),
+            den: nz_u32(den.get() / g),
+        }
+    }
+
+    /// Creates the ratio `num / den`, returning `None` if either is zero.
+    #[inline]
+    pub const fn new_checked(num: u32, den: u32) -> Option<Self> {
+        match (NonZeroU32::new(num), NonZeroU32::new(den)) {
+            (Some(num), Some(den)) => Some(Self::new(num, den)),
+            _ => None,
+        }
+    }
+
+    /// Returns the numerator, in lowest terms.
+    #[inline]
+    pub const fn numer(self) -> NonZeroU32 {
+        self.num
+    }
+
+    /// Returns the denominator, in lowest terms.
+    #[inline]
+    pub const fn denom(self) -> NonZeroU32 {
+        self.den
+    }
+
+    /// Returns `1 / self`.
+    #[inline]
+    pub const fn recip(self) -> Self {
+        Self {
+            num: self.den,
+            den: self.num,
+        }
+    }
+
+    /// Multiplies two ratios, returning `None` if the result (in lowest terms)
+    /// doesn't fit in a `u32` numerator and denominator.
+    #[inline]
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        // Cross-reduce first, so the products are already in lowest terms.
+        let g1 = gcd(self.num.get(), other.den.get());
+        let g2 = gcd(other.num.get(), self.den.get());
+        let num = (self.num.get() / g1).checked_mul(other.num.get() / g2);
+        let den = (self.den.get() / g2).checked_mul(other.den.get() / g1);
+        match (num, den) {
+            (Some(num), Some(den)) => Some(Self {
+                num: nz_u32(num),
+                den: nz_u32(den),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Divides two ratios, returning `None` if the result (in lowest terms)
+    /// doesn't fit in a `u32` numerator and denominator.
+    #[inline]
+    pub const fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(other.recip())
+    }
+
+    /// Compares two ratios. This is the same as [`Ord::cmp`], but usable in
+    /// `const`.
+    #[inline]
+    pub const fn compare(self, other: Self) -> Ordering {
+        let a = self.num.get() as u64 * other.den.get() as u64;
+        let b = other.num.get() as u64 * self.den.get() as u64;
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl PartialOrd for NonZeroRatio {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonZeroRatio {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(*other)
+    }
+}
+
+impl core::fmt::Display for NonZeroRatio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn ratio(num: u32, den: u32) -> NonZeroRatio {
+    // Note: Hacky const fn asserts.
+    let _ = ["numerator must not be zero"][(num == 0) as usize];
+    let _ = ["denominator must not be zero"][(den == 0) as usize];
+    NonZeroRatio::new(nz_u32(num), nz_u32(den))
+}
+
+/// Create a constant [`NonZeroRatio`], reducing it to lowest terms and checking
+/// at compile time that neither part is zero.
+///
+/// The ratio is written either as `num / den` (where each side is a literal, a
+/// constant's name, or a parenthesized expression), or as `num, den`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let r = nonzero_lit::ratio!(6 / 8);
+/// assert_eq!((r.numer().get(), r.denom().get()), (3, 4));
+/// ```
+///
+/// Works for consts, and the parameters can be any const expressions.
+/// ```
+/// use nonzero_lit::NonZeroRatio;
+/// const CLOCK: u32 = 16_000_000;
+/// const TICK: NonZeroRatio = nonzero_lit::ratio!(1 / (CLOCK / 1000));
+/// const SAME: NonZeroRatio = nonzero_lit::ratio!(1000, CLOCK);
+/// assert_eq!(TICK, SAME);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let r = nonzero_lit::ratio!(3 / 0);
+/// ```
+///
+/// ```compile_fail
+/// let r = nonzero_lit::ratio!(0 / 3);
+/// ```
+///
+/// Note: arguments must be constant expressions.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::ratio!(bar / 4);
+/// ```
+#[macro_export]
+macro_rules! ratio {
+    ($num:tt / $den:tt $(,)?) => {
+        $crate::ratio!($num, $den)
+    };
+    ($num:expr, $den:expr $(,)?) => {{
+        const __N: u32 = $num;
+        const __D: u32 = $den;
+        {
+            const R: $crate::NonZeroRatio = $crate::_private::ratio(__N, __D);
+            R
+        }
+    }};
+}
@@ -0,0 +1,121 @@
+use core::num::NonZeroUsize;
+
+/// The capacity of a ring buffer: a nonzero power of two no larger than
+/// `isize::MAX`, along with the mask used to wrap indices into it.
+///
+/// Use [`nonzero_lit::ring_capacity!`](crate::ring_capacity) to create one at
+/// compile time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::RingCapacity;
+///
+/// const CAP: RingCapacity = nonzero_lit::ring_capacity!(1024);
+/// assert_eq!(CAP.get().get(), 1024);
+/// assert_eq!(CAP.mask(), 1023);
+/// assert_eq!(CAP.wrap(1030), 6);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RingCapacity {
+    cap: NonZeroUsize,
+}
+
+impl RingCapacity {
+    /// Returns the capacity, if `n` is a power of two no larger than
+    /// `isize::MAX`.
+    #[inline]
+    pub const fn new(n: usize) -> Option<Self> {
+        if n.is_power_of_two() && n <= isize::MAX as usize {
+            Some(Self {
+                cap: crate::_private::nz_usize(n),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the capacity.
+    #[inline]
+    pub const fn get(self) -> NonZeroUsize {
+        self.cap
+    }
+
+    /// Returns the mask for wrapping indices, `capacity - 1`.
+    #[inline]
+    pub const fn mask(self) -> usize {
+        self.cap.get() - 1
+    }
+
+    /// Wraps `index` into the range `0..capacity`.
+    #[inline]
+    pub const fn wrap(self, index: usize) -> usize {
+        index & self.mask()
+    }
+}
+
+impl From<RingCapacity> for NonZeroUsize {
+    #[inline]
+    fn from(c: RingCapacity) -> Self {
+        c.cap
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn ring_capacity(n: usize) -> RingCapacity {
+    // Note: Hacky const fn asserts.
+    let _ = ["ring capacity must be a power of two"][!n.is_power_of_two() as usize];
+    let _ = ["ring capacity must not exceed isize::MAX"][(n > isize::MAX as usize) as usize];
+    RingCapacity {
+        cap: crate::_private::nz_usize(n),
+    }
+}
+
+/// Create a constant [`RingCapacity`], checking that the capacity is nonzero, a
+/// power of two, and no larger than `isize::MAX`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let cap = nonzero_lit::ring_capacity!(64);
+/// assert_eq!(cap.mask(), 63);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::RingCapacity;
+/// const SLOTS: usize = 1 << 12;
+/// const CAP: RingCapacity = nonzero_lit::ring_capacity!(SLOTS);
+/// assert_eq!(CAP.get().get(), 4096);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let cap = nonzero_lit::ring_capacity!(0);
+/// ```
+///
+/// Including capacities which aren't a power of two.
+/// ```compile_fail
+/// let cap = nonzero_lit::ring_capacity!(1000);
+/// ```
+///
+/// Or which exceed `isize::MAX`.
+/// ```compile_fail
+/// let cap = nonzero_lit::ring_capacity!(1 << (usize::MAX.count_ones() - 1));
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 4;
+/// let foo = nonzero_lit::ring_capacity!(bar);
+/// ```
+#[macro_export]
+macro_rules! ring_capacity {
+    ($val:expr $(,)?) => {{
+        const __E: usize = $val;
+        {
+            const C: $crate::RingCapacity = $crate::_private::ring_capacity(__E);
+            C
+        }
+    }};
+}
@@ -0,0 +1,210 @@
+//! `rkyv` `Archive`, `Serialize` and `Deserialize` impls for this crate's
+//! `NonZero`-backed types, enabled by the `rkyv` feature (which requires Rust
+//! 1.81, like `rkyv` itself).
+//!
+//! Each type archives as the archived form of the `NonZero` value it holds
+//! (for example, a [`Port`] archives as an `ArchivedNonZeroU16`), so validating
+//! an archive with `CheckBytes` rejects zero, rather than admitting it from
+//! untrusted bytes. Invariants beyond being nonzero (such as the range of a
+//! [`NonZeroU24`]) are checked when deserializing.
+//!
+//! # Examples
+//! ```
+//! use core::mem::MaybeUninit;
+//! use nonzero_lit::Port;
+//! use rkyv::api::low::{from_bytes, to_bytes_in_with_alloc};
+//! use rkyv::rancor::Failure;
+//! use rkyv::ser::{allocator::SubAllocator, writer::Buffer};
+//! use rkyv::util::Align;
+//!
+//! let mut output = Align([MaybeUninit::<u8>::uninit(); 16]);
+//! let mut alloc = [MaybeUninit::<u8>::uninit(); 16];
+//! let bytes = to_bytes_in_with_alloc::<_, _, Failure>(
+//!     &nonzero_lit::port!(443),
+//!     Buffer::from(&mut *output),
+//!     SubAllocator::new(&mut alloc),
+//! )
+//! .unwrap();
+//! assert_eq!(from_bytes::<Port, Failure>(&bytes), Ok(nonzero_lit::port!(443)));
+//!
+//! // A zero from untrusted bytes fails validation.
+//! assert!(from_bytes::<Port, Failure>(&Align([0u8; 2])[..]).is_err());
+//! ```
+use core::fmt;
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroUsize};
+use core::num::{NonZeroI32, NonZeroI64, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+use rkyv::primitive::{
+    ArchivedNonZeroI128, ArchivedNonZeroI16, ArchivedNonZeroI32, ArchivedNonZeroI64,
+    ArchivedNonZeroIsize, ArchivedNonZeroU128, ArchivedNonZeroU16, ArchivedNonZeroU32,
+    ArchivedNonZeroU64, ArchivedNonZeroUsize,
+};
+use rkyv::rancor::{Fallible, Source};
+use rkyv::tuple::ArchivedTuple2;
+use rkyv::{Archive, Deserialize, Place, Serialize};
+
+use crate::{
+    Capacity, EvenNonZero, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI48, NonZeroIndex,
+    NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU48, Percent, Port, PowerOfTwo,
+    RingCapacity,
+};
+
+// The error produced when an archived value is nonzero, but otherwise invalid.
+#[derive(Debug)]
+struct InvalidValue(&'static str);
+
+impl fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid archived value, expected {}", self.0)
+    }
+}
+
+impl core::error::Error for InvalidValue {}
+
+macro_rules! impl_rkyv {
+    ($($T:ty => $NonZeroInt:ty as $Archived:ty, |$n:ident| $ctor:expr, $expected:literal;)+) => {$(
+        impl Archive for $T {
+            type Archived = $Archived;
+            type Resolver = <$NonZeroInt as Archive>::Resolver;
+
+            #[inline]
+            fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+                self.get().resolve(resolver, out)
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $T {
+            #[inline]
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
+        impl<D> Deserialize<$T, D> for $Archived
+        where
+            D: Fallible + ?Sized,
+            D::Error: Source,
+        {
+            #[inline]
+            fn deserialize(&self, deserializer: &mut D) -> Result<$T, D::Error> {
+                let $n: $NonZeroInt = Deserialize::deserialize(self, deserializer)?;
+                let v: Option<$T> = $ctor;
+                v.ok_or_else(|| Source::new(InvalidValue($expected)))
+            }
+        }
+    )+};
+}
+
+impl_rkyv! {
+    Port => NonZeroU16 as ArchivedNonZeroU16, |n| Some(Port::new(n)), "a port number";
+    Percent => NonZeroU8 as NonZeroU8, |n| Percent::new_checked(n.get()), "a percentage from 1 to 100";
+    NonZeroExitCode => NonZeroI32 as ArchivedNonZeroI32, |n| Some(NonZeroExitCode::new(n)), "an exit code";
+    RingCapacity => NonZeroUsize as ArchivedNonZeroUsize, |n| RingCapacity::new(n.get()),
+        "a power of two no larger than `isize::MAX`";
+    Capacity => NonZeroUsize as ArchivedNonZeroUsize, |n| Some(Capacity::new(n)), "a capacity";
+    NonZeroU24 => NonZeroU32 as ArchivedNonZeroU32, |n| NonZeroU24::from_nonzero(n), "a 24-bit unsigned integer";
+    NonZeroU48 => NonZeroU64 as ArchivedNonZeroU64, |n| NonZeroU48::from_nonzero(n), "a 48-bit unsigned integer";
+    NonZeroI24 => NonZeroI32 as ArchivedNonZeroI32, |n| NonZeroI24::from_nonzero(n), "a 24-bit signed integer";
+    NonZeroI48 => NonZeroI64 as ArchivedNonZeroI64, |n| NonZeroI48::from_nonzero(n), "a 48-bit signed integer";
+    NonZeroIndex<usize> => NonZeroUsize as ArchivedNonZeroUsize, |n| Some(NonZeroIndex::<usize>::new(n)), "an index";
+    NonZeroIndex<u8> => NonZeroU8 as NonZeroU8, |n| Some(NonZeroIndex::<u8>::new(n)), "an index";
+    NonZeroIndex<u16> => NonZeroU16 as ArchivedNonZeroU16, |n| Some(NonZeroIndex::<u16>::new(n)), "an index";
+    NonZeroIndex<u32> => NonZeroU32 as ArchivedNonZeroU32, |n| Some(NonZeroIndex::<u32>::new(n)), "an index";
+    NonZeroIndex<u64> => NonZeroU64 as ArchivedNonZeroU64, |n| Some(NonZeroIndex::<u64>::new(n)), "an index";
+    PowerOfTwo<u8> => NonZeroU8 as NonZeroU8, |n| PowerOfTwo::<u8>::new(n.get()), "a power of two";
+    PowerOfTwo<u16> => NonZeroU16 as ArchivedNonZeroU16, |n| PowerOfTwo::<u16>::new(n.get()),
+        "a power of two";
+    PowerOfTwo<u32> => NonZeroU32 as ArchivedNonZeroU32, |n| PowerOfTwo::<u32>::new(n.get()),
+        "a power of two";
+    PowerOfTwo<u64> => NonZeroU64 as ArchivedNonZeroU64, |n| PowerOfTwo::<u64>::new(n.get()),
+        "a power of two";
+    PowerOfTwo<u128> => NonZeroU128 as ArchivedNonZeroU128, |n| PowerOfTwo::<u128>::new(n.get()),
+        "a power of two";
+    PowerOfTwo<usize> => NonZeroUsize as ArchivedNonZeroUsize, |n| PowerOfTwo::<usize>::new(n.get()),
+        "a power of two";
+    EvenNonZero<NonZeroU8> => NonZeroU8 as NonZeroU8, |n| EvenNonZero::<NonZeroU8>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroU16> => NonZeroU16 as ArchivedNonZeroU16, |n| EvenNonZero::<NonZeroU16>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroU32> => NonZeroU32 as ArchivedNonZeroU32, |n| EvenNonZero::<NonZeroU32>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroU64> => NonZeroU64 as ArchivedNonZeroU64, |n| EvenNonZero::<NonZeroU64>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroU128> => NonZeroU128 as ArchivedNonZeroU128, |n| EvenNonZero::<NonZeroU128>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroUsize> => NonZeroUsize as ArchivedNonZeroUsize, |n| EvenNonZero::<NonZeroUsize>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroI8> => NonZeroI8 as NonZeroI8, |n| EvenNonZero::<NonZeroI8>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroI16> => NonZeroI16 as ArchivedNonZeroI16, |n| EvenNonZero::<NonZeroI16>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroI32> => NonZeroI32 as ArchivedNonZeroI32, |n| EvenNonZero::<NonZeroI32>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroI64> => NonZeroI64 as ArchivedNonZeroI64, |n| EvenNonZero::<NonZeroI64>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroI128> => NonZeroI128 as ArchivedNonZeroI128, |n| EvenNonZero::<NonZeroI128>::new(n),
+        "an even integer";
+    EvenNonZero<NonZeroIsize> => NonZeroIsize as ArchivedNonZeroIsize, |n| EvenNonZero::<NonZeroIsize>::new(n),
+        "an even integer";
+    NonZeroSaturating<NonZeroU8> => NonZeroU8 as NonZeroU8, |n| Some(NonZeroSaturating(n)), "a nonzero u8";
+    NonZeroSaturating<NonZeroU16> => NonZeroU16 as ArchivedNonZeroU16, |n| Some(NonZeroSaturating(n)), "a nonzero u16";
+    NonZeroSaturating<NonZeroU32> => NonZeroU32 as ArchivedNonZeroU32, |n| Some(NonZeroSaturating(n)), "a nonzero u32";
+    NonZeroSaturating<NonZeroU64> => NonZeroU64 as ArchivedNonZeroU64, |n| Some(NonZeroSaturating(n)), "a nonzero u64";
+    NonZeroSaturating<NonZeroU128> => NonZeroU128 as ArchivedNonZeroU128, |n| Some(NonZeroSaturating(n)),
+        "a nonzero u128";
+    NonZeroSaturating<NonZeroUsize> => NonZeroUsize as ArchivedNonZeroUsize, |n| Some(NonZeroSaturating(n)),
+        "a nonzero usize";
+    NonZeroChecked<NonZeroU8> => NonZeroU8 as NonZeroU8, |n| Some(NonZeroChecked(n)),
+        "a nonzero u8";
+    NonZeroChecked<NonZeroU16> => NonZeroU16 as ArchivedNonZeroU16, |n| Some(NonZeroChecked(n)),
+        "a nonzero u16";
+    NonZeroChecked<NonZeroU32> => NonZeroU32 as ArchivedNonZeroU32, |n| Some(NonZeroChecked(n)),
+        "a nonzero u32";
+    NonZeroChecked<NonZeroU64> => NonZeroU64 as ArchivedNonZeroU64, |n| Some(NonZeroChecked(n)),
+        "a nonzero u64";
+    NonZeroChecked<NonZeroU128> => NonZeroU128 as ArchivedNonZeroU128, |n| Some(NonZeroChecked(n)),
+        "a nonzero u128";
+    NonZeroChecked<NonZeroUsize> => NonZeroUsize as ArchivedNonZeroUsize, |n| Some(NonZeroChecked(n)),
+        "a nonzero usize";
+    NonZeroChecked<NonZeroI8> => NonZeroI8 as NonZeroI8, |n| Some(NonZeroChecked(n)),
+        "a nonzero i8";
+    NonZeroChecked<NonZeroI16> => NonZeroI16 as ArchivedNonZeroI16, |n| Some(NonZeroChecked(n)),
+        "a nonzero i16";
+    NonZeroChecked<NonZeroI32> => NonZeroI32 as ArchivedNonZeroI32, |n| Some(NonZeroChecked(n)),
+        "a nonzero i32";
+    NonZeroChecked<NonZeroI64> => NonZeroI64 as ArchivedNonZeroI64, |n| Some(NonZeroChecked(n)),
+        "a nonzero i64";
+    NonZeroChecked<NonZeroI128> => NonZeroI128 as ArchivedNonZeroI128, |n| Some(NonZeroChecked(n)),
+        "a nonzero i128";
+    NonZeroChecked<NonZeroIsize> => NonZeroIsize as ArchivedNonZeroIsize, |n| Some(NonZeroChecked(n)),
+        "a nonzero isize";
+}
+
+// Archived as a `(numerator, denominator)` pair, which is reduced again when
+// deserializing.
+impl Archive for NonZeroRatio {
+    type Archived = ArchivedTuple2<ArchivedNonZeroU32, ArchivedNonZeroU32>;
+    type Resolver = <(NonZeroU32, NonZeroU32) as Archive>::Resolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        (self.numer(), self.denom()).resolve(resolver, out)
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for NonZeroRatio {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        (self.numer(), self.denom()).serialize(serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<NonZeroRatio, D>
+    for ArchivedTuple2<ArchivedNonZeroU32, ArchivedNonZeroU32>
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<NonZeroRatio, D::Error> {
+        let (num, den): (NonZeroU32, NonZeroU32) = Deserialize::deserialize(self, deserializer)?;
+        Ok(NonZeroRatio::new(num, den))
+    }
+}
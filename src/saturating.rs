@@ -0,0 +1,133 @@
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Provides intentionally-saturating arithmetic on unsigned `NonZero` values,
+/// which never produces zero.
+///
+/// This is the `NonZero` analog of [`core::num::Saturating`]: results which
+/// would exceed `MAX` saturate at `MAX`, and results which would be zero (or
+/// less) saturate at `1`. This is useful for things like rate limiters and
+/// backoff logic, where a value must never reach zero, without having to
+/// sprinkle `max(1)` everywhere.
+///
+/// Only the unsigned `NonZero` types are supported, as there's no sensible way
+/// to saturate a signed sum that crosses zero.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroSaturating;
+///
+/// const ONE: NonZeroSaturating<core::num::NonZeroU8> =
+///     NonZeroSaturating(nonzero_lit::u8!(1));
+/// let big = NonZeroSaturating(nonzero_lit::u8!(200));
+///
+/// assert_eq!((big + big).0.get(), u8::MAX);
+/// assert_eq!((ONE - big).0.get(), 1);
+/// assert_eq!((ONE / big).0.get(), 1);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroSaturating<T>(pub T);
+
+impl<T: core::fmt::Display> core::fmt::Display for NonZeroSaturating<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_saturating {
+    ($($NonZeroInt:ident => $int:ident, $nz_func:ident;)+) => {$(
+        impl NonZeroSaturating<$NonZeroInt> {
+            /// The smallest value that can be represented by this type, `1`.
+            pub const MIN: Self = Self(crate::_private::$nz_func(1));
+
+            /// The largest value that can be represented by this type.
+            pub const MAX: Self = Self(crate::_private::$nz_func($int::MAX));
+
+            /// Wraps a `NonZero` value.
+            #[inline]
+            pub const fn new(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+
+            /// Returns the wrapped `NonZero` value.
+            #[inline]
+            pub const fn get(self) -> $NonZeroInt {
+                self.0
+            }
+
+            /// Saturating addition, which stops at `MAX`.
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self(crate::_private::$nz_func(self.0.get().saturating_add(rhs.0.get())))
+            }
+
+            /// Saturating subtraction, which stops at `1`.
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                let n = self.0.get().saturating_sub(rhs.0.get());
+                Self(crate::_private::$nz_func(if n == 0 { 1 } else { n }))
+            }
+
+            /// Saturating multiplication, which stops at `MAX`.
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                Self(crate::_private::$nz_func(self.0.get().saturating_mul(rhs.0.get())))
+            }
+
+            /// Saturating division, which stops at `1`.
+            ///
+            /// Unlike normal integer division, this can never panic, as the
+            /// divisor is known to be nonzero.
+            #[inline]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                let n = self.0.get() / rhs.0.get();
+                Self(crate::_private::$nz_func(if n == 0 { 1 } else { n }))
+            }
+        }
+
+        impl From<$NonZeroInt> for NonZeroSaturating<$NonZeroInt> {
+            #[inline]
+            fn from(n: $NonZeroInt) -> Self {
+                Self(n)
+            }
+        }
+
+        impl From<NonZeroSaturating<$NonZeroInt>> for $NonZeroInt {
+            #[inline]
+            fn from(n: NonZeroSaturating<$NonZeroInt>) -> Self {
+                n.0
+            }
+        }
+
+        impl_saturating!(@op $NonZeroInt, Add, add, AddAssign, add_assign, saturating_add);
+        impl_saturating!(@op $NonZeroInt, Sub, sub, SubAssign, sub_assign, saturating_sub);
+        impl_saturating!(@op $NonZeroInt, Mul, mul, MulAssign, mul_assign, saturating_mul);
+        impl_saturating!(@op $NonZeroInt, Div, div, DivAssign, div_assign, saturating_div);
+    )+};
+    (@op $NonZeroInt:ident, $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $method:ident) => {
+        impl $Op for NonZeroSaturating<$NonZeroInt> {
+            type Output = Self;
+            #[inline]
+            fn $op(self, rhs: Self) -> Self {
+                self.$method(rhs)
+            }
+        }
+
+        impl $OpAssign for NonZeroSaturating<$NonZeroInt> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+    };
+}
+
+impl_saturating! {
+    NonZeroUsize => usize, nz_usize;
+    NonZeroU8 => u8, nz_u8;
+    NonZeroU16 => u16, nz_u16;
+    NonZeroU32 => u32, nz_u32;
+    NonZeroU64 => u64, nz_u64;
+    NonZeroU128 => u128, nz_u128;
+}
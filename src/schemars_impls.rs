@@ -0,0 +1,269 @@
+//! `JsonSchema` impls for this crate's types, enabled by the `schemars`
+//! feature.
+//!
+//! The schemas describe the same representation the `serde` impls use, and
+//! carry each type's invariant where JSON Schema can express it (for example,
+//! `"minimum": 1`, or a range for the fixed-width integer types).
+//!
+//! # Examples
+//! ```
+//! let schema = schemars::schema_for!(nonzero_lit::Port);
+//! assert_eq!(schema.get("minimum"), Some(&1.into()));
+//! assert_eq!(schema.get("maximum"), Some(&65535.into()));
+//! ```
+
+use alloc::borrow::Cow;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{
+    Capacity, EvenNonZero, NonEmptyStr, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI256,
+    NonZeroI48, NonZeroIndex, NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU256, NonZeroU48,
+    NulFreeStr, Percent, Port, PowerOfTwo, RingCapacity,
+};
+
+// Integer types with a contiguous range of valid values.
+macro_rules! impl_schema_range {
+    ($($T:ty => $name:expr, $format:literal, $min:expr, $max:expr;)+) => {$(
+        impl JsonSchema for $T {
+            fn schema_name() -> Cow<'static, str> {
+                Cow::Borrowed($name)
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "integer",
+                    "format": $format,
+                    "minimum": $min,
+                    "maximum": $max,
+                })
+            }
+        }
+    )+};
+}
+
+impl_schema_range! {
+    Port => "Port", "uint16", 1, u16::MAX;
+    Percent => "Percent", "uint8", 1, 100;
+    RingCapacity => "RingCapacity", "uint", 1, isize::MAX as usize / 2 + 1;
+    Capacity => "Capacity", "uint", 1, usize::MAX;
+    NonZeroU24 => "NonZeroU24", "uint32", 1, NonZeroU24::MAX.get().get();
+    NonZeroU48 => "NonZeroU48", "uint64", 1, NonZeroU48::MAX.get().get();
+    NonZeroIndex<usize> => "NonZeroIndex_usize", "uint", 1, usize::MAX;
+    NonZeroIndex<u8> => "NonZeroIndex_u8", "uint8", 1, u8::MAX;
+    NonZeroIndex<u16> => "NonZeroIndex_u16", "uint16", 1, u16::MAX;
+    NonZeroIndex<u32> => "NonZeroIndex_u32", "uint32", 1, u32::MAX;
+    NonZeroIndex<u64> => "NonZeroIndex_u64", "uint64", 1, u64::MAX;
+}
+
+// Signed integer types, whose valid values are a range with zero excluded.
+macro_rules! impl_schema_signed {
+    ($($T:ty => $name:expr, $format:literal, $min:expr, $max:expr;)+) => {$(
+        impl JsonSchema for $T {
+            fn schema_name() -> Cow<'static, str> {
+                Cow::Borrowed($name)
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "integer",
+                    "format": $format,
+                    "minimum": $min,
+                    "maximum": $max,
+                    "not": { "const": 0 },
+                })
+            }
+        }
+    )+};
+}
+
+impl_schema_signed! {
+    NonZeroExitCode => "NonZeroExitCode", "int32", i32::MIN, i32::MAX;
+    NonZeroI24 => "NonZeroI24", "int32", NonZeroI24::MIN.get().get(), NonZeroI24::MAX.get().get();
+    NonZeroI48 => "NonZeroI48", "int64", NonZeroI48::MIN.get().get(), NonZeroI48::MAX.get().get();
+}
+
+// Types whose schema needs more than a range, given as the schema itself.
+macro_rules! impl_schema_json {
+    ($($T:ty => $name:expr, $schema:tt;)+) => {$(
+        impl JsonSchema for $T {
+            fn schema_name() -> Cow<'static, str> {
+                Cow::Borrowed($name)
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!($schema)
+            }
+        }
+    )+};
+}
+
+// Note: JSON Schema can't express that these are powers of two, only their
+// range (and a `u128` maximum doesn't fit in a JSON number).
+impl_schema_json! {
+    PowerOfTwo<u8> => "PowerOfTwo_u8",
+        { "type": "integer", "format": "uint8", "minimum": 1, "maximum": 1u8 << 7 };
+    PowerOfTwo<u16> => "PowerOfTwo_u16",
+        { "type": "integer", "format": "uint16", "minimum": 1, "maximum": 1u16 << 15 };
+    PowerOfTwo<u32> => "PowerOfTwo_u32",
+        { "type": "integer", "format": "uint32", "minimum": 1, "maximum": 1u32 << 31 };
+    PowerOfTwo<u64> => "PowerOfTwo_u64",
+        { "type": "integer", "format": "uint64", "minimum": 1, "maximum": 1u64 << 63 };
+    PowerOfTwo<u128> => "PowerOfTwo_u128",
+        { "type": "integer", "format": "uint128", "minimum": 1 };
+    PowerOfTwo<usize> => "PowerOfTwo_usize",
+        { "type": "integer", "format": "uint", "minimum": 1, "maximum": usize::MAX / 2 + 1 };
+}
+
+// As with `PowerOfTwo`, the 128-bit bounds don't fit in a JSON number.
+impl_schema_json! {
+    EvenNonZero<NonZeroU8> => "EvenNonZero_u8",
+        { "type": "integer", "format": "uint8", "minimum": 2, "maximum": u8::MAX - 1, "multipleOf": 2 };
+    EvenNonZero<NonZeroU16> => "EvenNonZero_u16",
+        { "type": "integer", "format": "uint16", "minimum": 2, "maximum": u16::MAX - 1, "multipleOf": 2 };
+    EvenNonZero<NonZeroU32> => "EvenNonZero_u32",
+        { "type": "integer", "format": "uint32", "minimum": 2, "maximum": u32::MAX - 1, "multipleOf": 2 };
+    EvenNonZero<NonZeroU64> => "EvenNonZero_u64",
+        { "type": "integer", "format": "uint64", "minimum": 2, "maximum": u64::MAX - 1, "multipleOf": 2 };
+    EvenNonZero<NonZeroU128> => "EvenNonZero_u128",
+        { "type": "integer", "format": "uint128", "minimum": 2, "multipleOf": 2 };
+    EvenNonZero<NonZeroUsize> => "EvenNonZero_usize",
+        { "type": "integer", "format": "uint", "minimum": 2, "maximum": usize::MAX - 1, "multipleOf": 2 };
+    EvenNonZero<NonZeroI8> => "EvenNonZero_i8", {
+        "type": "integer", "format": "int8", "minimum": i8::MIN, "maximum": i8::MAX - 1,
+        "multipleOf": 2, "not": { "const": 0 },
+    };
+    EvenNonZero<NonZeroI16> => "EvenNonZero_i16", {
+        "type": "integer", "format": "int16", "minimum": i16::MIN, "maximum": i16::MAX - 1,
+        "multipleOf": 2, "not": { "const": 0 },
+    };
+    EvenNonZero<NonZeroI32> => "EvenNonZero_i32", {
+        "type": "integer", "format": "int32", "minimum": i32::MIN, "maximum": i32::MAX - 1,
+        "multipleOf": 2, "not": { "const": 0 },
+    };
+    EvenNonZero<NonZeroI64> => "EvenNonZero_i64", {
+        "type": "integer", "format": "int64", "minimum": i64::MIN, "maximum": i64::MAX - 1,
+        "multipleOf": 2, "not": { "const": 0 },
+    };
+    EvenNonZero<NonZeroI128> => "EvenNonZero_i128",
+        { "type": "integer", "format": "int128", "multipleOf": 2, "not": { "const": 0 } };
+    EvenNonZero<NonZeroIsize> => "EvenNonZero_isize", {
+        "type": "integer", "format": "int", "minimum": isize::MIN, "maximum": isize::MAX - 1,
+        "multipleOf": 2, "not": { "const": 0 },
+    };
+}
+
+impl<T: JsonSchema> JsonSchema for NonZeroSaturating<T> {
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::json_schema(generator)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for NonZeroChecked<T> {
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        T::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::json_schema(generator)
+    }
+}
+
+impl JsonSchema for NulFreeStr {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NulFreeStr")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^[^\\u0000]*$",
+        })
+    }
+}
+
+impl JsonSchema for NonEmptyStr<'_> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonEmptyStr")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "minLength": 1,
+        })
+    }
+}
+
+impl JsonSchema for NonZeroRatio {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonZeroRatio")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let part = json_schema!({
+            "type": "integer",
+            "format": "uint32",
+            "minimum": 1,
+            "maximum": u32::MAX,
+        });
+        json_schema!({
+            "type": "array",
+            "prefixItems": [part, part],
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    }
+}
+
+// Note: JSON Schema can't express that these are nonzero, or that they fit in
+// 256 bits, so only the hex format is described.
+impl JsonSchema for NonZeroU256 {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonZeroU256")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^(0[xX])?[0-9a-fA-F_]+$",
+        })
+    }
+}
+
+impl JsonSchema for NonZeroI256 {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonZeroI256")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^-?(0[xX])?[0-9a-fA-F_]+$",
+        })
+    }
+}
@@ -0,0 +1,143 @@
+//! Field adapters for schemas which use `0` as a sentinel for "no value",
+//! enabled by the `serde` feature.
+//!
+//! Each submodule is meant to be used with serde's `with` attribute:
+//!
+//! - [`zero_as_none`] is for `Option<NonZero*>` fields, where the data uses `0`
+//!   for `None` (as legacy JSON and protobuf schemas often do). Deserializing
+//!   maps `0` to `None`, and serializing writes `None` as `0`.
+//! - [`none_as_zero`] is the strict inverse, for plain integer fields which use
+//!   `0` as their sentinel, where the data is optional. Deserializing maps a
+//!   missing value (`null`) to `0`, and rejects an explicit `0`, and
+//!   serializing writes `0` as `None`.
+//!
+//! For example, `#[serde(with = "nonzero_lit::serde_adapters::zero_as_none")]`
+//! on a `parent: Option<NonZeroU32>` field reads `"parent": 0` as `None`.
+//!
+//! # Examples
+//! ```
+//! use core::num::NonZeroU32;
+//! use nonzero_lit::serde_adapters::{none_as_zero, zero_as_none};
+//! use serde::de::{value::Error, IntoDeserializer};
+//!
+//! let parent: Result<Option<NonZeroU32>, Error> =
+//!     zero_as_none::deserialize(0u32.into_deserializer());
+//! assert_eq!(parent, Ok(None));
+//! let parent: Result<Option<NonZeroU32>, Error> =
+//!     zero_as_none::deserialize(7u32.into_deserializer());
+//! assert_eq!(parent, Ok(Some(nonzero_lit::u32!(7))));
+//!
+//! // `()` deserializes as `null`.
+//! let parent: Result<u32, Error> = none_as_zero::deserialize(().into_deserializer());
+//! assert_eq!(parent, Ok(0));
+//! ```
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use serde::de::DeserializeOwned;
+use serde::ser::Serialize;
+
+use crate::ZeroableInt;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The `NonZero` types which [`zero_as_none`] supports.
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait NonZeroField: sealed::Sealed + Copy {
+    /// The matching primitive type (e.g. `u32` for `NonZeroU32`).
+    type Int: ZeroableInt<NonZero = Self> + Default + Serialize + DeserializeOwned;
+}
+
+macro_rules! impl_nonzero_field {
+    ($($NonZeroInt:ident => $int:ident;)+) => {$(
+        impl sealed::Sealed for $NonZeroInt {}
+
+        impl NonZeroField for $NonZeroInt {
+            type Int = $int;
+        }
+    )+};
+}
+
+impl_nonzero_field! {
+    NonZeroU8 => u8;
+    NonZeroU16 => u16;
+    NonZeroU32 => u32;
+    NonZeroU64 => u64;
+    NonZeroU128 => u128;
+    NonZeroUsize => usize;
+    NonZeroI8 => i8;
+    NonZeroI16 => i16;
+    NonZeroI32 => i32;
+    NonZeroI64 => i64;
+    NonZeroI128 => i128;
+    NonZeroIsize => isize;
+}
+
+/// Adapts an `Option<NonZero*>` field to data which uses `0` for `None`.
+///
+/// Use with `#[serde(with = "nonzero_lit::serde_adapters::zero_as_none")]`.
+pub mod zero_as_none {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    use super::NonZeroField;
+    use crate::ZeroableInt;
+
+    /// Serializes `None` as `0`, and `Some(n)` as `n`.
+    pub fn serialize<N, S>(value: &Option<N>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        N: NonZeroField,
+        S: Serializer,
+    {
+        value
+            .map_or_else(N::Int::default, N::Int::from_nonzero)
+            .serialize(serializer)
+    }
+
+    /// Deserializes `0` as `None`, and anything else as `Some`.
+    pub fn deserialize<'de, N, D>(deserializer: D) -> Result<Option<N>, D::Error>
+    where
+        N: NonZeroField,
+        D: Deserializer<'de>,
+    {
+        N::Int::deserialize(deserializer).map(N::Int::to_nonzero)
+    }
+}
+
+/// Adapts an integer field which uses `0` for "no value" to data where the
+/// value is optional, and `0` isn't allowed.
+///
+/// Use with `#[serde(with = "nonzero_lit::serde_adapters::none_as_zero")]`.
+pub mod none_as_zero {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    use crate::ZeroableInt;
+
+    /// Serializes `0` as `None`, and anything else as `Some`.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ZeroableInt,
+        T::NonZero: Serialize,
+        S: Serializer,
+    {
+        value.to_nonzero().serialize(serializer)
+    }
+
+    /// Deserializes `None` as `0`, and `Some(n)` as `n`, rejecting `Some(0)`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: ZeroableInt + Default,
+        T::NonZero: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let n = Option::<T::NonZero>::deserialize(deserializer)?;
+        Ok(n.map_or_else(T::default, T::from_nonzero))
+    }
+}
@@ -0,0 +1,288 @@
+//! `Serialize` and `Deserialize` impls for this crate's types, enabled by the
+//! `serde` feature.
+//!
+//! Each type serializes as its underlying value, and deserialization checks
+//! the same invariant as the type's constructors, so an invalid value (such as
+//! a zero) is a deserialization error rather than a broken invariant.
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::Port;
+//! use serde::de::{value::Error, Deserialize, IntoDeserializer};
+//!
+//! let p = Port::deserialize(IntoDeserializer::<Error>::into_deserializer(8080u16));
+//! assert_eq!(p, Ok(nonzero_lit::port!(8080)));
+//!
+//! // "invalid value: integer `0`, expected a nonzero port number"
+//! let err = Port::deserialize(IntoDeserializer::<Error>::into_deserializer(0u16));
+//! assert!(err.is_err());
+//! ```
+
+use core::fmt;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use serde::de::{self, Deserialize, Deserializer, Unexpected};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{
+    Capacity, EvenNonZero, NonEmptyStr, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI256,
+    NonZeroI48, NonZeroIndex, NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU256, NonZeroU48,
+    NulFreeStr, Percent, Port, PowerOfTwo, RingCapacity,
+};
+
+macro_rules! impl_serde_int {
+    ($($T:ty => $int:ident, $ctor:expr, $unexpected:ident as $wide:ident, $expected:literal;)+) => {$(
+        impl Serialize for $T {
+            #[inline]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $int::from(self.get()).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $T {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let n = $int::deserialize(deserializer)?;
+                $ctor(n).ok_or_else(|| {
+                    de::Error::invalid_value(Unexpected::$unexpected(n as $wide), &$expected)
+                })
+            }
+        }
+    )+};
+}
+
+impl_serde_int! {
+    Port => u16, Port::new_checked, Unsigned as u64, "a nonzero port number";
+    Percent => u8, Percent::new_checked, Unsigned as u64, "a percentage from 1 to 100";
+    NonZeroExitCode => i32, NonZeroExitCode::new_checked, Signed as i64, "a nonzero exit code";
+    RingCapacity => usize, RingCapacity::new, Unsigned as u64,
+        "a nonzero power of two no larger than `isize::MAX`";
+    Capacity => usize, Capacity::new_checked, Unsigned as u64, "a nonzero capacity";
+    NonZeroU24 => u32, NonZeroU24::new, Unsigned as u64, "a nonzero 24-bit unsigned integer";
+    NonZeroU48 => u64, NonZeroU48::new, Unsigned as u64, "a nonzero 48-bit unsigned integer";
+    NonZeroI24 => i32, NonZeroI24::new, Signed as i64, "a nonzero 24-bit signed integer";
+    NonZeroI48 => i64, NonZeroI48::new, Signed as i64, "a nonzero 48-bit signed integer";
+    NonZeroIndex<usize> => usize, NonZeroIndex::<usize>::from_one_based, Unsigned as u64,
+        "a one-based index";
+    NonZeroIndex<u8> => u8, NonZeroIndex::<u8>::from_one_based, Unsigned as u64,
+        "a one-based index";
+    NonZeroIndex<u16> => u16, NonZeroIndex::<u16>::from_one_based, Unsigned as u64,
+        "a one-based index";
+    NonZeroIndex<u32> => u32, NonZeroIndex::<u32>::from_one_based, Unsigned as u64,
+        "a one-based index";
+    NonZeroIndex<u64> => u64, NonZeroIndex::<u64>::from_one_based, Unsigned as u64,
+        "a one-based index";
+    PowerOfTwo<u8> => u8, PowerOfTwo::<u8>::new, Unsigned as u64, "a power of two";
+    PowerOfTwo<u16> => u16, PowerOfTwo::<u16>::new, Unsigned as u64, "a power of two";
+    PowerOfTwo<u32> => u32, PowerOfTwo::<u32>::new, Unsigned as u64, "a power of two";
+    PowerOfTwo<u64> => u64, PowerOfTwo::<u64>::new, Unsigned as u64, "a power of two";
+    PowerOfTwo<u128> => u128, PowerOfTwo::<u128>::new, Unsigned as u64, "a power of two";
+    PowerOfTwo<usize> => usize, PowerOfTwo::<usize>::new, Unsigned as u64, "a power of two";
+    EvenNonZero<NonZeroU8> => u8, |n| NonZeroU8::new(n).and_then(EvenNonZero::<NonZeroU8>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroU16> => u16, |n| NonZeroU16::new(n).and_then(EvenNonZero::<NonZeroU16>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroU32> => u32, |n| NonZeroU32::new(n).and_then(EvenNonZero::<NonZeroU32>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroU64> => u64, |n| NonZeroU64::new(n).and_then(EvenNonZero::<NonZeroU64>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroU128> => u128, |n| NonZeroU128::new(n).and_then(EvenNonZero::<NonZeroU128>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroUsize> => usize, |n| NonZeroUsize::new(n).and_then(EvenNonZero::<NonZeroUsize>::new),
+        Unsigned as u64, "a nonzero even integer";
+    EvenNonZero<NonZeroI8> => i8, |n| NonZeroI8::new(n).and_then(EvenNonZero::<NonZeroI8>::new),
+        Signed as i64, "a nonzero even integer";
+    EvenNonZero<NonZeroI16> => i16, |n| NonZeroI16::new(n).and_then(EvenNonZero::<NonZeroI16>::new),
+        Signed as i64, "a nonzero even integer";
+    EvenNonZero<NonZeroI32> => i32, |n| NonZeroI32::new(n).and_then(EvenNonZero::<NonZeroI32>::new),
+        Signed as i64, "a nonzero even integer";
+    EvenNonZero<NonZeroI64> => i64, |n| NonZeroI64::new(n).and_then(EvenNonZero::<NonZeroI64>::new),
+        Signed as i64, "a nonzero even integer";
+    EvenNonZero<NonZeroI128> => i128, |n| NonZeroI128::new(n).and_then(EvenNonZero::<NonZeroI128>::new),
+        Signed as i64, "a nonzero even integer";
+    EvenNonZero<NonZeroIsize> => isize, |n| NonZeroIsize::new(n).and_then(EvenNonZero::<NonZeroIsize>::new),
+        Signed as i64, "a nonzero even integer";
+}
+
+impl<T: Serialize> Serialize for NonZeroSaturating<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonZeroSaturating<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // `T` is a `NonZero` type, which already rejects zero.
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+impl<T: Serialize> Serialize for NonZeroChecked<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonZeroChecked<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // `T` is a `NonZero` type, which already rejects zero.
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+// `NulFreeStr` only wraps `&'static str`, so it can't be deserialized.
+impl Serialize for NulFreeStr {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl Serialize for NonEmptyStr<'_> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+// Like `&str`, this can only be deserialized by borrowing from the input.
+impl<'de: 'a, 'a> Deserialize<'de> for NonEmptyStr<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <&'a str>::deserialize(deserializer)?;
+        NonEmptyStr::new(s).ok_or_else(|| de::Error::invalid_length(0, &"a non-empty string"))
+    }
+}
+
+// Serialized as a `(numerator, denominator)` pair.
+impl Serialize for NonZeroRatio {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numer(), self.denom()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonZeroRatio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (num, den) = <(u32, u32)>::deserialize(deserializer)?;
+        NonZeroRatio::new_checked(num, den).ok_or_else(|| {
+            de::Error::invalid_value(
+                Unexpected::Unsigned(0),
+                &"a ratio with a nonzero numerator and denominator",
+            )
+        })
+    }
+}
+
+// The 256-bit types are serialized as hex strings (in the same format as the
+// `u256!` and `i256!` macros) in human-readable formats, and as 32 big-endian
+// (two's complement) bytes otherwise.
+
+// Parses an optionally `-`-prefixed hex string into its sign and magnitude.
+fn parse_hex(s: &str) -> Option<(bool, (u128, u128))> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let s = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s);
+    if s.is_empty() {
+        return None;
+    }
+    let mut v = (0u128, 0u128);
+    for c in s.chars().filter(|&c| c != '_') {
+        let d = c.to_digit(16)?;
+        if v.0 >> 124 != 0 {
+            return None;
+        }
+        v = ((v.0 << 4) | (v.1 >> 124), (v.1 << 4) | d as u128);
+    }
+    Some((negative, v))
+}
+
+struct HexVisitor(&'static str);
+
+impl<'de> de::Visitor<'de> for HexVisitor {
+    type Value = (bool, (u128, u128));
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        parse_hex(s).ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self.0))
+    }
+}
+
+impl Serialize for NonZeroU256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{:#x}", self))
+        } else {
+            self.to_be_bytes().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NonZeroU256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const EXPECTED: &str = "a nonzero 256-bit unsigned integer";
+        let n = if deserializer.is_human_readable() {
+            match deserializer.deserialize_str(HexVisitor(EXPECTED))? {
+                (false, (hi, lo)) => NonZeroU256::new(hi, lo),
+                (true, _) => None,
+            }
+        } else {
+            NonZeroU256::from_be_bytes(<[u8; 32]>::deserialize(deserializer)?)
+        };
+        n.ok_or_else(|| de::Error::invalid_value(Unexpected::Other("zero or negative"), &EXPECTED))
+    }
+}
+
+impl Serialize for NonZeroI256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            // Note: `{:x}` prints the two's complement representation, but we
+            // want the sign and magnitude.
+            match self.checked_neg() {
+                Some(neg) if self.is_negative() => {
+                    serializer.collect_str(&format_args!("-{:#x}", neg))
+                }
+                Some(_) => serializer.collect_str(&format_args!("{:#x}", self)),
+                None => serializer.collect_str(&format_args!("-0x8{:063x}", 0)),
+            }
+        } else {
+            self.to_be_bytes().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NonZeroI256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const EXPECTED: &str = "a nonzero 256-bit signed integer";
+        let n = if deserializer.is_human_readable() {
+            let (negative, (hi, lo)) = deserializer.deserialize_str(HexVisitor(EXPECTED))?;
+            let limit = 1u128 << 127;
+            if hi > limit || (hi == limit && (lo != 0 || !negative)) {
+                return Err(de::Error::invalid_value(
+                    Unexpected::Other("an out of range integer"),
+                    &EXPECTED,
+                ));
+            }
+            match NonZeroI256::new(hi as i128, lo) {
+                Some(n) if negative => n.checked_neg().or(Some(NonZeroI256::MIN)),
+                n => n,
+            }
+        } else {
+            NonZeroI256::from_be_bytes(<[u8; 32]>::deserialize(deserializer)?)
+        };
+        n.ok_or_else(|| de::Error::invalid_value(Unexpected::Other("zero"), &EXPECTED))
+    }
+}
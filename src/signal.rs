@@ -0,0 +1,120 @@
+use core::num::{NonZeroI32, NonZeroU32};
+
+use crate::target::SIGNAL_MAX;
+
+#[doc(hidden)]
+#[inline]
+pub const fn signal_i32(n: i32) -> NonZeroI32 {
+    // Note: Hacky const fn assert.
+    let _ =
+        ["signal number is out of range for the target"][(n < 1 || n > SIGNAL_MAX.get()) as usize];
+    crate::_private::nz_i32(n)
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn signal_u32(n: u32) -> NonZeroU32 {
+    // Note: Hacky const fn assert.
+    let _ = ["signal number is out of range for the target"]
+        [(n < 1 || n > SIGNAL_MAX.get() as u32) as usize];
+    crate::_private::nz_u32(n)
+}
+
+/// Create a constant signal number, checking at compile time that it's between
+/// 1 and [`target::SIGNAL_MAX`](crate::target::SIGNAL_MAX).
+///
+/// This produces a `NonZeroI32` (the type `libc` uses for signal numbers) by
+/// default, or a `NonZeroU32` if the argument is prefixed with `u32,`.
+///
+/// With the `signals` feature, the standard signals are also available as
+/// constants in `nonzero_lit::unix_signal`.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const SIGTERM: core::num::NonZeroI32 = nonzero_lit::signal!(15);
+/// assert_eq!(SIGTERM.get(), 15);
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use core::num::NonZeroU32;
+/// const SIGRTMIN: u32 = 34;
+/// # #[cfg(target_os = "linux")]
+/// const RELOAD: NonZeroU32 = nonzero_lit::signal!(u32, SIGRTMIN + 2);
+/// # #[cfg(target_os = "linux")]
+/// assert_eq!(RELOAD.get(), 36);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let sig = nonzero_lit::signal!(0);
+/// ```
+///
+/// ```compile_fail
+/// let sig = nonzero_lit::signal!(u32, 1000);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let sig = nonzero_lit::signal!(bar);
+/// ```
+#[macro_export]
+macro_rules! signal {
+    (i32, $val:expr $(,)?) => {
+        $crate::signal!($val)
+    };
+    (u32, $val:expr $(,)?) => {{
+        const __E: u32 = $val;
+        {
+            const NZ: $crate::_private::NonZeroU32 = $crate::_private::signal_u32(__E);
+            NZ
+        }
+    }};
+    ($val:expr $(,)?) => {{
+        const __E: i32 = $val;
+        {
+            const NZ: $crate::_private::NonZeroI32 = $crate::_private::signal_i32(__E);
+            NZ
+        }
+    }};
+}
+
+#[cfg(feature = "signals")]
+crate::errno! {
+    /// The standard signals, as `NonZeroI32` constants.
+    ///
+    /// This only includes the signals which have the same number on every
+    /// Unix-like OS (so, for example, `SIGUSR1` is missing, as it's 10 on
+    /// Linux but 30 on macOS). Use [`nonzero_lit::signal!`](crate::signal) for
+    /// the others.
+    ///
+    /// Like a module defined by [`nonzero_lit::errno!`](crate::errno), this also
+    /// provides `name` and `description` lookups, and a list of every signal in
+    /// `CODES`.
+    ///
+    /// Requires the `signals` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use nonzero_lit::unix_signal;
+    ///
+    /// assert_eq!(unix_signal::SIGKILL.get(), 9);
+    /// assert_eq!(unix_signal::name(nonzero_lit::signal!(15)), Some("SIGTERM"));
+    /// ```
+    pub mod unix_signal {
+        SIGHUP = 1 => "Hangup";
+        SIGINT = 2 => "Interrupt";
+        SIGQUIT = 3 => "Quit";
+        SIGILL = 4 => "Illegal instruction";
+        SIGTRAP = 5 => "Trace/breakpoint trap";
+        SIGABRT = 6 => "Aborted";
+        SIGFPE = 8 => "Floating point exception";
+        SIGKILL = 9 => "Killed";
+        SIGSEGV = 11 => "Segmentation fault";
+        SIGPIPE = 13 => "Broken pipe";
+        SIGALRM = 14 => "Alarm clock";
+        SIGTERM = 15 => "Terminated";
+    }
+}
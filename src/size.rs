@@ -0,0 +1,122 @@
+// Parses a size such as `64KiB` into a number of bytes, which must be nonzero
+// and at most `max`.
+#[doc(hidden)]
+pub const fn parse_bytes(s: &str, max: u128) -> u128 {
+    let b = s.as_bytes();
+    let len = b.len();
+    let mut i = 0;
+    // Note: Hacky const fn asserts, here and below.
+    let _ =
+        ["expected a number at the start of size"][!(len > 0 && b[0].is_ascii_digit()) as usize];
+    let mut n: u128 = 0;
+    while i < len && (b[i].is_ascii_digit() || b[i] == b'_') {
+        if b[i] != b'_' {
+            n = n * 10 + (b[i] - b'0') as u128;
+            let _ = ["size is too large"][(n > u64::MAX as u128) as usize];
+        }
+        i += 1;
+    }
+    // A single space is allowed between the number and the unit.
+    if i + 1 < len && b[i] == b' ' {
+        i += 1;
+    }
+    let unit = match (len - i, if i < len { b[i] } else { 0 }) {
+        (0, _) => 1,
+        (1, b'B') => 1,
+        (2, _) | (3, _) => {
+            let _ = ["size unit must end in `B`"][(b[len - 1] != b'B') as usize];
+            let iec = len - i == 3;
+            let _ = ["unknown size unit, expected a unit such as `KB` or `KiB`"]
+                [(iec && b[i + 1] != b'i') as usize];
+            let base: u128 = if iec { 1024 } else { 1000 };
+            let exp = match b[i] {
+                b'k' if !iec => 1,
+                b'K' => 1,
+                b'M' => 2,
+                b'G' => 3,
+                b'T' => 4,
+                b'P' => 5,
+                b'E' => 6,
+                _ => 0,
+            };
+            let _ =
+                ["unknown size unit, expected a unit such as `KB` or `KiB`"][(exp == 0) as usize];
+            let mut unit = 1;
+            let mut j = 0;
+            while j < exp {
+                unit *= base;
+                j += 1;
+            }
+            unit
+        }
+        _ => 0,
+    };
+    let _ = ["unknown size unit, expected a unit such as `KB` or `KiB`"][(unit == 0) as usize];
+    let bytes = n * unit;
+    let _ = ["size must not be zero"][(bytes == 0) as usize];
+    let _ = ["size doesn't fit in the integer type"][(bytes > max) as usize];
+    bytes
+}
+
+/// Parse a size string such as `"64KiB"` at compile time, producing the number
+/// of bytes as the `NonZero` type matching the given integer type.
+///
+/// The first argument is the (unsigned) integer type, and the second is the
+/// size: a whole number (which may contain `_` separators), optionally
+/// followed by a space, and then a unit. The units are `B`, the SI units `KB`
+/// (or `kB`), `MB`, `GB`, `TB`, `PB` and `EB`, which are powers of 1000, and
+/// the IEC units `KiB`, `MiB`, `GiB`, `TiB`, `PiB` and `EiB`, which are powers
+/// of 1024. A number with no unit is in bytes.
+///
+/// Malformed strings, zero sizes, and sizes which don't fit in the type fail
+/// the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let n = nonzero_lit::bytes_str!(usize, "64KiB");
+/// assert_eq!(n.get(), 64 * 1024);
+/// assert_eq!(nonzero_lit::bytes_str!(u64, "2 MB").get(), 2_000_000);
+/// ```
+///
+/// Works for consts.
+/// ```
+/// use core::num::{NonZeroU64, NonZeroUsize};
+///
+/// const BUFFER: NonZeroUsize = nonzero_lit::bytes_str!(usize, "8KiB");
+/// const MAX_UPLOAD: NonZeroU64 = nonzero_lit::bytes_str!(u64, "5GiB");
+/// assert_eq!(BUFFER.get(), 8192);
+/// assert_eq!(MAX_UPLOAD.get(), 5 << 30);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::bytes_str!(usize, "0KiB");
+/// ```
+///
+/// Including malformed strings, and sizes which don't fit in the type.
+/// ```compile_fail
+/// let n = nonzero_lit::bytes_str!(usize, "64 kib");
+/// ```
+///
+/// ```compile_fail
+/// let n = nonzero_lit::bytes_str!(u16, "64KiB");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "4KiB";
+/// let foo = nonzero_lit::bytes_str!(usize, bar);
+/// ```
+#[macro_export]
+macro_rules! bytes_str {
+    ($int:ident, $s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(
+                $crate::_private::parse_bytes(__E, $int::MAX as u128) as $int,
+            );
+            NZ
+        }
+    }};
+}
@@ -0,0 +1,87 @@
+use core::num::NonZeroUsize;
+use core::slice::{Chunks, ChunksExact, ChunksMut, RChunks, Windows};
+
+use crate::NonZeroIndex;
+
+/// Extension methods for slices which take `NonZero` sizes and indices.
+///
+/// The standard `chunks` and `windows` methods panic when given a size of
+/// zero. Taking a [`NonZeroUsize`] instead makes that impossible (and lets the
+/// compiler drop the check), so when the size comes from a macro such as
+/// [`nonzero_lit::usize!`](crate::usize), it's checked at compile time rather
+/// than at runtime.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::SliceNonZeroExt;
+///
+/// const BLOCK: core::num::NonZeroUsize = nonzero_lit::usize!(4);
+/// let data = [1u8, 2, 3, 4, 5, 6];
+///
+/// let blocks: Vec<&[u8]> = data.chunks_nz(BLOCK).collect();
+/// assert_eq!(blocks, [&[1, 2, 3, 4][..], &[5, 6][..]]);
+/// assert_eq!(data.windows_nz(nonzero_lit::usize!(5)).count(), 2);
+///
+/// assert_eq!(data.get_1based(nonzero_lit::index!(1)), Some(&1));
+/// assert_eq!(data.get_1based(nonzero_lit::index!(7)), None);
+/// ```
+pub trait SliceNonZeroExt<T> {
+    /// Like `chunks`, but with a size which can't be zero.
+    fn chunks_nz(&self, size: NonZeroUsize) -> Chunks<'_, T>;
+
+    /// Like `chunks_mut`, but with a size which can't be zero.
+    fn chunks_mut_nz(&mut self, size: NonZeroUsize) -> ChunksMut<'_, T>;
+
+    /// Like `chunks_exact`, but with a size which can't be zero.
+    fn chunks_exact_nz(&self, size: NonZeroUsize) -> ChunksExact<'_, T>;
+
+    /// Like `rchunks`, but with a size which can't be zero.
+    fn rchunks_nz(&self, size: NonZeroUsize) -> RChunks<'_, T>;
+
+    /// Like `windows`, but with a size which can't be zero.
+    fn windows_nz(&self, size: NonZeroUsize) -> Windows<'_, T>;
+
+    /// Returns the element at a one-based index, if it's in bounds.
+    fn get_1based(&self, index: NonZeroIndex) -> Option<&T>;
+
+    /// Returns a mutable reference to the element at a one-based index, if
+    /// it's in bounds.
+    fn get_1based_mut(&mut self, index: NonZeroIndex) -> Option<&mut T>;
+}
+
+impl<T> SliceNonZeroExt<T> for [T] {
+    #[inline]
+    fn chunks_nz(&self, size: NonZeroUsize) -> Chunks<'_, T> {
+        self.chunks(size.get())
+    }
+
+    #[inline]
+    fn chunks_mut_nz(&mut self, size: NonZeroUsize) -> ChunksMut<'_, T> {
+        self.chunks_mut(size.get())
+    }
+
+    #[inline]
+    fn chunks_exact_nz(&self, size: NonZeroUsize) -> ChunksExact<'_, T> {
+        self.chunks_exact(size.get())
+    }
+
+    #[inline]
+    fn rchunks_nz(&self, size: NonZeroUsize) -> RChunks<'_, T> {
+        self.rchunks(size.get())
+    }
+
+    #[inline]
+    fn windows_nz(&self, size: NonZeroUsize) -> Windows<'_, T> {
+        self.windows(size.get())
+    }
+
+    #[inline]
+    fn get_1based(&self, index: NonZeroIndex) -> Option<&T> {
+        self.get(index.zero_based())
+    }
+
+    #[inline]
+    fn get_1based_mut(&mut self, index: NonZeroIndex) -> Option<&mut T> {
+        self.get_mut(index.zero_based())
+    }
+}
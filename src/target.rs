@@ -0,0 +1,82 @@
+//! `NonZero` constants describing the compilation target.
+//!
+//! These are all computed from the target's actual layout (which is what
+//! `cfg(target_pointer_width)` and friends describe), so they're always correct
+//! for the target being compiled for.
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::target;
+//!
+//! assert_eq!(target::POINTER_WIDTH_BYTES.get() * 8, target::POINTER_WIDTH_BITS.get() as usize);
+//! assert!(target::MAX_ALIGN.get() >= target::POINTER_ALIGN.get());
+//! # #[cfg(target_pointer_width = "64")]
+//! assert_eq!(target::POINTER_WIDTH_BITS.get(), 64);
+//! ```
+
+use core::mem::{align_of, size_of};
+use core::num::{NonZeroI32, NonZeroU32, NonZeroUsize};
+
+use crate::_private::{nz_i32, nz_u32, nz_usize};
+
+/// The width of a pointer (and of `usize`) in bits.
+pub const POINTER_WIDTH_BITS: NonZeroU32 = nz_u32(size_of::<usize>() as u32 * 8);
+
+/// The width of a pointer (and of `usize`) in bytes.
+pub const POINTER_WIDTH_BYTES: NonZeroUsize = nz_usize(size_of::<usize>());
+
+/// The alignment of a pointer (and of `usize`) in bytes.
+pub const POINTER_ALIGN: NonZeroUsize = nz_usize(align_of::<usize>());
+
+/// The largest alignment of any primitive scalar type, in bytes.
+///
+/// This is similar to the alignment of C's `max_align_t`, and is a reasonable
+/// alignment to use for memory which may hold values of any primitive type.
+/// However, they don't always agree (for example, older Rust versions
+/// under-aligned `u128` on x86), so don't rely on it for FFI.
+pub const MAX_ALIGN: NonZeroUsize = nz_usize(max(
+    max(align_of::<u128>(), align_of::<u64>()),
+    max(align_of::<f64>(), align_of::<usize>()),
+));
+
+/// The largest value a `usize` can hold.
+pub const USIZE_MAX: NonZeroUsize = nz_usize(usize::MAX);
+
+/// The largest signal number on the target OS (that is, one less than C's
+/// `NSIG`), including any real-time signals.
+///
+/// Unlike the other constants in this module, this isn't computed from the
+/// target's layout, so OSes it doesn't know about get Linux's value.
+pub const SIGNAL_MAX: NonZeroI32 = nz_i32(
+    if cfg!(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+    )) {
+        31
+    } else if cfg!(target_os = "openbsd") {
+        32
+    } else if cfg!(target_os = "netbsd") {
+        63
+    } else if cfg!(any(target_os = "freebsd", target_os = "dragonfly")) {
+        128
+    } else if cfg!(all(
+        target_os = "linux",
+        any(target_arch = "mips", target_arch = "mips64"),
+    )) {
+        127
+    } else if cfg!(windows) {
+        22
+    } else {
+        64
+    },
+);
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
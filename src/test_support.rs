@@ -0,0 +1,144 @@
+//! Helpers for testing code which works with `NonZero` values, enabled by the
+//! `test_support` feature.
+//!
+//! This has iterators over every value of the 8 and 16-bit `NonZero` types,
+//! for sweeping their whole domain, and arrays of boundary values for all of
+//! the types, for when that isn't practical. Both are free of zeros by
+//! construction, so tests don't need to unwrap anything.
+//!
+//! This is meant for tests, so it's typically enabled only for them:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! nonzero_lit = { version = "0.1", features = ["test_support"] }
+//! ```
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::test_support;
+//!
+//! fn halve(n: core::num::NonZeroU8) -> u8 {
+//!     n.get() / 2
+//! }
+//!
+//! for n in test_support::all_u8() {
+//!     assert!(halve(n) < n.get());
+//! }
+//! assert_eq!(test_support::all_i8().count(), 255);
+//!
+//! for &n in test_support::U32_BOUNDARIES.iter() {
+//!     assert_eq!(n.get().checked_next_power_of_two().is_some(), n.get() <= 1 << 31);
+//! }
+//! assert_eq!(test_support::I64_BOUNDARIES[0].get(), i64::MIN);
+//! ```
+
+use core::iter::{Chain, FilterMap};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::ops::RangeInclusive;
+
+use crate::NonZeroRange;
+
+/// Returns an iterator over every `NonZeroU8`, in increasing order.
+#[inline]
+pub fn all_u8() -> NonZeroRange<NonZeroU8> {
+    NonZeroRange::<NonZeroU8>::new(crate::_private::nz_u8(1), crate::_private::nz_u8(u8::MAX))
+}
+
+/// Returns an iterator over every `NonZeroU16`, in increasing order.
+#[inline]
+pub fn all_u16() -> NonZeroRange<NonZeroU16> {
+    NonZeroRange::<NonZeroU16>::new(
+        crate::_private::nz_u16(1),
+        crate::_private::nz_u16(u16::MAX),
+    )
+}
+
+/// An iterator over every value of a signed `NonZero` type, returned by
+/// [`all_i8`] and [`all_i16`].
+pub type AllSigned<T, N> = Chain<
+    FilterMap<RangeInclusive<T>, fn(T) -> Option<N>>,
+    FilterMap<RangeInclusive<T>, fn(T) -> Option<N>>,
+>;
+
+/// Returns an iterator over every `NonZeroI8`, in increasing order.
+#[inline]
+pub fn all_i8() -> AllSigned<i8, NonZeroI8> {
+    let new: fn(i8) -> Option<NonZeroI8> = NonZeroI8::new;
+    (i8::MIN..=-1)
+        .filter_map(new)
+        .chain((1..=i8::MAX).filter_map(new))
+}
+
+/// Returns an iterator over every `NonZeroI16`, in increasing order.
+#[inline]
+pub fn all_i16() -> AllSigned<i16, NonZeroI16> {
+    let new: fn(i16) -> Option<NonZeroI16> = NonZeroI16::new;
+    (i16::MIN..=-1)
+        .filter_map(new)
+        .chain((1..=i16::MAX).filter_map(new))
+}
+
+macro_rules! unsigned_boundaries {
+    ($($(#[$attr:meta])* $NAME:ident: $NonZeroInt:ident => $int:ident;)+) => {$(
+        $(#[$attr])*
+        pub const $NAME: [$NonZeroInt; 6] = [
+            crate::_private::nz_ctor::$int(1),
+            crate::_private::nz_ctor::$int(2),
+            crate::_private::nz_ctor::$int($int::MAX / 2),
+            crate::_private::nz_ctor::$int($int::MAX / 2 + 1),
+            crate::_private::nz_ctor::$int($int::MAX - 1),
+            crate::_private::nz_ctor::$int($int::MAX),
+        ];
+    )+};
+}
+
+unsigned_boundaries! {
+    /// Boundary values of `NonZeroU8`: `1`, `2`, the values either side of
+    /// the top bit, `MAX - 1` and `MAX`, in increasing order.
+    U8_BOUNDARIES: NonZeroU8 => u8;
+    /// Boundary values of `NonZeroU16`, like [`U8_BOUNDARIES`].
+    U16_BOUNDARIES: NonZeroU16 => u16;
+    /// Boundary values of `NonZeroU32`, like [`U8_BOUNDARIES`].
+    U32_BOUNDARIES: NonZeroU32 => u32;
+    /// Boundary values of `NonZeroU64`, like [`U8_BOUNDARIES`].
+    U64_BOUNDARIES: NonZeroU64 => u64;
+    /// Boundary values of `NonZeroU128`, like [`U8_BOUNDARIES`].
+    U128_BOUNDARIES: NonZeroU128 => u128;
+    /// Boundary values of `NonZeroUsize`, like [`U8_BOUNDARIES`].
+    USIZE_BOUNDARIES: NonZeroUsize => usize;
+}
+
+macro_rules! signed_boundaries {
+    ($($(#[$attr:meta])* $NAME:ident: $NonZeroInt:ident => $int:ident;)+) => {$(
+        $(#[$attr])*
+        pub const $NAME: [$NonZeroInt; 8] = [
+            crate::_private::nz_ctor::$int($int::MIN),
+            crate::_private::nz_ctor::$int($int::MIN + 1),
+            crate::_private::nz_ctor::$int(-2),
+            crate::_private::nz_ctor::$int(-1),
+            crate::_private::nz_ctor::$int(1),
+            crate::_private::nz_ctor::$int(2),
+            crate::_private::nz_ctor::$int($int::MAX - 1),
+            crate::_private::nz_ctor::$int($int::MAX),
+        ];
+    )+};
+}
+
+signed_boundaries! {
+    /// Boundary values of `NonZeroI8`: `MIN`, `MIN + 1`, `-2`, `-1`, `1`, `2`,
+    /// `MAX - 1` and `MAX`, in increasing order.
+    I8_BOUNDARIES: NonZeroI8 => i8;
+    /// Boundary values of `NonZeroI16`, like [`I8_BOUNDARIES`].
+    I16_BOUNDARIES: NonZeroI16 => i16;
+    /// Boundary values of `NonZeroI32`, like [`I8_BOUNDARIES`].
+    I32_BOUNDARIES: NonZeroI32 => i32;
+    /// Boundary values of `NonZeroI64`, like [`I8_BOUNDARIES`].
+    I64_BOUNDARIES: NonZeroI64 => i64;
+    /// Boundary values of `NonZeroI128`, like [`I8_BOUNDARIES`].
+    I128_BOUNDARIES: NonZeroI128 => i128;
+    /// Boundary values of `NonZeroIsize`, like [`I8_BOUNDARIES`].
+    ISIZE_BOUNDARIES: NonZeroIsize => isize;
+}
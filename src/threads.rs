@@ -0,0 +1,78 @@
+use core::num::NonZeroUsize;
+
+#[doc(hidden)]
+#[inline]
+pub const fn threads_bounds(default: usize, max: usize) -> (NonZeroUsize, NonZeroUsize) {
+    // Note: Hacky const fn asserts.
+    let _ = ["default thread count must not be zero"][(default == 0) as usize];
+    let _ = ["maximum thread count must not be zero"][(max == 0) as usize];
+    let _ = ["default thread count must not exceed the maximum"][(default > max) as usize];
+    (
+        crate::_private::nz_usize(default),
+        crate::_private::nz_usize(max),
+    )
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn threads(default: NonZeroUsize, max: NonZeroUsize) -> NonZeroUsize {
+    std::thread::available_parallelism()
+        .unwrap_or(default)
+        .min(max)
+}
+
+/// Get the number of threads to use for a thread pool, as a
+/// [`NonZeroUsize`].
+///
+/// This is [`std::thread::available_parallelism`], or the `default` if that
+/// fails, limited to at most `max` (which can be omitted). Both bounds are
+/// checked at compile time: they must be nonzero, and `default` must not
+/// exceed `max`.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let workers = nonzero_lit::threads!(default: 4, max: 64);
+/// assert!(workers.get() <= 64);
+///
+/// let all = nonzero_lit::threads!(default: 1);
+/// assert!(all >= workers);
+/// ```
+///
+/// The bounds can be any constant expressions.
+/// ```
+/// const MAX_WORKERS: usize = 16;
+/// let workers = nonzero_lit::threads!(default: MAX_WORKERS / 4, max: MAX_WORKERS);
+/// assert!(workers.get() <= MAX_WORKERS);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let workers = nonzero_lit::threads!(default: 0, max: 64);
+/// ```
+///
+/// ```compile_fail
+/// let workers = nonzero_lit::threads!(default: 8, max: 4);
+/// ```
+///
+/// Note: arguments must be constant expressions.
+/// ```compile_fail
+/// let bar = 4;
+/// let workers = nonzero_lit::threads!(default: bar);
+/// ```
+#[macro_export]
+macro_rules! threads {
+    (default: $default:expr, max: $max:expr $(,)?) => {{
+        const __E: (usize, usize) = ($default, $max);
+        {
+            const B: ($crate::_private::NonZeroUsize, $crate::_private::NonZeroUsize) =
+                $crate::_private::threads_bounds(__E.0, __E.1);
+            $crate::_private::threads(B.0, B.1)
+        }
+    }};
+    (default: $default:expr $(,)?) => {
+        $crate::threads!(default: $default, max: usize::MAX)
+    };
+}
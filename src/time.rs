@@ -0,0 +1,626 @@
+#[doc(hidden)]
+pub const fn parse_epoch(s: &str) -> u64 {
+    let b = s.as_bytes();
+    let len = b.len();
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["timestamp is too short, expected `YYYY-MM-DDTHH:MM:SS` and an offset"]
+        [(len < 20) as usize];
+
+    let year = digits(b, 0, 4);
+    expect_byte(b, 4, b'-');
+    let month = digits(b, 5, 2);
+    expect_byte(b, 7, b'-');
+    let day = digits(b, 8, 2);
+    let _ = ["expected `T` between the date and time"]
+        [!(b[10] == b'T' || b[10] == b't' || b[10] == b' ') as usize];
+    let hour = digits(b, 11, 2);
+    expect_byte(b, 13, b':');
+    let minute = digits(b, 14, 2);
+    expect_byte(b, 16, b':');
+    let second = digits(b, 17, 2);
+
+    let mut i = 19;
+    // Fractional seconds are allowed, but ignored.
+    if b[i] == b'.' {
+        i += 1;
+        let _ = ["expected a digit after `.`"][!(i < len && b[i].is_ascii_digit()) as usize];
+        while i < len && b[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+
+    let _ = ["expected an offset (`Z` or `+HH:MM`) after the time"][(i >= len) as usize];
+    let offset: i64 = if b[i] == b'Z' || b[i] == b'z' {
+        i += 1;
+        0
+    } else {
+        let _ = ["expected an offset (`Z` or `+HH:MM`) after the time"]
+            [!(b[i] == b'+' || b[i] == b'-') as usize];
+        let _ = ["timestamp offset is too short, expected `+HH:MM`"][(len < i + 6) as usize];
+        let oh = digits(b, i + 1, 2);
+        expect_byte(b, i + 3, b':');
+        let om = digits(b, i + 4, 2);
+        let _ = ["offset hours must be at most 23"][(oh > 23) as usize];
+        let _ = ["offset minutes must be at most 59"][(om > 59) as usize];
+        let sign = if b[i] == b'-' { -1 } else { 1 };
+        i += 6;
+        sign * (oh * 3600 + om * 60) as i64
+    };
+    let _ = ["unexpected trailing characters in timestamp"][(i != len) as usize];
+
+    let _ = ["month must be in 1..=12"][(month < 1 || month > 12) as usize];
+    let _ = ["day is out of range for the month"]
+        [(day < 1 || day > days_in_month(year, month)) as usize];
+    let _ = ["hour must be at most 23"][(hour > 23) as usize];
+    let _ = ["minute must be at most 59"][(minute > 59) as usize];
+    let _ = ["second must be at most 59 (leap seconds are not supported)"][(second > 59) as usize];
+
+    let days = days_from_civil(year as i64, month as i64, day as i64);
+    let secs = days * 86400 + (hour * 3600 + minute * 60 + second) as i64 - offset;
+    let _ = ["timestamp must be after 1970-01-01T00:00:00Z"][(secs <= 0) as usize];
+    secs as u64
+}
+
+const fn digits(b: &[u8], start: usize, n: usize) -> u64 {
+    let mut v = 0;
+    let mut i = start;
+    while i < start + n {
+        let _ = ["expected a digit"][!b[i].is_ascii_digit() as usize];
+        v = v * 10 + (b[i] - b'0') as u64;
+        i += 1;
+    }
+    v
+}
+
+const fn expect_byte(b: &[u8], i: usize, c: u8) {
+    let _ =
+        ["unexpected character in timestamp (expected `-` or `:` separator)"][(b[i] != c) as usize];
+}
+
+const fn is_leap_year(y: u64) -> bool {
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+}
+
+const fn days_in_month(y: u64, m: u64) -> u64 {
+    match m {
+        2 if is_leap_year(y) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date. From Howard Hinnant's
+// `days_from_civil`.
+const fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = if m > 2 { m - 3 } else { m + 9 };
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Parse an RFC 3339 timestamp at compile time, producing the number of
+/// seconds since the Unix epoch as a [`NonZeroU64`](core::num::NonZeroU64).
+///
+/// The timestamp must include an offset (either `Z`, or `+HH:MM`/`-HH:MM`).
+/// Fractional seconds are accepted, but truncated. Malformed or invalid
+/// timestamps, and timestamps at or before the epoch itself, fail to compile.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let cutoff = nonzero_lit::epoch!("2025-01-01T00:00:00Z");
+/// assert_eq!(cutoff.get(), 1735689600);
+/// ```
+///
+/// Works for consts, and with offsets.
+/// ```
+/// use core::num::NonZeroU64;
+/// const DEADLINE: NonZeroU64 = nonzero_lit::epoch!("2024-02-29T09:30:00.250+05:30");
+/// assert_eq!(DEADLINE.get(), 1709179200);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::epoch!("1970-01-01T00:00:00Z");
+/// ```
+///
+/// Including malformed or invalid dates.
+/// ```compile_fail
+/// let t = nonzero_lit::epoch!("2023-02-29T00:00:00Z");
+/// ```
+///
+/// ```compile_fail
+/// let t = nonzero_lit::epoch!("2023-01-01 12:00");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "2023-01-01T00:00:00Z";
+/// let foo = nonzero_lit::epoch!(bar);
+/// ```
+#[macro_export]
+macro_rules! epoch {
+    ($s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const T: $crate::_private::NonZeroU64 =
+                $crate::_private::nz_u64($crate::_private::parse_epoch(__E));
+            T
+        }
+    }};
+}
+
+// Parses a number of seconds since the Unix epoch, as in `SOURCE_DATE_EPOCH`.
+#[doc(hidden)]
+pub const fn parse_unix_time(s: &str) -> u64 {
+    let b = s.as_bytes();
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["build timestamp is empty"][b.is_empty() as usize];
+    let mut secs: u128 = 0;
+    let mut i = 0;
+    while i < b.len() {
+        let _ = ["build timestamp must be a decimal number of seconds since the epoch"]
+            [!b[i].is_ascii_digit() as usize];
+        secs = secs * 10 + (b[i] - b'0') as u128;
+        let _ = ["build timestamp is too large"][(secs > u64::MAX as u128) as usize];
+        i += 1;
+    }
+    let _ = ["build timestamp must not be zero"][(secs == 0) as usize];
+    secs as u64
+}
+
+/// Get the time of the build from an environment variable, as the number of
+/// seconds since the Unix epoch in a [`NonZeroU64`](core::num::NonZeroU64).
+///
+/// By default this reads [`SOURCE_DATE_EPOCH`], which reproducible build
+/// tooling sets to a fixed time, such as that of the last commit. Use
+/// `build_time!(env: "NAME")` to read another variable instead. Either way,
+/// the variable is read when compiling the crate which uses the macro, and
+/// must hold a decimal number of seconds. The build fails if the variable is
+/// missing, malformed, or zero.
+///
+/// [`SOURCE_DATE_EPOCH`]: https://reproducible-builds.org/specs/source-date-epoch/
+///
+/// # Examples
+/// Basic usage (ignored here, since it only compiles when `SOURCE_DATE_EPOCH`
+/// and `FIRMWARE_DATE` are set)
+/// ```ignore
+/// use core::num::NonZeroU64;
+///
+/// const BUILT_AT: NonZeroU64 = nonzero_lit::build_time!();
+/// const FIRMWARE_DATE: NonZeroU64 = nonzero_lit::build_time!(env: "FIRMWARE_DATE");
+/// ```
+///
+/// Any variable holding a number works, such as this one, which Cargo sets to
+/// the minor version of the crate being built.
+/// ```
+/// use core::num::NonZeroU64;
+///
+/// const MINOR: NonZeroU64 = nonzero_lit::build_time!(env: "CARGO_PKG_VERSION_MINOR");
+/// assert_eq!(MINOR.get().to_string(), env!("CARGO_PKG_VERSION_MINOR"));
+/// ```
+///
+/// Misuse is detected at compile time, including a missing variable.
+/// ```compile_fail
+/// let t = nonzero_lit::build_time!(env: "NONZERO_LIT_UNSET_BUILD_TIME");
+/// ```
+///
+/// Or one which isn't a number.
+/// ```compile_fail
+/// let t = nonzero_lit::build_time!(env: "CARGO_PKG_NAME");
+/// ```
+#[macro_export]
+macro_rules! build_time {
+    ($(,)?) => {
+        $crate::build_time!(env: "SOURCE_DATE_EPOCH")
+    };
+    (env: $var:literal $(,)?) => {{
+        const T: $crate::_private::NonZeroU64 =
+            $crate::_private::nz_u64($crate::_private::parse_unix_time(::core::env!($var)));
+        T
+    }};
+}
+
+// Converts `v` of a unit with `per_sec` units per second into ticks of a
+// `num / den` Hz clock, rounding down. As with `checked_from`, the value is
+// passed as its sign and its bits as a `u128`.
+#[doc(hidden)]
+pub const fn time_ticks(
+    negative: bool,
+    v: u128,
+    per_sec: u128,
+    num: u128,
+    den: u128,
+    max: u128,
+) -> u128 {
+    // Note: Hacky const fn asserts.
+    let _ = ["duration must not be negative"][negative as usize];
+    let _ = ["tick rate must not be zero"][(num == 0 || den == 0) as usize];
+    let (n, overflowed) = match v.checked_mul(num) {
+        Some(n) => (n, false),
+        None => (0, true),
+    };
+    let _ = ["duration overflowed while converting to ticks"][overflowed as usize];
+    let ticks = n / (per_sec * den);
+    let _ = ["duration must be at least one tick"][(ticks == 0) as usize];
+    let _ = ["duration in ticks doesn't fit in the integer type"][(ticks > max) as usize];
+    ticks
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __time_ticks {
+    ($per_sec:expr, $int:ident, $val:expr, hz: $num:tt / $den:tt $(,)?) => {{
+        // Note: The comparison makes floats (and other non-integers) a type
+        // error, rather than truncating them with the cast.
+        const __E: (bool, u128) = (($val) < 0, ($val) as i128 as u128);
+        {
+            const NZ: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(
+                $crate::_private::time_ticks(
+                    __E.0,
+                    __E.1,
+                    $per_sec,
+                    $num as u128,
+                    $den as u128,
+                    $int::MAX as u128,
+                ) as $int,
+            );
+            NZ
+        }
+    }};
+    ($per_sec:expr, $int:ident, $val:expr, hz: $hz:expr $(,)?) => {
+        $crate::__time_ticks!($per_sec, $int, $val, hz: ($hz) / 1)
+    };
+    ($per_sec:expr, $int:ident, $val:expr $(,)?) => {
+        $crate::__time_ticks!($per_sec, $int, $val, hz: ($per_sec) / 1)
+    };
+}
+
+/// Convert a constant number of milliseconds into the `NonZero` type matching
+/// the given integer type, optionally converting it to ticks of a clock at
+/// compile time.
+///
+/// With just a type and a value, the result is the value itself. Adding
+/// `hz: rate` converts it to ticks of a clock running at `rate` Hz, where the
+/// rate may be a fraction written `num / den` (such as a prescaled clock),
+/// rounding down. Zero (including a duration shorter than a single tick) and
+/// overflow are detected at compile time.
+///
+/// See also [`nonzero_lit::micros!`](crate::micros) and
+/// [`nonzero_lit::nanos!`](crate::nanos).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let timeout = nonzero_lit::millis!(u64, 250);
+/// assert_eq!(timeout.get(), 250);
+/// ```
+///
+/// Converting to ticks of a 32.768 kHz clock, or of a 16 MHz clock divided by
+/// 64.
+/// ```
+/// use core::num::{NonZeroU16, NonZeroU32};
+///
+/// const WATCHDOG: NonZeroU32 = nonzero_lit::millis!(u32, 250, hz: 32_768);
+/// assert_eq!(WATCHDOG.get(), 8192);
+///
+/// const CPU_HZ: u32 = 16_000_000;
+/// const DEBOUNCE: NonZeroU16 = nonzero_lit::millis!(u16, 20, hz: CPU_HZ / 64);
+/// assert_eq!(DEBOUNCE.get(), 5000);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::millis!(u64, 0);
+/// ```
+///
+/// Including durations which don't fit in the type.
+/// ```compile_fail
+/// let t = nonzero_lit::millis!(u16, 1000, hz: 1_000_000);
+/// ```
+///
+/// And those shorter than one tick.
+/// ```compile_fail
+/// let t = nonzero_lit::millis!(u32, 1, hz: 100);
+/// ```
+///
+/// And negative or fractional durations, rather than truncating them.
+/// ```compile_fail
+/// let t = nonzero_lit::millis!(u32, -5);
+/// ```
+///
+/// ```compile_fail
+/// let t = nonzero_lit::millis!(u32, 1.9);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let foo = nonzero_lit::millis!(u32, bar);
+/// ```
+#[macro_export]
+macro_rules! millis {
+    ($int:ident, $($rest:tt)+) => {
+        $crate::__time_ticks!(1_000, $int, $($rest)+)
+    };
+}
+
+/// Convert a constant number of microseconds into the `NonZero` type matching
+/// the given integer type, optionally converting it to ticks of a clock at
+/// compile time.
+///
+/// This works exactly like [`nonzero_lit::millis!`](crate::millis), but for
+/// microseconds.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// const SETTLE: NonZeroU32 = nonzero_lit::micros!(u32, 1500);
+/// assert_eq!(SETTLE.get(), 1500);
+///
+/// const PULSE: NonZeroU32 = nonzero_lit::micros!(u32, 10, hz: 48_000_000);
+/// assert_eq!(PULSE.get(), 480);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::micros!(u32, 10, hz: 1000);
+/// ```
+#[macro_export]
+macro_rules! micros {
+    ($int:ident, $($rest:tt)+) => {
+        $crate::__time_ticks!(1_000_000, $int, $($rest)+)
+    };
+}
+
+/// Convert a constant number of nanoseconds into the `NonZero` type matching
+/// the given integer type, optionally converting it to ticks of a clock at
+/// compile time.
+///
+/// This works exactly like [`nonzero_lit::millis!`](crate::millis), but for
+/// nanoseconds.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroU8;
+///
+/// const SETUP: NonZeroU8 = nonzero_lit::nanos!(u8, 250, hz: 100_000_000);
+/// assert_eq!(SETUP.get(), 25);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::nanos!(u8, 0);
+/// ```
+#[macro_export]
+macro_rules! nanos {
+    ($int:ident, $($rest:tt)+) => {
+        $crate::__time_ticks!(1_000_000_000, $int, $($rest)+)
+    };
+}
+
+#[doc(hidden)]
+pub const fn backoff_check(base: u64, factor: u64, max: u64) {
+    // Note: Hacky const fn asserts.
+    let _ = ["backoff base delay must not be zero"][(base == 0) as usize];
+    let _ = ["backoff factor must not be zero"][(factor == 0) as usize];
+    let _ = ["backoff maximum must be at least the base delay"][(max < base) as usize];
+}
+
+#[doc(hidden)]
+pub const fn backoff_next(delay: u64, factor: u64, max: u64) -> u64 {
+    match delay.checked_mul(factor) {
+        Some(d) if d <= max => d,
+        _ => max,
+    }
+}
+
+/// Produce an array of `N` exponential backoff delays at compile time, as
+/// [`NonZeroU64`](core::num::NonZeroU64)s.
+///
+/// The arguments are the first delay, the factor each delay is multiplied by
+/// to get the next, the maximum delay, and `N` (a `usize`). The delays are
+/// `u64`s in whatever unit the caller uses (such as milliseconds), without
+/// jitter, and once a delay reaches the maximum, the rest are the maximum.
+///
+/// A first delay of zero (which would make a retry loop spin), a factor of
+/// zero, or a maximum below the first delay fails the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let t = nonzero_lit::backoff_delays!(100, 2, 1000, 6);
+/// let v: Vec<u64> = t.iter().map(|n| n.get()).collect();
+/// assert_eq!(v, [100, 200, 400, 800, 1000, 1000]);
+/// ```
+///
+/// Works for consts, and the parameters can be any const expression.
+/// ```
+/// use core::num::NonZeroU64;
+/// use core::time::Duration;
+///
+/// const BASE_MS: u64 = 250;
+/// const RETRIES: [NonZeroU64; 4] = nonzero_lit::backoff_delays!(BASE_MS, 3, 60_000, 4);
+///
+/// for (attempt, delay) in RETRIES.iter().enumerate() {
+///     let wait = Duration::from_millis(delay.get());
+///     assert!(wait >= Duration::from_millis(250 * 3u64.pow(attempt as u32)));
+/// }
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::backoff_delays!(0, 2, 1000, 6);
+/// ```
+///
+/// Including a maximum below the first delay.
+/// ```compile_fail
+/// let t = nonzero_lit::backoff_delays!(100, 2, 50, 6);
+/// ```
+///
+/// Note: arguments must be constant expressions.
+/// ```compile_fail
+/// let bar = 100;
+/// let t = nonzero_lit::backoff_delays!(bar, 2, 1000, 6);
+/// ```
+#[macro_export]
+macro_rules! backoff_delays {
+    ($base:expr, $factor:expr, $max:expr, $n:expr $(,)?) => {{
+        const __BASE: u64 = $base;
+        const __FACTOR: u64 = $factor;
+        const __MAX: u64 = $max;
+        const __N: usize = $n;
+        {
+            const T: [$crate::_private::NonZeroU64; __N] = {
+                $crate::_private::backoff_check(__BASE, __FACTOR, __MAX);
+                let mut out = [$crate::_private::nz_u64(1); __N];
+                let mut delay = __BASE;
+                let mut i = 0;
+                while i < __N {
+                    out[i] = $crate::_private::nz_u64(delay);
+                    delay = $crate::_private::backoff_next(delay, __FACTOR, __MAX);
+                    i += 1;
+                }
+                out
+            };
+            T
+        }
+    }};
+}
+
+// Parses a duration such as `1h30m15s` into a number of `unit_nanos`
+// nanosecond units.
+#[doc(hidden)]
+pub const fn parse_duration(s: &str, unit_nanos: u128) -> u64 {
+    let b = s.as_bytes();
+    let len = b.len();
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["duration must not be empty"][(len == 0) as usize];
+    let mut total: u128 = 0;
+    // The rank of the previous component's unit, so that they can be
+    // required to be in decreasing order.
+    let mut last_rank = 7;
+    let mut i = 0;
+    while i < len {
+        let _ = ["expected a number in duration"][!b[i].is_ascii_digit() as usize];
+        let mut n: u128 = 0;
+        while i < len && b[i].is_ascii_digit() {
+            n = n * 10 + (b[i] - b'0') as u128;
+            let _ = ["duration is too long"][(n > u64::MAX as u128) as usize];
+            i += 1;
+        }
+        let _ = ["expected a unit (`d`, `h`, `m`, `s`, `ms`, `us` or `ns`) after number"]
+            [(i >= len) as usize];
+        let next = if i + 1 < len { b[i + 1] } else { 0 };
+        let (rank, nanos, unit_len): (u32, u128, usize) = match (b[i], next) {
+            (b'd', _) => (6, 86_400_000_000_000, 1),
+            (b'h', _) => (5, 3_600_000_000_000, 1),
+            (b'm', b's') => (2, 1_000_000, 2),
+            (b'm', _) => (4, 60_000_000_000, 1),
+            (b's', _) => (3, 1_000_000_000, 1),
+            (b'u', b's') => (1, 1_000, 2),
+            (b'n', b's') => (0, 1, 2),
+            _ => (7, 0, 0),
+        };
+        let _ = ["unknown unit in duration, expected `d`, `h`, `m`, `s`, `ms`, `us` or `ns`"]
+            [(rank == 7) as usize];
+        let _ = ["duration units must be in decreasing order, and not repeated"]
+            [(rank >= last_rank) as usize];
+        last_rank = rank;
+        i += unit_len;
+        total += n * nanos;
+    }
+    let _ = ["duration is not a whole number of the requested unit"]
+        [(total % unit_nanos != 0) as usize];
+    let v = total / unit_nanos;
+    let _ = ["duration must not be zero"][(v == 0) as usize];
+    let _ =
+        ["duration doesn't fit in a `u64` of the requested unit"][(v > u64::MAX as u128) as usize];
+    v as u64
+}
+
+/// Parse a duration string such as `"1h30m15s"` at compile time, producing a
+/// [`NonZeroU64`](core::num::NonZeroU64) number of milliseconds.
+///
+/// The string is a sequence of whole numbers, each followed by a unit: `d`,
+/// `h`, `m`, `s`, `ms`, `us` or `ns` (with no spaces), in decreasing order of
+/// unit. Prefixing the string with `secs:`, `millis:`, `micros:` or `nanos:`
+/// selects the unit of the result, which defaults to milliseconds.
+///
+/// Malformed strings, zero durations, durations which aren't a whole number of
+/// the result's unit, and overflow fail the build.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let t = nonzero_lit::duration_str!("1h30m15s");
+/// assert_eq!(t.get(), 5_415_000);
+/// ```
+///
+/// Works for consts, and with other units.
+/// ```
+/// use core::num::NonZeroU64;
+/// use core::time::Duration;
+///
+/// const TIMEOUT: NonZeroU64 = nonzero_lit::duration_str!(secs: "2m");
+/// assert_eq!(TIMEOUT.get(), 120);
+///
+/// const POLL: NonZeroU64 = nonzero_lit::duration_str!(nanos: "1ms500us");
+/// assert_eq!(Duration::from_nanos(POLL.get()), Duration::from_micros(1500));
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let t = nonzero_lit::duration_str!("0s");
+/// ```
+///
+/// Including malformed strings, or units out of order.
+/// ```compile_fail
+/// let t = nonzero_lit::duration_str!("1h 30m");
+/// ```
+///
+/// ```compile_fail
+/// let t = nonzero_lit::duration_str!("30m1h");
+/// ```
+///
+/// Or durations which aren't a whole number of the requested unit.
+/// ```compile_fail
+/// let t = nonzero_lit::duration_str!(secs: "1500ms");
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "5s";
+/// let foo = nonzero_lit::duration_str!(bar);
+/// ```
+#[macro_export]
+macro_rules! duration_str {
+    (@imp $unit_nanos:expr, $s:expr) => {{
+        const __E: &str = $s;
+        {
+            const T: $crate::_private::NonZeroU64 =
+                $crate::_private::nz_u64($crate::_private::parse_duration(__E, $unit_nanos));
+            T
+        }
+    }};
+    (secs: $s:expr $(,)?) => {
+        $crate::duration_str!(@imp 1_000_000_000, $s)
+    };
+    (millis: $s:expr $(,)?) => {
+        $crate::duration_str!(@imp 1_000_000, $s)
+    };
+    (micros: $s:expr $(,)?) => {
+        $crate::duration_str!(@imp 1_000, $s)
+    };
+    (nanos: $s:expr $(,)?) => {
+        $crate::duration_str!(@imp 1, $s)
+    };
+    ($s:expr $(,)?) => {
+        $crate::duration_str!(@imp 1_000_000, $s)
+    };
+}
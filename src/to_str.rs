@@ -0,0 +1,99 @@
+#[doc(hidden)]
+pub const fn digits_len(v: u128, radix: u128) -> usize {
+    let mut v = v / radix;
+    let mut n = 1;
+    while v != 0 {
+        v /= radix;
+        n += 1;
+    }
+    n
+}
+
+// The `i`th digit of `v` (which is `len` digits long), from the left, as an
+// ASCII byte.
+#[doc(hidden)]
+pub const fn digit(v: u128, radix: u128, len: usize, i: usize) -> u8 {
+    let mut v = v;
+    let mut n = len - 1 - i;
+    while n != 0 {
+        v /= radix;
+        n -= 1;
+    }
+    b"0123456789abcdef"[(v % radix) as usize]
+}
+
+/// Convert a constant into a `&'static str` of its decimal (or hexadecimal)
+/// representation at compile time, checking that it's nonzero.
+///
+/// The first argument is the integer type. Prefix the arguments with `hex:`
+/// for lowercase hexadecimal (without a `0x` prefix) instead of decimal. Like
+/// `{:x}`, this formats negative numbers as their two's complement.
+///
+/// Requires the `const_str` feature, and Rust 1.63 (which is when
+/// [`core::str::from_utf8`] became usable in `const`).
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// const PORT: u16 = 8080;
+/// const PORT_STR: &str = nonzero_lit::const_to_str!(u16, PORT);
+/// assert_eq!(PORT_STR, "8080");
+///
+/// assert_eq!(nonzero_lit::const_to_str!(i64, -1234), "-1234");
+/// assert_eq!(nonzero_lit::const_to_str!(hex: u32, 0xdead_beef), "deadbeef");
+/// assert_eq!(nonzero_lit::const_to_str!(hex: i8, -1), "ff");
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let s = nonzero_lit::const_to_str!(u32, 0);
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = 3;
+/// let s = nonzero_lit::const_to_str!(u32, bar);
+/// ```
+#[macro_export]
+macro_rules! const_to_str {
+    (@imp $radix:expr, $int:ident, $val:expr) => {{
+        const __E: $int = $val;
+        {
+            const V: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int(__E);
+            const BITS: u32 = (::core::mem::size_of::<$int>() * 8) as u32;
+            // Decimal is written as a sign and magnitude, but hex is written
+            // as the two's complement bits.
+            const NEG: bool = $radix == 10 && $int::MIN != 0 && (V.get() as i128) < 0;
+            const MAG: u128 = if NEG {
+                (V.get() as i128).wrapping_neg() as u128
+            } else if BITS < 128 {
+                V.get() as u128 & ((1 << BITS) - 1)
+            } else {
+                V.get() as u128
+            };
+            const DIGITS: usize = $crate::_private::digits_len(MAG, $radix);
+            const LEN: usize = NEG as usize + DIGITS;
+            const BYTES: [u8; LEN] = {
+                let mut out = [b'-'; LEN];
+                let mut i = 0;
+                while i < DIGITS {
+                    out[NEG as usize + i] = $crate::_private::digit(MAG, $radix, DIGITS, i);
+                    i += 1;
+                }
+                out
+            };
+            const B: &[u8] = &BYTES;
+            const S: &str = match ::core::str::from_utf8(B) {
+                Ok(s) => s,
+                Err(_) => panic!("const_to_str! produced invalid UTF-8"),
+            };
+            S
+        }
+    }};
+    (hex: $int:ident, $val:expr $(,)?) => {
+        $crate::const_to_str!(@imp 16, $int, $val)
+    };
+    ($int:ident, $val:expr $(,)?) => {
+        $crate::const_to_str!(@imp 10, $int, $val)
+    };
+}
@@ -0,0 +1,82 @@
+/// Implemented by the types declared with
+/// [`nonzero_typeconst!`](crate::nonzero_typeconst), each of which carries a
+/// `NonZero` constant.
+///
+/// This allows generic code to take a nonzero value as a type parameter, even
+/// without const generics.
+///
+/// # Examples
+/// ```
+/// use core::num::NonZeroUsize;
+/// use nonzero_lit::TypeConst;
+///
+/// nonzero_lit::nonzero_typeconst! {
+///     type Small = usize: 16;
+///     type Large = usize: 4096;
+/// }
+///
+/// fn blocks<B: TypeConst<NonZero = NonZeroUsize>>(bytes: usize) -> usize {
+///     (bytes + B::VALUE.get() - 1) / B::VALUE.get()
+/// }
+/// assert_eq!(blocks::<Small>(100), 7);
+/// assert_eq!(blocks::<Large>(100), 1);
+/// ```
+pub trait TypeConst {
+    /// The `NonZero` type of the constant (e.g. `NonZeroUsize`).
+    type NonZero: Copy;
+
+    /// The constant.
+    const VALUE: Self::NonZero;
+}
+
+/// Declare zero-sized types which each carry a `NonZero` constant, checked at
+/// compile time.
+///
+/// Each declaration is `type Name = int: value;`, where `int` is the integer
+/// type. The constant is available both as `Name::VALUE` and through the
+/// [`TypeConst`](crate::TypeConst) trait. Attributes (such as doc comments)
+/// and a visibility can be given for each type.
+///
+/// This is the style of API used by crates like `typenum`, and is useful for
+/// configuring generic code with values carried by types.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// nonzero_lit::nonzero_typeconst! {
+///     /// The size of a page.
+///     pub type PageSize = usize: 4096;
+///     type Rounds = u8: 10;
+/// }
+///
+/// assert_eq!(PageSize::VALUE.get(), 4096);
+/// assert_eq!(Rounds::VALUE.get(), 10);
+/// ```
+///
+/// Misuse is detected at compile time, even if the type is never used.
+/// ```compile_fail
+/// nonzero_lit::nonzero_typeconst! {
+///     type Zero = u32: 0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! nonzero_typeconst {
+    ($($(#[$meta:meta])* $vis:vis type $Name:ident = $int:ident : $val:expr;)+) => {$(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $Name;
+
+        impl $Name {
+            /// The constant this type carries.
+            pub const VALUE: $crate::_private::nz::$int = $crate::_private::nz_ctor::$int($val);
+        }
+
+        impl $crate::TypeConst for $Name {
+            type NonZero = $crate::_private::nz::$int;
+            const VALUE: $crate::_private::nz::$int = $Name::VALUE;
+        }
+
+        // Check the value even if the type is never used.
+        const _: $crate::_private::nz::$int = $Name::VALUE;
+    )+};
+}
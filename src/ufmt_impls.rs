@@ -0,0 +1,138 @@
+//! `ufmt::uDisplay` and `ufmt::uDebug` impls for this crate's wrapper types,
+//! enabled by the `ufmt` feature (which requires Rust 1.56, like `ufmt`
+//! itself).
+//!
+//! Both traits write what the type's `Display` impl does (so a [`Percent`]
+//! writes `75%`, and a [`NonZeroRatio`] writes `3/4`), as there's little
+//! point in keeping the structure of the derived `Debug` output on the
+//! targets which use `ufmt`.
+//!
+//! # Examples
+//! ```
+//! use ufmt::{uwrite, uWrite};
+//!
+//! struct Buf(String);
+//!
+//! impl uWrite for Buf {
+//!     type Error = core::convert::Infallible;
+//!     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+//!         self.0.push_str(s);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut buf = Buf(String::new());
+//! uwrite!(buf, "{} {:?} ", nonzero_lit::port!(8080), nonzero_lit::percent!(75)).unwrap();
+//! uwrite!(buf, "{}", nonzero_lit::NonZeroSaturating(nonzero_lit::u128!(3))).unwrap();
+//! assert_eq!(buf.0, "8080 75% 3");
+//! ```
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::{
+    Capacity, EvenNonZero, NonEmptyStr, NonZeroChecked, NonZeroExitCode, NonZeroI24, NonZeroI48,
+    NonZeroIndex, NonZeroRatio, NonZeroSaturating, NonZeroU24, NonZeroU48, NulFreeStr, Percent,
+    Port, PowerOfTwo, RingCapacity,
+};
+
+macro_rules! impl_ufmt {
+    ($($T:ty => |$v:ident, $f:ident| $body:expr;)+) => {$(
+        impl uDisplay for $T {
+            #[inline]
+            fn fmt<W>(&self, $f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: uWrite + ?Sized,
+            {
+                let $v = self;
+                $body
+            }
+        }
+
+        impl uDebug for $T {
+            #[inline]
+            fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: uWrite + ?Sized,
+            {
+                <$T as uDisplay>::fmt(self, f)
+            }
+        }
+    )+};
+    // Note: `ufmt` doesn't implement its traits for `NonZeroU128` or
+    // `NonZeroI128`, so these write the underlying integer.
+    (@get $($T:ty),+ $(,)?) => {
+        impl_ufmt! {$(
+            $T => |v, f| uDisplay::fmt(&v.get().get(), f);
+        )+}
+    };
+    (@inner $($T:ty),+ $(,)?) => {
+        impl_ufmt! {$(
+            $T => |v, f| uDisplay::fmt(&v.0.get(), f);
+        )+}
+    };
+}
+
+impl_ufmt! {
+    @get
+    Port,
+    NonZeroExitCode,
+    RingCapacity,
+    Capacity,
+    NonZeroU24,
+    NonZeroU48,
+    NonZeroI24,
+    NonZeroI48,
+    NonZeroIndex<usize>,
+    NonZeroIndex<u8>,
+    NonZeroIndex<u16>,
+    NonZeroIndex<u32>,
+    NonZeroIndex<u64>,
+    PowerOfTwo<u8>,
+    PowerOfTwo<u16>,
+    PowerOfTwo<u32>,
+    PowerOfTwo<u64>,
+    PowerOfTwo<u128>,
+    PowerOfTwo<usize>,
+}
+
+macro_rules! impl_ufmt_wrappers {
+    ($($NonZeroInt:ident),+ $(,)?) => {
+        impl_ufmt!(@get $(EvenNonZero<$NonZeroInt>),+);
+        impl_ufmt!(@inner $(NonZeroSaturating<$NonZeroInt>),+);
+        impl_ufmt!(@inner $(NonZeroChecked<$NonZeroInt>),+);
+    };
+}
+
+impl_ufmt_wrappers! {
+    NonZeroUsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroIsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+}
+
+impl_ufmt! {
+    Percent => |v, f| {
+        uDisplay::fmt(&v.get().get(), f)?;
+        f.write_char('%')
+    };
+    NonZeroRatio => |v, f| {
+        uDisplay::fmt(&v.numer().get(), f)?;
+        f.write_char('/')?;
+        uDisplay::fmt(&v.denom().get(), f)
+    };
+    NonEmptyStr<'_> => |v, f| f.write_str(v.as_str());
+    NulFreeStr => |v, f| f.write_str(v.as_str());
+}
@@ -0,0 +1,473 @@
+//! Support for checking code which uses this crate with the
+//! [Kani](https://github.com/model-checking/kani) model checker, enabled by
+//! the `verify` feature.
+//!
+//! The [`Invariant`] trait describes the invariant of each of this crate's
+//! `NonZero`-backed types (and of the `NonZero` types themselves), which
+//! [`nonzero_lit::assert_nonzero_invariant!`](crate::assert_nonzero_invariant)
+//! and [`nonzero_lit::assume_nonzero_invariant!`](crate::assume_nonzero_invariant)
+//! check in a proof harness. Outside of Kani, the former is a `debug_assert!`,
+//! and the latter does nothing, so they can be left in ordinary code.
+//!
+//! When compiled by Kani (that is, with `cfg(kani)`), this also implements
+//! `kani::Arbitrary` for the wrapper types, producing only valid values, so
+//! `kani::any::<Port>()` and friends can be used in downstream proofs, and
+//! adds harnesses for this crate's `const fn` operations, which check that
+//! they never produce a value which breaks its type's invariant.
+//!
+//! # Examples
+//! ```
+//! use nonzero_lit::{Percent, PowerOfTwo};
+//!
+//! fn scale(p: Percent, align: PowerOfTwo<u32>) -> u32 {
+//!     nonzero_lit::assume_nonzero_invariant!(p);
+//!     let n = align.get().get() * u32::from(p.get().get());
+//!     nonzero_lit::assert_nonzero_invariant!(core::num::NonZeroU32::new(n).unwrap());
+//!     n
+//! }
+//!
+//! #[cfg(kani)]
+//! #[kani::proof]
+//! fn scale_is_nonzero() {
+//!     let align = nonzero_lit::pow2!(u32, log2: 4);
+//!     assert!(scale(kani::any(), align) >= 16);
+//! }
+//!
+//! assert_eq!(scale(nonzero_lit::percent!(50), nonzero_lit::pow2!(u32, 8)), 400);
+//! ```
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{
+    Capacity, EvenNonZero, NonZeroChecked, NonZeroExitCode, NonZeroIndex, NonZeroSaturating,
+    Percent, Port, PowerOfTwo, RingCapacity,
+};
+
+/// A type with an invariant which can be checked at runtime.
+///
+/// For the `NonZero` types this is just that the value isn't zero, which
+/// always holds in safe code, but checking it in a proof costs nothing. For
+/// the wrapper types, it's whatever their constructors check (such as
+/// [`Percent`] being at most 100).
+///
+/// This can be implemented for downstream types, so that they can be used
+/// with [`nonzero_lit::assert_nonzero_invariant!`](crate::assert_nonzero_invariant)
+/// as well.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::verify::Invariant;
+///
+/// assert!(nonzero_lit::port!(8080).invariant_holds());
+/// assert!(nonzero_lit::ring_capacity!(64).invariant_holds());
+/// ```
+pub trait Invariant {
+    /// Returns whether the invariant holds for this value.
+    fn invariant_holds(&self) -> bool;
+}
+
+impl<T: Invariant + ?Sized> Invariant for &T {
+    #[inline]
+    fn invariant_holds(&self) -> bool {
+        (**self).invariant_holds()
+    }
+}
+
+impl<T: Invariant> Invariant for Option<T> {
+    #[inline]
+    fn invariant_holds(&self) -> bool {
+        self.as_ref().map_or(true, T::invariant_holds)
+    }
+}
+
+impl<T: Invariant> Invariant for NonZeroChecked<T> {
+    #[inline]
+    fn invariant_holds(&self) -> bool {
+        self.0.invariant_holds()
+    }
+}
+
+macro_rules! impl_invariant {
+    ($($T:ty => |$n:ident| $check:expr;)+) => {$(
+        impl Invariant for $T {
+            #[inline]
+            fn invariant_holds(&self) -> bool {
+                let $n = *self;
+                $check
+            }
+        }
+    )+};
+}
+
+impl_invariant! {
+    NonZeroU8 => |n| n.get() != 0;
+    NonZeroU16 => |n| n.get() != 0;
+    NonZeroU32 => |n| n.get() != 0;
+    NonZeroU64 => |n| n.get() != 0;
+    NonZeroU128 => |n| n.get() != 0;
+    NonZeroUsize => |n| n.get() != 0;
+    NonZeroI8 => |n| n.get() != 0;
+    NonZeroI16 => |n| n.get() != 0;
+    NonZeroI32 => |n| n.get() != 0;
+    NonZeroI64 => |n| n.get() != 0;
+    NonZeroI128 => |n| n.get() != 0;
+    NonZeroIsize => |n| n.get() != 0;
+    Port => |p| p.get().get() != 0;
+    Percent => |p| p.get().get() != 0 && p.get().get() <= 100;
+    NonZeroExitCode => |c| c.get().get() != 0;
+    Capacity => |c| c.get().get() != 0;
+    RingCapacity => |c| c.get().get().is_power_of_two() && c.get().get() <= isize::MAX as usize;
+    NonZeroIndex<u8> => |i| i.get().get() != 0;
+    NonZeroIndex<u16> => |i| i.get().get() != 0;
+    NonZeroIndex<u32> => |i| i.get().get() != 0;
+    NonZeroIndex<u64> => |i| i.get().get() != 0;
+    NonZeroIndex<usize> => |i| i.get().get() != 0;
+}
+
+macro_rules! impl_invariant_generic {
+    ($($int:ident => $NonZeroInt:ident;)+) => {$(
+        impl Invariant for EvenNonZero<$NonZeroInt> {
+            #[inline]
+            fn invariant_holds(&self) -> bool {
+                self.get().get() != 0 && self.get().get() % 2 == 0
+            }
+        }
+
+        impl Invariant for PowerOfTwo<$int> {
+            #[inline]
+            fn invariant_holds(&self) -> bool {
+                self.get().get().is_power_of_two()
+            }
+        }
+
+        impl Invariant for NonZeroSaturating<$NonZeroInt> {
+            #[inline]
+            fn invariant_holds(&self) -> bool {
+                self.get().get() != 0
+            }
+        }
+    )+};
+}
+
+impl_invariant_generic! {
+    u8 => NonZeroU8;
+    u16 => NonZeroU16;
+    u32 => NonZeroU32;
+    u64 => NonZeroU64;
+    u128 => NonZeroU128;
+    usize => NonZeroUsize;
+}
+
+macro_rules! impl_invariant_even_signed {
+    ($($NonZeroInt:ident),+) => {$(
+        impl Invariant for EvenNonZero<$NonZeroInt> {
+            #[inline]
+            fn invariant_holds(&self) -> bool {
+                self.get().get() != 0 && self.get().get() % 2 == 0
+            }
+        }
+    )+};
+}
+
+impl_invariant_even_signed!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
+
+/// Check that a value's [`Invariant`](crate::verify::Invariant) holds.
+///
+/// When compiled by Kani, this is a `kani::assert`, so a proof fails if any
+/// execution reaches it with an invalid value. Otherwise, it's a
+/// `debug_assert!`. Requires the `verify` feature.
+///
+/// # Examples
+/// ```
+/// let p = nonzero_lit::percent!(40);
+/// nonzero_lit::assert_nonzero_invariant!(p);
+/// nonzero_lit::assert_nonzero_invariant!(nonzero_lit::ops::u8::max(p.get(), nonzero_lit::u8!(7)));
+/// ```
+#[cfg(not(kani))]
+#[macro_export]
+macro_rules! assert_nonzero_invariant {
+    ($val:expr $(,)?) => {
+        ::core::debug_assert!(
+            $crate::verify::Invariant::invariant_holds(&$val),
+            "nonzero invariant violated",
+        )
+    };
+}
+
+#[cfg(kani)]
+#[macro_export]
+macro_rules! assert_nonzero_invariant {
+    ($val:expr $(,)?) => {
+        ::kani::assert(
+            $crate::verify::Invariant::invariant_holds(&$val),
+            "nonzero invariant violated",
+        )
+    };
+}
+
+/// Assume that a value's [`Invariant`](crate::verify::Invariant) holds.
+///
+/// When compiled by Kani, this is a `kani::assume`, so a proof only considers
+/// executions where the value is valid, which is useful for values built
+/// from parts (such as with `kani::any()`). Otherwise, it does nothing.
+/// Requires the `verify` feature.
+///
+/// # Examples
+/// ```
+/// fn wrap(cap: nonzero_lit::RingCapacity, i: usize) -> usize {
+///     nonzero_lit::assume_nonzero_invariant!(cap);
+///     cap.wrap(i)
+/// }
+/// assert_eq!(wrap(nonzero_lit::ring_capacity!(8), 11), 3);
+/// ```
+#[cfg(not(kani))]
+#[macro_export]
+macro_rules! assume_nonzero_invariant {
+    ($val:expr $(,)?) => {{
+        let _ = &$val;
+    }};
+}
+
+#[cfg(kani)]
+#[macro_export]
+macro_rules! assume_nonzero_invariant {
+    ($val:expr $(,)?) => {
+        ::kani::assume($crate::verify::Invariant::invariant_holds(&$val))
+    };
+}
+
+#[cfg(kani)]
+macro_rules! impl_arbitrary {
+    ($($T:ty => $ctor:expr;)+) => {$(
+        impl kani::Arbitrary for $T {
+            #[inline]
+            fn any() -> Self {
+                let v: Option<$T> = $ctor(kani::any());
+                kani::assume(v.is_some());
+                v.unwrap()
+            }
+        }
+    )+};
+}
+
+#[cfg(kani)]
+impl_arbitrary! {
+    Port => Port::new_checked;
+    Percent => Percent::new_checked;
+    NonZeroExitCode => NonZeroExitCode::new_checked;
+    RingCapacity => RingCapacity::new;
+    Capacity => Capacity::new_checked;
+    NonZeroIndex<u8> => NonZeroIndex::<u8>::from_one_based;
+    NonZeroIndex<u16> => NonZeroIndex::<u16>::from_one_based;
+    NonZeroIndex<u32> => NonZeroIndex::<u32>::from_one_based;
+    NonZeroIndex<u64> => NonZeroIndex::<u64>::from_one_based;
+    NonZeroIndex<usize> => NonZeroIndex::<usize>::from_one_based;
+}
+
+#[cfg(kani)]
+impl<T: kani::Arbitrary> kani::Arbitrary for NonZeroChecked<T> {
+    #[inline]
+    fn any() -> Self {
+        NonZeroChecked(kani::any())
+    }
+}
+
+#[cfg(kani)]
+macro_rules! impl_arbitrary_generic {
+    ($($int:ident => $NonZeroInt:ident;)+) => {$(
+        impl_arbitrary! {
+            EvenNonZero<$NonZeroInt> => EvenNonZero::<$NonZeroInt>::new;
+            PowerOfTwo<$int> => PowerOfTwo::<$int>::from_log2;
+            NonZeroSaturating<$NonZeroInt> => |n| Some(NonZeroSaturating::<$NonZeroInt>::new(n));
+        }
+    )+};
+}
+
+#[cfg(kani)]
+impl_arbitrary_generic! {
+    u8 => NonZeroU8;
+    u16 => NonZeroU16;
+    u32 => NonZeroU32;
+    u64 => NonZeroU64;
+    u128 => NonZeroU128;
+    usize => NonZeroUsize;
+}
+
+#[cfg(kani)]
+impl_arbitrary! {
+    EvenNonZero<NonZeroI8> => EvenNonZero::<NonZeroI8>::new;
+    EvenNonZero<NonZeroI16> => EvenNonZero::<NonZeroI16>::new;
+    EvenNonZero<NonZeroI32> => EvenNonZero::<NonZeroI32>::new;
+    EvenNonZero<NonZeroI64> => EvenNonZero::<NonZeroI64>::new;
+    EvenNonZero<NonZeroI128> => EvenNonZero::<NonZeroI128>::new;
+    EvenNonZero<NonZeroIsize> => EvenNonZero::<NonZeroIsize>::new;
+}
+
+#[cfg(kani)]
+mod proofs {
+    use super::*;
+
+    macro_rules! common_proofs {
+        ($($int:ident => $NonZeroInt:ident, $bits:literal;)+) => {$(
+            mod $int {
+                use crate::verify::*;
+
+                #[kani::proof]
+                fn mul_checked() {
+                    let (a, b) = (kani::any(), kani::any());
+                    assert_nonzero_invariant!(crate::ops::$int::mul_checked(a, b));
+                }
+
+                #[kani::proof]
+                #[kani::unwind($bits)]
+                fn pow_checked() {
+                    let exp: u32 = kani::any();
+                    kani::assume(exp < $bits);
+                    assert_nonzero_invariant!(crate::ops::$int::pow_checked(kani::any(), exp));
+                }
+
+                #[kani::proof]
+                fn max_min() {
+                    let (a, b): ($NonZeroInt, $NonZeroInt) = (kani::any(), kani::any());
+                    let (hi, lo) = (crate::ops::$int::max(a, b), crate::ops::$int::min(a, b));
+                    assert_nonzero_invariant!(hi);
+                    assert_nonzero_invariant!(lo);
+                    kani::assert(lo <= hi, "`min` is above `max`");
+                }
+
+                #[kani::proof]
+                fn checked() {
+                    let a: NonZeroChecked<$NonZeroInt> = kani::any();
+                    let b: NonZeroChecked<$NonZeroInt> = kani::any();
+                    assert_nonzero_invariant!(a + b);
+                    assert_nonzero_invariant!(a - b);
+                    assert_nonzero_invariant!(a * b - b);
+                }
+
+                #[kani::proof]
+                fn even_half() {
+                    let e: EvenNonZero<$NonZeroInt> = kani::any();
+                    assert_nonzero_invariant!(e.half());
+                }
+            }
+        )+};
+    }
+
+    // Note: These are in their own modules, as modules named after the integer
+    // types would shadow them in the rest of `proofs`.
+    mod common {
+        common_proofs! {
+            u8 => NonZeroU8, 8;
+            u16 => NonZeroU16, 16;
+            u32 => NonZeroU32, 32;
+            i8 => NonZeroI8, 8;
+            i16 => NonZeroI16, 16;
+            i32 => NonZeroI32, 32;
+        }
+    }
+
+    macro_rules! unsigned_proofs {
+        ($($int:ident => $NonZeroInt:ident, $bits:literal;)+) => {$(
+            mod $int {
+                use crate::verify::*;
+
+                #[kani::proof]
+                fn add_unsigned() {
+                    let r = crate::ops::$int::add_unsigned(kani::any(), kani::any());
+                    assert_nonzero_invariant!(r);
+                }
+
+                #[kani::proof]
+                fn at_least_one() {
+                    assert_nonzero_invariant!(crate::ops::$int::at_least_one(kani::any()));
+                }
+
+                #[kani::proof]
+                #[kani::unwind($bits)]
+                fn isqrt() {
+                    let n: $NonZeroInt = kani::any();
+                    let r = crate::ops::$int::isqrt(n);
+                    assert_nonzero_invariant!(r);
+                    kani::assert(r.get() <= n.get() / r.get(), "`isqrt` is too large");
+                }
+
+                #[kani::proof]
+                fn saturating() {
+                    let a: NonZeroSaturating<$NonZeroInt> = kani::any();
+                    let b: NonZeroSaturating<$NonZeroInt> = kani::any();
+                    assert_nonzero_invariant!(a.saturating_add(b));
+                    assert_nonzero_invariant!(a.saturating_sub(b));
+                    assert_nonzero_invariant!(a.saturating_mul(b));
+                    assert_nonzero_invariant!(a.saturating_div(b));
+                }
+
+                #[kani::proof]
+                fn pow2_checked_mul() {
+                    let a: PowerOfTwo<$int> = kani::any();
+                    assert_nonzero_invariant!(a.checked_mul(kani::any()));
+                }
+            }
+        )+};
+    }
+
+    mod unsigned {
+        unsigned_proofs! {
+            u8 => NonZeroU8, 8;
+            u16 => NonZeroU16, 16;
+            u32 => NonZeroU32, 32;
+        }
+    }
+
+    #[kani::proof]
+    fn ring_wrap() {
+        let cap: RingCapacity = kani::any();
+        kani::assert(
+            cap.wrap(kani::any()) < cap.get().get(),
+            "`wrap` is out of range",
+        );
+    }
+
+    #[kani::proof]
+    fn capacity_sizes() {
+        let cap: Capacity = kani::any();
+        assert_nonzero_invariant!(cap.next_pow2());
+        let bytes = cap.checked_mul_elem_size(kani::any());
+        kani::assert(
+            bytes.map_or(true, |n| n.get() <= isize::MAX as usize),
+            "size is too large",
+        );
+        kani::assert(
+            cap.bytes_for::<u32>().map_or(true, |n| n % 4 == 0),
+            "size is misaligned",
+        );
+    }
+
+    #[kani::proof]
+    fn index_round_trip() {
+        let i: NonZeroIndex<u32> = kani::any();
+        assert_nonzero_invariant!(NonZeroIndex::<u32>::from_zero_based(i.zero_based()));
+        kani::assert(
+            i.zero_based() + 1 == i.one_based(),
+            "index is off by more than one",
+        );
+    }
+
+    #[kani::proof]
+    fn percent_of() {
+        let p: Percent = kani::any();
+        let v: u64 = kani::any();
+        kani::assert(p.of_floor(v) <= v, "`of_floor` is above the value");
+        kani::assert(p.of_ceil(v) <= v, "`of_ceil` is above the value");
+        kani::assert(p.of_nearest(v) <= v, "`of_nearest` is above the value");
+    }
+}
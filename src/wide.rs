@@ -0,0 +1,625 @@
+use core::fmt;
+use core::num::{NonZeroI128, NonZeroU128};
+
+const LOW_64: u128 = u64::MAX as u128;
+
+// The helpers below work on 256-bit values as `(hi, lo)` pairs of limbs.
+
+const fn add(a: (u128, u128), b: (u128, u128)) -> ((u128, u128), bool) {
+    let (lo, carry) = a.1.overflowing_add(b.1);
+    let (hi, o1) = a.0.overflowing_add(b.0);
+    let (hi, o2) = hi.overflowing_add(carry as u128);
+    ((hi, lo), o1 || o2)
+}
+
+const fn sub(a: (u128, u128), b: (u128, u128)) -> ((u128, u128), bool) {
+    let (lo, borrow) = a.1.overflowing_sub(b.1);
+    let (hi, o1) = a.0.overflowing_sub(b.0);
+    let (hi, o2) = hi.overflowing_sub(borrow as u128);
+    ((hi, lo), o1 || o2)
+}
+
+const fn neg(a: (u128, u128)) -> (u128, u128) {
+    add((!a.0, !a.1), (0, 1)).0
+}
+
+// Full 128x128 -> 256 bit product.
+const fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a1, a0) = (a >> 64, a & LOW_64);
+    let (b1, b0) = (b >> 64, b & LOW_64);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let mid = (p00 >> 64) + (p01 & LOW_64) + (p10 & LOW_64);
+    let lo = (p00 & LOW_64) | (mid << 64);
+    let hi = a1 * b1 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+const fn mul(a: (u128, u128), b: (u128, u128)) -> Option<(u128, u128)> {
+    if a.0 != 0 && b.0 != 0 {
+        return None;
+    }
+    let (hi, lo) = mul_wide(a.1, b.1);
+    let cross = if a.0 != 0 {
+        a.0.checked_mul(b.1)
+    } else {
+        b.0.checked_mul(a.1)
+    };
+    match cross {
+        Some(cross) => match hi.checked_add(cross) {
+            Some(hi) => Some((hi, lo)),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+// Divides by `d`, returning the quotient and remainder.
+const fn div_rem_small(a: (u128, u128), d: u64) -> ((u128, u128), u64) {
+    let limbs = [a.0 >> 64, a.0 & LOW_64, a.1 >> 64, a.1 & LOW_64];
+    let mut q = [0u128; 4];
+    let mut rem = 0u128;
+    let mut i = 0;
+    while i < 4 {
+        let cur = (rem << 64) | limbs[i];
+        q[i] = cur / d as u128;
+        rem = cur % d as u128;
+        i += 1;
+    }
+    (((q[0] << 64) | q[1], (q[2] << 64) | q[3]), rem as u64)
+}
+
+const fn to_be_bytes(a: (u128, u128)) -> [u8; 32] {
+    let hi = a.0.to_be_bytes();
+    let lo = a.1.to_be_bytes();
+    let mut out = [0; 32];
+    let mut i = 0;
+    while i < 16 {
+        out[i] = hi[i];
+        out[i + 16] = lo[i];
+        i += 1;
+    }
+    out
+}
+
+const fn from_be_bytes(bytes: [u8; 32]) -> (u128, u128) {
+    let mut hi = [0; 16];
+    let mut lo = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        hi[i] = bytes[i];
+        lo[i] = bytes[i + 16];
+        i += 1;
+    }
+    (u128::from_be_bytes(hi), u128::from_be_bytes(lo))
+}
+
+fn fmt_decimal(a: (u128, u128), nonneg: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // 2^256 has 78 decimal digits.
+    let mut buf = [0u8; 78];
+    let mut i = buf.len();
+    let mut rest = a;
+    loop {
+        let (q, r) = div_rem_small(rest, 10);
+        i -= 1;
+        buf[i] = b'0' + r as u8;
+        rest = q;
+        if rest == (0, 0) {
+            break;
+        }
+    }
+    f.pad_integral(nonneg, "", core::str::from_utf8(&buf[i..]).unwrap())
+}
+
+fn fmt_hex(a: (u128, u128), upper: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let digits: &[u8; 16] = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let mut buf = [0u8; 64];
+    let mut i = buf.len();
+    let mut rest = a;
+    loop {
+        i -= 1;
+        buf[i] = digits[(rest.1 & 0xf) as usize];
+        rest = (rest.0 >> 4, (rest.1 >> 4) | (rest.0 << 124));
+        if rest == (0, 0) {
+            break;
+        }
+    }
+    f.pad_integral(true, "0x", core::str::from_utf8(&buf[i..]).unwrap())
+}
+
+/// A 256-bit unsigned integer that is known not to equal zero, made of two
+/// `u128` limbs.
+///
+/// Unlike the `core` `NonZero` types, this has no niche, so `Option<Self>` is
+/// larger than `Self`. Use [`nonzero_lit::u256!`](crate::u256) to create one
+/// at compile time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroU256;
+///
+/// const P: NonZeroU256 = nonzero_lit::u256!(
+///     "0xffffffff_ffffffff_ffffffff_ffffffff_ffffffff_ffffffff_fffffffe_fffffc2f"
+/// );
+/// assert_eq!(P.hi(), u128::MAX);
+/// assert_eq!(P.checked_add(nonzero_lit::u256!("0x1_000003d1")), None);
+/// assert_eq!(P.checked_sub(P), None);
+/// assert!(P < NonZeroU256::MAX);
+/// assert_eq!(format!("{:#x}", NonZeroU256::MIN), "0x1");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroU256 {
+    // Note: field order matters for the derived `Ord`.
+    hi: u128,
+    lo: u128,
+}
+
+impl NonZeroU256 {
+    /// The smallest value of this type, one.
+    pub const MIN: Self = Self { hi: 0, lo: 1 };
+    /// The largest value of this type, `2^256 - 1`.
+    pub const MAX: Self = Self {
+        hi: u128::MAX,
+        lo: u128::MAX,
+    };
+
+    /// Creates a value from its high and low limbs, returning `None` if both
+    /// are zero.
+    #[inline]
+    pub const fn new(hi: u128, lo: u128) -> Option<Self> {
+        if hi == 0 && lo == 0 {
+            None
+        } else {
+            Some(Self { hi, lo })
+        }
+    }
+
+    /// Widens a [`NonZeroU128`].
+    #[inline]
+    pub const fn from_u128(n: NonZeroU128) -> Self {
+        Self { hi: 0, lo: n.get() }
+    }
+
+    /// Returns the high 128 bits.
+    #[inline]
+    pub const fn hi(self) -> u128 {
+        self.hi
+    }
+
+    /// Returns the low 128 bits.
+    #[inline]
+    pub const fn lo(self) -> u128 {
+        self.lo
+    }
+
+    /// Adds, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match add((self.hi, self.lo), (other.hi, other.lo)) {
+            (_, true) => None,
+            ((hi, lo), false) => Some(Self { hi, lo }),
+        }
+    }
+
+    /// Subtracts, returning `None` if the result would be zero or negative.
+    #[inline]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match sub((self.hi, self.lo), (other.hi, other.lo)) {
+            (_, true) => None,
+            ((hi, lo), false) => Self::new(hi, lo),
+        }
+    }
+
+    /// Multiplies, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        match mul((self.hi, self.lo), (other.hi, other.lo)) {
+            Some((hi, lo)) => Some(Self { hi, lo }),
+            None => None,
+        }
+    }
+
+    /// Returns the value as a big-endian byte array.
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 32] {
+        to_be_bytes((self.hi, self.lo))
+    }
+
+    /// Reads a value from a big-endian byte array, returning `None` if it's
+    /// zero.
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Option<Self> {
+        let (hi, lo) = from_be_bytes(bytes);
+        Self::new(hi, lo)
+    }
+}
+
+impl From<NonZeroU128> for NonZeroU256 {
+    #[inline]
+    fn from(n: NonZeroU128) -> Self {
+        Self::from_u128(n)
+    }
+}
+
+impl fmt::Debug for NonZeroU256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for NonZeroU256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal((self.hi, self.lo), true, f)
+    }
+}
+
+impl fmt::LowerHex for NonZeroU256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex((self.hi, self.lo), false, f)
+    }
+}
+
+impl fmt::UpperHex for NonZeroU256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex((self.hi, self.lo), true, f)
+    }
+}
+
+/// A 256-bit signed integer that is known not to equal zero, made of two
+/// 128-bit limbs (in two's complement).
+///
+/// Unlike the `core` `NonZero` types, this has no niche, so `Option<Self>` is
+/// larger than `Self`. Use [`nonzero_lit::i256!`](crate::i256) to create one
+/// at compile time.
+///
+/// # Examples
+/// ```
+/// use nonzero_lit::NonZeroI256;
+///
+/// const A: NonZeroI256 = nonzero_lit::i256!("-0x10");
+/// const B: NonZeroI256 = nonzero_lit::i256!("0x1_00000000_00000000_00000000_00000000");
+/// assert!(A.is_negative());
+/// assert!(A < B);
+/// assert_eq!(A.checked_mul(B).unwrap().to_string(), "-5444517870735015415413993718908291383296");
+/// assert_eq!(A.checked_add(nonzero_lit::i256!("0x10")), None);
+/// assert_eq!(NonZeroI256::MIN.checked_neg(), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroI256 {
+    // Note: field order matters for the derived `Ord`.
+    hi: i128,
+    lo: u128,
+}
+
+impl NonZeroI256 {
+    /// The smallest value of this type, `-2^255`.
+    pub const MIN: Self = Self {
+        hi: i128::MIN,
+        lo: 0,
+    };
+    /// The largest value of this type, `2^255 - 1`.
+    pub const MAX: Self = Self {
+        hi: i128::MAX,
+        lo: u128::MAX,
+    };
+
+    /// Creates a value from its high and low limbs, returning `None` if both
+    /// are zero.
+    #[inline]
+    pub const fn new(hi: i128, lo: u128) -> Option<Self> {
+        if hi == 0 && lo == 0 {
+            None
+        } else {
+            Some(Self { hi, lo })
+        }
+    }
+
+    /// Widens a [`NonZeroI128`].
+    #[inline]
+    pub const fn from_i128(n: NonZeroI128) -> Self {
+        let n = n.get();
+        Self {
+            hi: if n < 0 { -1 } else { 0 },
+            lo: n as u128,
+        }
+    }
+
+    /// Returns the high 128 bits, which include the sign.
+    #[inline]
+    pub const fn hi(self) -> i128 {
+        self.hi
+    }
+
+    /// Returns the low 128 bits.
+    #[inline]
+    pub const fn lo(self) -> u128 {
+        self.lo
+    }
+
+    /// Returns true if this value is negative.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    #[inline]
+    const fn raw(self) -> (u128, u128) {
+        (self.hi as u128, self.lo)
+    }
+
+    #[inline]
+    const fn from_raw(a: (u128, u128)) -> Option<Self> {
+        Self::new(a.0 as i128, a.1)
+    }
+
+    /// Adds, returning `None` on overflow, or if the result would be zero.
+    #[inline]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        let (sum, _) = add(self.raw(), other.raw());
+        let overflowed = self.is_negative() == other.is_negative()
+            && ((sum.0 as i128) < 0) != self.is_negative();
+        if overflowed {
+            None
+        } else {
+            Self::from_raw(sum)
+        }
+    }
+
+    /// Subtracts, returning `None` on overflow, or if the result would be
+    /// zero.
+    #[inline]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        let (diff, _) = sub(self.raw(), other.raw());
+        let overflowed = self.is_negative() != other.is_negative()
+            && ((diff.0 as i128) < 0) != self.is_negative();
+        if overflowed {
+            None
+        } else {
+            Self::from_raw(diff)
+        }
+    }
+
+    /// Negates, returning `None` for [`MIN`](Self::MIN).
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.hi == i128::MIN && self.lo == 0 {
+            None
+        } else {
+            Self::from_raw(neg(self.raw()))
+        }
+    }
+
+    /// Multiplies, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        let negative = self.is_negative() != other.is_negative();
+        let product = match mul(self.magnitude(), other.magnitude()) {
+            Some(p) => p,
+            None => return None,
+        };
+        let limit = 1u128 << 127;
+        if product.0 > limit || (product.0 == limit && (product.1 != 0 || !negative)) {
+            None
+        } else if negative {
+            Self::from_raw(neg(product))
+        } else {
+            Self::from_raw(product)
+        }
+    }
+
+    // The absolute value, as an unsigned 256-bit value.
+    #[inline]
+    const fn magnitude(self) -> (u128, u128) {
+        if self.is_negative() {
+            neg(self.raw())
+        } else {
+            self.raw()
+        }
+    }
+
+    /// Returns the value (in two's complement) as a big-endian byte array.
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 32] {
+        to_be_bytes(self.raw())
+    }
+
+    /// Reads a value (in two's complement) from a big-endian byte array,
+    /// returning `None` if it's zero.
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 32]) -> Option<Self> {
+        Self::from_raw(from_be_bytes(bytes))
+    }
+}
+
+impl From<NonZeroI128> for NonZeroI256 {
+    #[inline]
+    fn from(n: NonZeroI128) -> Self {
+        Self::from_i128(n)
+    }
+}
+
+impl fmt::Debug for NonZeroI256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for NonZeroI256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_decimal(self.magnitude(), !self.is_negative(), f)
+    }
+}
+
+impl fmt::LowerHex for NonZeroI256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.raw(), false, f)
+    }
+}
+
+impl fmt::UpperHex for NonZeroI256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(self.raw(), true, f)
+    }
+}
+
+// Parses an optionally `-`-prefixed hex string, returning whether it was
+// negative, and the magnitude.
+const fn parse_hex(s: &str) -> (bool, (u128, u128)) {
+    let b = s.as_bytes();
+    let mut i = 0;
+    let negative = i < b.len() && b[i] == b'-';
+    if negative {
+        i += 1;
+    }
+    if i + 1 < b.len() && b[i] == b'0' && (b[i + 1] == b'x' || b[i + 1] == b'X') {
+        i += 2;
+    }
+    // Note: Hacky const fn asserts, here and below.
+    let _ = ["expected a hex string, like \"0x1234\""][(i == b.len()) as usize];
+    let mut v = (0u128, 0u128);
+    while i < b.len() {
+        let c = b[i];
+        i += 1;
+        let _ = ["expected a hex digit"][!(c.is_ascii_hexdigit() || c == b'_') as usize];
+        if c == b'_' {
+            continue;
+        }
+        let d = if c <= b'9' {
+            c - b'0'
+        } else {
+            (c | 0x20) - b'a' + 10
+        };
+        let _ = ["hex string is too large for 256 bits"][(v.0 >> 124 != 0) as usize];
+        v = ((v.0 << 4) | (v.1 >> 124), (v.1 << 4) | d as u128);
+    }
+    (negative, v)
+}
+
+#[doc(hidden)]
+pub const fn parse_u256(s: &str) -> NonZeroU256 {
+    let (negative, (hi, lo)) = parse_hex(s);
+    // Note: Hacky const fn asserts.
+    let _ = ["u256 values must not be negative"][negative as usize];
+    let _ = ["N must not be zero"][(hi == 0 && lo == 0) as usize];
+    NonZeroU256 { hi, lo }
+}
+
+#[doc(hidden)]
+pub const fn parse_i256(s: &str) -> NonZeroI256 {
+    let (negative, v) = parse_hex(s);
+    // Note: Hacky const fn asserts.
+    let _ = ["N must not be zero"][(v.0 == 0 && v.1 == 0) as usize];
+    let limit = 1u128 << 127;
+    let _ = ["value is out of range for i256"]
+        [(v.0 > limit || (v.0 == limit && (v.1 != 0 || !negative))) as usize];
+    let (hi, lo) = if negative { neg(v) } else { v };
+    NonZeroI256 { hi: hi as i128, lo }
+}
+
+/// Create a [`NonZeroU256`] from a hex string literal, checking at compile
+/// time that it's nonzero and fits in 256 bits.
+///
+/// The string may start with `0x`, and may contain `_` separators.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let n = nonzero_lit::u256!("0x1_0000_0000_0000_0000_0000_0000_0000_0000");
+/// assert_eq!((n.hi(), n.lo()), (1, 0));
+/// ```
+///
+/// Works for consts, and the parameter can be any const expression.
+/// ```
+/// use nonzero_lit::NonZeroU256;
+/// const ORDER: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+/// const N: NonZeroU256 = nonzero_lit::u256!(ORDER);
+/// assert_eq!(format!("{:X}", N), ORDER);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::u256!("0x0000");
+/// ```
+///
+/// Including malformed or oversized strings.
+/// ```compile_fail
+/// let n = nonzero_lit::u256!("0xfg");
+/// ```
+///
+/// ```compile_fail
+/// let n = nonzero_lit::u256!(
+///     "0x1_00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000"
+/// );
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "0x1";
+/// let foo = nonzero_lit::u256!(bar);
+/// ```
+#[macro_export]
+macro_rules! u256 {
+    ($s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const N: $crate::NonZeroU256 = $crate::_private::parse_u256(__E);
+            N
+        }
+    }};
+}
+
+/// Create a [`NonZeroI256`] from a hex string literal, checking at compile
+/// time that it's nonzero and in range.
+///
+/// The string may start with `-` and then `0x`, and may contain `_`
+/// separators. It gives the magnitude of the value, not its two's complement
+/// representation.
+///
+/// # Examples
+/// Basic usage
+/// ```
+/// let n = nonzero_lit::i256!("-0x1");
+/// assert_eq!((n.hi(), n.lo()), (-1, u128::MAX));
+/// ```
+///
+/// Works for consts, and the full range is supported.
+/// ```
+/// use nonzero_lit::NonZeroI256;
+/// const MIN: NonZeroI256 = nonzero_lit::i256!(
+///     "-0x8000000000000000000000000000000000000000000000000000000000000000"
+/// );
+/// assert_eq!(MIN, NonZeroI256::MIN);
+/// ```
+///
+/// Misuse is detected at compile time.
+/// ```compile_fail
+/// let n = nonzero_lit::i256!("-0x0");
+/// ```
+///
+/// Including values which are out of range.
+/// ```compile_fail
+/// let n = nonzero_lit::i256!(
+///     "0x8000000000000000000000000000000000000000000000000000000000000000"
+/// );
+/// ```
+///
+/// Note: argument must be a constant expression.
+/// ```compile_fail
+/// let bar = "0x1";
+/// let foo = nonzero_lit::i256!(bar);
+/// ```
+#[macro_export]
+macro_rules! i256 {
+    ($s:expr $(,)?) => {{
+        const __E: &str = $s;
+        {
+            const N: $crate::NonZeroI256 = $crate::_private::parse_i256(__E);
+            N
+        }
+    }};
+}